@@ -0,0 +1,151 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use html5ever::driver::ParseOpts;
+use html5ever::parse_document;
+use html5ever::tendril::TendrilSink;
+use html_linter::{ColumnUnit, DOMIndex, HtmlLinter, Rule, RuleType, Severity};
+use markup5ever_rcdom::RcDom;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+fn rule(name: String, rule_type: RuleType, selector: &str, condition: &str) -> Rule {
+    Rule {
+        name,
+        rule_type,
+        severity: Severity::Warning,
+        selector: selector.to_string(),
+        condition: condition.into(),
+        message: "Benchmark rule".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    }
+}
+
+fn setup_rules() -> Vec<Rule> {
+    let mut rules = Vec::new();
+    for i in 0..15 {
+        rules.push(rule(
+            format!("img-alt-{}", i),
+            RuleType::AttributePresence,
+            "img",
+            "alt-missing",
+        ));
+        rules.push(rule(
+            format!("input-label-{}", i),
+            RuleType::Nesting,
+            "input",
+            "parent-label-or-for",
+        ));
+        rules.push(rule(
+            format!("semantic-{}", i),
+            RuleType::Semantics,
+            "div",
+            "semantic-structure",
+        ));
+        rules.push(Rule {
+            options: HashMap::from([("pattern".to_string(), "^[a-z-]+$".to_string())]),
+            ..rule(
+                format!("class-names-{}", i),
+                RuleType::AttributeValue,
+                "div",
+                "pattern-match",
+            )
+        });
+    }
+    rules
+}
+
+fn setup_document() -> String {
+    let mut html = String::from("<!DOCTYPE html><html><head><title>Bench</title></head><body>");
+    for i in 0..500 {
+        html.push_str(&format!(
+            "<div class='item-{}'><img src='{}.png'><input id='field-{}' type='text'><p>Item {}</p></div>\n",
+            i, i, i, i
+        ));
+    }
+    html.push_str("</body></html>");
+    html
+}
+
+fn bench_lint(c: &mut Criterion) {
+    let linter = HtmlLinter::new(setup_rules(), None);
+    let html = setup_document();
+
+    let single_thread = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+
+    let mut group = c.benchmark_group("lint_60_rules");
+    group.sample_size(10);
+    group.bench_function("single_thread", |b| {
+        b.iter(|| single_thread.install(|| linter.lint(&html).unwrap()))
+    });
+    group.bench_function("parallel", |b| b.iter(|| linter.lint(&html).unwrap()));
+    group.finish();
+}
+
+fn bench_lint_batch(c: &mut Criterion) {
+    let linter = HtmlLinter::new(setup_rules().into_iter().take(20).collect(), None);
+    let documents: Vec<(String, String)> = (0..100)
+        .map(|i| {
+            let mut html = String::from("<!DOCTYPE html><html><head><title>Page</title></head><body>");
+            for j in 0..20 {
+                html.push_str(&format!(
+                    "<div class='item-{}'><img src='{}-{}.png'><input type='text'><p>Item</p></div>\n",
+                    j, i, j
+                ));
+            }
+            html.push_str("</body></html>");
+            (format!("page-{}.html", i), html)
+        })
+        .collect();
+    let batch: Vec<(&str, &str)> = documents
+        .iter()
+        .map(|(id, html)| (id.as_str(), html.as_str()))
+        .collect();
+
+    let mut group = c.benchmark_group("lint_100_documents_20_rules");
+    group.sample_size(10);
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            batch
+                .iter()
+                .map(|(_, html)| linter.lint(html).unwrap())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("batch", |b| b.iter(|| linter.lint_batch(&batch)));
+    group.finish();
+}
+
+// Rules resolve symbols constantly and from every thread at once
+fn bench_resolve_symbols(c: &mut Criterion) {
+    let html = setup_document();
+    let dom = parse_document(RcDom::default(), ParseOpts::default())
+        .from_utf8()
+        .read_from(&mut html.as_bytes())
+        .unwrap();
+    let index = DOMIndex::new(&dom, &html, ColumnUnit::default());
+    let resolve_all = |index: &DOMIndex| {
+        index
+            .get_nodes()
+            .par_iter()
+            .flat_map_iter(|node| node.attributes.iter())
+            .map(|attr| {
+                index
+                    .resolve_symbol(attr.value)
+                    .map_or(0, |value| value.len())
+            })
+            .sum::<usize>()
+    };
+
+    let mut group = c.benchmark_group("resolve_symbols");
+    group.bench_function("parallel", |b| b.iter(|| resolve_all(&index)));
+    group.bench_function("query_attribute_prefix", |b| {
+        b.iter(|| index.query("img[src^=4]").len())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_lint, bench_lint_batch, bench_resolve_symbols);
+criterion_main!(benches);
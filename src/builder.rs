@@ -0,0 +1,42 @@
+use crate::{HtmlLinter, LinterOptions, Rule};
+
+/// Incrementally assembles an [`HtmlLinter`] from rules and options.
+#[derive(Default)]
+pub struct HtmlLinterBuilder {
+    rules: Vec<Rule>,
+    options: LinterOptions,
+}
+
+impl HtmlLinterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn rules(mut self, rules: Vec<Rule>) -> Self {
+        self.rules.extend(rules);
+        self
+    }
+
+    pub fn option(mut self, f: impl FnOnce(&mut LinterOptions)) -> Self {
+        f(&mut self.options);
+        self
+    }
+
+    /// Skips the rule with exactly this name when linting.
+    pub fn ignore_rule(mut self, name: &str) -> Self {
+        // `ignore_files` entries are treated as regexes, so anchor the escaped name
+        self.options
+            .ignore_files
+            .push(format!("^{}$", regex::escape(name)));
+        self
+    }
+
+    pub fn build(self) -> HtmlLinter {
+        HtmlLinter::new(self.rules, Some(self.options))
+    }
+}
@@ -0,0 +1,116 @@
+use crate::*;
+
+/// Required states and properties for common ARIA roles (WAI-ARIA 1.2).
+const REQUIRED_ARIA_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("checkbox", &["aria-checked"]),
+    ("combobox", &["aria-expanded"]),
+    ("heading", &["aria-level"]),
+    ("menuitemcheckbox", &["aria-checked"]),
+    ("menuitemradio", &["aria-checked"]),
+    ("meter", &["aria-valuenow"]),
+    ("radio", &["aria-checked"]),
+    ("scrollbar", &["aria-controls", "aria-valuenow"]),
+    ("slider", &["aria-valuenow"]),
+    ("switch", &["aria-checked"]),
+];
+
+impl HtmlLinter {
+    pub(crate) fn check_aria_role(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let allowed_roles: Option<Vec<String>> = rule
+            .options
+            .get("allowed_roles")
+            .map(|roles| {
+                serde_json::from_str(roles).map_err(|e| {
+                    LinterError::RuleError(format!("Invalid allowed_roles JSON: {}", e))
+                })
+            })
+            .transpose()?;
+
+        let mut required_attributes: HashMap<String, Vec<String>> = REQUIRED_ARIA_ATTRIBUTES
+            .iter()
+            .map(|(role, attrs)| {
+                (
+                    role.to_string(),
+                    attrs.iter().map(|attr| attr.to_string()).collect(),
+                )
+            })
+            .collect();
+        if let Some(custom) = rule.options.get("required_attributes") {
+            let custom: HashMap<String, Vec<String>> =
+                serde_json::from_str(custom).map_err(|e| {
+                    LinterError::RuleError(format!("Invalid required_attributes JSON: {}", e))
+                })?;
+            required_attributes.extend(custom);
+        }
+
+        let matches = index.query(&rule.selector);
+
+        for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
+            if let Some(node) = index.get_node(node_idx) {
+                let attributes: HashMap<String, String> = node
+                    .attributes
+                    .iter()
+                    .map(|attr| {
+                        (
+                            index.resolve_symbol(attr.name).unwrap_or_default(),
+                            index.resolve_symbol(attr.value).unwrap_or_default(),
+                        )
+                    })
+                    .collect();
+
+                // The first token is the role in effect; later tokens are fallbacks
+                let Some(role) = attributes
+                    .get("role")
+                    .and_then(|role| role.split_whitespace().next())
+                else {
+                    continue;
+                };
+
+                if let Some(allowed) = &allowed_roles {
+                    if !allowed.iter().any(|allowed_role| allowed_role == role) {
+                        results.report(node, |result| {
+                            result.message = format!(
+                                "{} - Role '{}' is not in the list of allowed roles",
+                                rule.message, role
+                            );
+                        });
+                        continue;
+                    }
+                }
+
+                if let Some(required) = required_attributes.get(role) {
+                    let missing: Vec<&str> = required
+                        .iter()
+                        .filter(|attr| {
+                            attributes
+                                .get(attr.as_str())
+                                .is_none_or(|value| value.trim().is_empty())
+                        })
+                        .map(String::as_str)
+                        .collect();
+
+                    if !missing.is_empty() {
+                        results.report(node, |result| {
+                            result.message = format!(
+                                "{} - Role '{}' requires {}",
+                                rule.message,
+                                role,
+                                missing.join(", ")
+                            );
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
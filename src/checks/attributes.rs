@@ -1,21 +1,59 @@
+use dom::tags::parse_start_tag;
+use dom::utils::{missing_references, url_scheme};
 use dom::QuotesType;
 
 use crate::*;
 
+const DEFAULT_ALLOWED_SCHEMES: &[&str] = &["https", "http", "mailto", "tel"];
+const DEFAULT_DENIED_SCHEMES: &[&str] = &["javascript", "data", "vbscript"];
+const DEFAULT_BOOLEAN_ATTRIBUTES: &[&str] = &[
+    "disabled",
+    "required",
+    "checked",
+    "readonly",
+    "multiple",
+    "autofocus",
+    "novalidate",
+];
+
 impl HtmlLinter {
     pub(crate) fn check_attribute_value(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
         // Special handling for unique-id condition
-        if rule.condition == "unique-id" {
-            return self.check_unique_ids(rule, index);
+        if rule.condition == ConditionType::UniqueId {
+            return self.check_unique_ids(rule, index, results);
         }
 
         // Special handling for positive-number condition
-        if rule.condition == "positive-number" {
-            return self.check_positive_number(rule, index);
+        if rule.condition == ConditionType::PositiveNumber {
+            return self.check_positive_number(rule, index, results);
+        }
+
+        if rule.condition == ConditionType::NumericRange
+            || rule.condition == ConditionType::IntegerOnly
+        {
+            return self.check_numeric_attribute(rule, index, results);
+        }
+
+        if rule.condition == ConditionType::ReferenceExists {
+            return self.check_reference_exists(rule, index, results);
+        }
+
+        if rule.condition == ConditionType::UrlScheme {
+            return self.check_url_scheme(rule, index, results);
+        }
+
+        if rule.condition == ConditionType::BooleanAttribute {
+            self.check_boolean_attributes(rule, index, results);
+            return Ok(());
+        }
+
+        if rule.condition == ConditionType::ClassOrder {
+            return self.check_class_order(rule, index, results);
         }
 
         let pattern = rule.options.get("pattern").ok_or_else(|| {
@@ -37,9 +75,11 @@ impl HtmlLinter {
             .unwrap_or_else(|| vec!["*"]);
 
         let matches = index.query(&rule.selector);
-        let mut results = Vec::new();
 
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
                 let (has_required_attr, found_match) =
                     self.check_node_attributes(node, index, &attributes, &regex);
@@ -51,12 +91,12 @@ impl HtmlLinter {
                 };
 
                 if should_report {
-                    results.push(self.create_lint_result(rule, node, index));
+                    results.report(node, |_| {});
                 }
             }
         }
 
-        Ok(results)
+        Ok(())
     }
 
     fn check_node_attributes(
@@ -88,8 +128,8 @@ impl HtmlLinter {
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
         let matches = index.query(&rule.selector);
         let quote_style = rule
             .options
@@ -98,70 +138,111 @@ impl HtmlLinter {
             .unwrap_or("double");
 
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
                 for attr in &node.attributes {
                     let wrong_quotes = match quote_style {
-                        "double" => attr.quotes_type == QuotesType::Single,
-                        "single" => attr.quotes_type == QuotesType::Double,
+                        "double" => attr.quotes_type == Some(QuotesType::Single),
+                        "single" => attr.quotes_type == Some(QuotesType::Double),
                         _ => false,
                     };
 
                     if wrong_quotes {
-                        results.push(LintResult {
-                            rule: rule.name.clone(),
-                            severity: rule.severity.clone(),
-                            message: format!("{} (expected {} quotes)", rule.message, quote_style),
-                            location: Location {
-                                line: node.source_info.line,
-                                column: node.source_info.column,
-                                element: index
-                                    .resolve_symbol(node.tag_name)
-                                    .unwrap_or_default()
-                                    .to_string(),
-                            },
-                            source: node.source_info.source.clone(),
+                        results.report(node, |result| {
+                            result.message =
+                                format!("{} (expected {} quotes)", rule.message, quote_style);
+                            result.fix_suggestion = Some(Self::requote_suggestion(
+                                &node.source_info.source,
+                                quote_style,
+                            ));
                         });
                     }
                 }
             }
         }
 
-        Ok(results)
+        Ok(())
+    }
+
+    // Requotes the values of `tag`, the element's start tag as written. Values keep their text,
+    // character references included, and valueless attributes such as `checked` are left alone.
+    fn requote_suggestion(tag: &str, quote_style: &str) -> FixSuggestion {
+        let quote = if quote_style == "single" { '\'' } else { '"' };
+        let unfixable = |description| FixSuggestion {
+            description,
+            replacement: None,
+            is_safe: false,
+        };
+        let description = format!("Use {} quotes for attribute values", quote_style);
+        if tag.is_empty() {
+            return unfixable(description);
+        }
+
+        let (_, attributes) = parse_start_tag(tag);
+        let mut replacement = tag.to_string();
+        for attr in attributes.iter().rev() {
+            let Some(value) = attr.value.clone() else {
+                continue;
+            };
+            // The value itself contains the preferred quote, so re-quoting would need escaping
+            if tag[value.clone()].contains(quote) || value.end >= tag.len() {
+                return unfixable(description);
+            }
+            let written = match attr.quote {
+                Some(_) => value.start - 1..value.end + 1,
+                None => value.clone(),
+            };
+            replacement.replace_range(written, &format!("{}{}{}", quote, &tag[value], quote));
+        }
+
+        FixSuggestion {
+            description,
+            replacement: Some(replacement),
+            is_safe: true,
+        }
     }
 
     fn check_unique_ids(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
         let mut seen_ids = std::collections::HashSet::new();
         let matches = index.query(&rule.selector);
 
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
                 for attr in &node.attributes {
                     if index.resolve_symbol(attr.name).unwrap_or_default() == "id" {
                         let id = index.resolve_symbol(attr.value).unwrap_or_default();
                         if !seen_ids.insert(id.to_string()) {
-                            results.push(self.create_lint_result(rule, node, index));
+                            results.report(node, |_| {});
                         }
                     }
                 }
             }
         }
-        Ok(results)
+        Ok(())
     }
 
     fn check_positive_number(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
         let matches = index.query(&rule.selector);
 
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
                 for attr in &node.attributes {
                     let attr_name = index.resolve_symbol(attr.name).unwrap_or_default();
@@ -169,13 +250,364 @@ impl HtmlLinter {
                         let value = index.resolve_symbol(attr.value).unwrap_or_default();
                         if let Ok(num) = value.parse::<i32>() {
                             if num > 0 {
-                                results.push(self.create_lint_result(rule, node, index));
+                                results.report(node, |_| {});
                             }
                         }
                     }
                 }
             }
         }
-        Ok(results)
+        Ok(())
+    }
+
+    fn check_numeric_attribute(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let attribute = rule.options.get("attribute").ok_or_else(|| {
+            LinterError::RuleError(format!(
+                "attribute option required for {} check",
+                rule.condition
+            ))
+        })?;
+        let bound = |name: &str| {
+            rule.options
+                .get(name)
+                .map(|value| {
+                    value.parse::<f64>().map_err(|_| {
+                        LinterError::RuleError(format!("Invalid {} option: {}", name, value))
+                    })
+                })
+                .transpose()
+        };
+        let (min, max) = (bound("min")?, bound("max")?);
+        let inclusive = rule
+            .options
+            .get("inclusive")
+            .is_none_or(|inclusive| inclusive == "true");
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let Some(value) = node
+                .attributes
+                .iter()
+                .find(|attr| index.resolve_symbol(attr.name).unwrap_or_default() == *attribute)
+                .map(|attr| index.resolve_symbol(attr.value).unwrap_or_default())
+            else {
+                continue;
+            };
+
+            // Non-numeric values are left to pattern rules
+            let trimmed = value.trim();
+            let Some(number) = trimmed.parse::<f64>().ok().filter(|n| n.is_finite()) else {
+                continue;
+            };
+
+            let problem = if rule.condition == ConditionType::IntegerOnly {
+                trimmed
+                    .parse::<i64>()
+                    .is_err()
+                    .then(|| format!("{}=\"{}\" is not an integer", attribute, value))
+            } else {
+                let below = min.is_some_and(|min| {
+                    if inclusive {
+                        number < min
+                    } else {
+                        number <= min
+                    }
+                });
+                let above = max.is_some_and(|max| {
+                    if inclusive {
+                        number > max
+                    } else {
+                        number >= max
+                    }
+                });
+                (below || above).then(|| {
+                    format!(
+                        "{}=\"{}\" is outside {}{}, {}{}",
+                        attribute,
+                        value,
+                        if inclusive { "[" } else { "(" },
+                        min.map_or("-∞".to_string(), |min| min.to_string()),
+                        max.map_or("∞".to_string(), |max| max.to_string()),
+                        if inclusive { "]" } else { ")" },
+                    )
+                })
+            };
+
+            if let Some(problem) = problem {
+                results.report(node, |result| {
+                    result.message = format!("{} ({})", rule.message, problem);
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_reference_exists(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let attribute = rule.options.get("attribute").ok_or_else(|| {
+            LinterError::RuleError(
+                "attribute option required for reference-exists check".to_string(),
+            )
+        })?;
+        let reference_attribute = rule
+            .options
+            .get("reference_attribute")
+            .map(String::as_str)
+            .unwrap_or("id");
+        let allow_missing = rule
+            .options
+            .get("allow_missing")
+            .is_none_or(|allow| allow == "true");
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let value = node
+                .attributes
+                .iter()
+                .find(|attr| index.resolve_symbol(attr.name).unwrap_or_default() == *attribute)
+                .map(|attr| index.resolve_symbol(attr.value).unwrap_or_default())
+                .filter(|value| !value.trim().is_empty());
+
+            let problem = match value {
+                Some(value) => {
+                    let missing = missing_references(&value, reference_attribute, index);
+                    (!missing.is_empty()).then(|| {
+                        format!(
+                            "no element with {}=\"{}\"",
+                            reference_attribute,
+                            missing.join(" ")
+                        )
+                    })
+                }
+                None => (!allow_missing).then(|| format!("missing {} attribute", attribute)),
+            };
+
+            if let Some(problem) = problem {
+                results.report(node, |result| {
+                    result.message = format!("{} ({})", rule.message, problem);
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_url_scheme(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let attribute = rule
+            .options
+            .get("attribute")
+            .map(String::as_str)
+            .unwrap_or("href");
+        let schemes = |name: &str, default: &[&str]| -> Result<Vec<String>, LinterError> {
+            let schemes: Vec<String> = match rule.options.get(name) {
+                Some(json) => serde_json::from_str(json)
+                    .map_err(|e| LinterError::RuleError(format!("Invalid {} JSON: {}", name, e)))?,
+                None => default.iter().map(|scheme| scheme.to_string()).collect(),
+            };
+            Ok(schemes
+                .into_iter()
+                .map(|scheme| scheme.trim_end_matches(':').to_ascii_lowercase())
+                .collect())
+        };
+        let allowed = schemes("allowed_schemes", DEFAULT_ALLOWED_SCHEMES)?;
+        let denied = schemes("deny_schemes", DEFAULT_DENIED_SCHEMES)?;
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let Some(value) = node
+                .attributes
+                .iter()
+                .find(|attr| index.resolve_symbol(attr.name).unwrap_or_default() == attribute)
+                .map(|attr| index.resolve_symbol(attr.value).unwrap_or_default())
+            else {
+                continue;
+            };
+
+            // Relative and protocol-relative URLs inherit the page's scheme
+            let Some(scheme) = url_scheme(&value) else {
+                continue;
+            };
+            let problem = if denied.contains(&scheme) {
+                Some(format!("the {}: scheme is denied", scheme))
+            } else if !allowed.is_empty() && !allowed.contains(&scheme) {
+                Some(format!("the {}: scheme is not allowed", scheme))
+            } else {
+                None
+            };
+
+            if let Some(problem) = problem {
+                results.report(node, |result| {
+                    result.message = format!("{} ({})", rule.message, problem);
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_boolean_attributes(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let attributes: Vec<&str> = match rule.options.get("attribute") {
+            Some(attrs) => attrs.split(',').map(str::trim).collect(),
+            None => DEFAULT_BOOLEAN_ATTRIBUTES.to_vec(),
+        };
+
+        let mut matches: Vec<usize> = attributes
+            .iter()
+            .flat_map(|attribute| index.query(&format!("[{}]", attribute)))
+            .collect();
+        matches.sort_unstable();
+        matches.dedup();
+
+        for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            for attr in &node.attributes {
+                let name = index.resolve_symbol(attr.name).unwrap_or_default();
+                if !attributes.contains(&name.as_str()) {
+                    continue;
+                }
+                // Only presence matters, so the value must be empty or repeat the name
+                let value = index.resolve_symbol(attr.value).unwrap_or_default();
+                if value.is_empty() || value.eq_ignore_ascii_case(&name) {
+                    continue;
+                }
+
+                let problem = if matches!(
+                    value.trim().to_ascii_lowercase().as_str(),
+                    "false" | "0" | "no" | "off"
+                ) {
+                    format!(
+                        "{}=\"{}\" still enables {}; remove the attribute to turn it off",
+                        name, value, name
+                    )
+                } else {
+                    format!(
+                        "{}=\"{}\" is not a boolean value; use {} or {}=\"\"",
+                        name, value, name, name
+                    )
+                };
+                results.report(node, |result| {
+                    result.message = format!("{} ({})", rule.message, problem);
+                });
+            }
+        }
+    }
+
+    fn check_class_order(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let order = rule
+            .options
+            .get("order")
+            .map(String::as_str)
+            .unwrap_or("alphabetical");
+        let prefix_groups = match order {
+            "alphabetical" => Vec::new(),
+            "custom" => {
+                let groups = Self::parse_string_list(rule, "prefix_groups")?;
+                if groups.is_empty() {
+                    return Err(LinterError::RuleError(
+                        "prefix_groups option required for custom class order".to_string(),
+                    ));
+                }
+                groups
+            }
+            other => {
+                return Err(LinterError::RuleError(format!(
+                    "Invalid class order: {}",
+                    other
+                )))
+            }
+        };
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let Some(value) = node
+                .attributes
+                .iter()
+                .find(|attr| index.resolve_symbol(attr.name).unwrap_or_default() == "class")
+                .and_then(|attr| index.resolve_symbol(attr.value))
+            else {
+                continue;
+            };
+
+            let classes: Vec<&str> = value.split_ascii_whitespace().collect();
+            let mut expected = classes.clone();
+            super::sort_classes(&mut expected, &prefix_groups);
+            if classes == expected {
+                continue;
+            }
+
+            let expected = expected.join(" ");
+            results.report(node, |result| {
+                result.message = format!(
+                    "{} (class names are not in {} order; expected \"{}\")",
+                    rule.message, order, expected
+                );
+                // The class value is swapped in where it's written, which is only safe when it's
+                // written literally rather than with character references
+                let tag = &node.source_info.source;
+                let (_, attributes) = parse_start_tag(tag);
+                let replacement = attributes
+                    .iter()
+                    .find(|attr| tag[attr.name.clone()].eq_ignore_ascii_case("class"))
+                    .and_then(|attr| attr.value.clone())
+                    .filter(|written| tag[written.clone()] == value)
+                    .map(|written| {
+                        let mut replacement = tag.clone();
+                        replacement.replace_range(written, &expected);
+                        replacement
+                    });
+                result.fix_suggestion = Some(FixSuggestion {
+                    description: format!("Reorder the classes as \"{}\"", expected),
+                    is_safe: replacement.is_some(),
+                    replacement,
+                });
+            });
+        }
+
+        Ok(())
     }
 }
@@ -1,7 +1,24 @@
+use super::json_schema;
+use crate::dom::tags::parse_start_tag;
 use crate::*;
 use markup5ever_rcdom::NodeData;
 use regex::Regex;
 use serde::Deserialize;
+use url::Url;
+
+// Script types other than the default JavaScript that mark a module or a data block
+const VALID_SCRIPT_TYPES: &[&str] = &["module", "application/ld+json", "application/json"];
+
+// Legacy JavaScript MIME types; HTML5 scripts should leave `type` out instead
+const JAVASCRIPT_MIME_TYPES: &[&str] = &[
+    "application/javascript",
+    "application/ecmascript",
+    "application/x-javascript",
+    "application/x-ecmascript",
+    "text/ecmascript",
+    "text/jscript",
+    "text/livescript",
+];
 
 #[derive(Debug, Clone, Deserialize)]
 struct MetaTagRule {
@@ -19,16 +36,63 @@ struct MetaTagRule {
 enum PatternRule {
     #[serde(rename = "MinLength")]
     MinLength { value: usize },
+    #[serde(rename = "MaxLength")]
+    MaxLength { value: usize },
+    #[serde(rename = "ExactLength")]
+    ExactLength { value: usize },
     #[serde(rename = "LengthRange")]
     LengthRange { min: usize, max: usize },
     #[serde(rename = "OneOf")]
     OneOf { value: Vec<String> },
-    #[serde(rename = "NonEmpty")]
+    #[serde(rename = "NonEmpty", alias = "NotEmpty")]
     NonEmpty,
     #[serde(rename = "Exact")]
     Exact { value: String },
     #[serde(rename = "Regex")]
     Regex { value: String },
+    #[serde(rename = "UrlPattern")]
+    UrlPattern(UrlPattern),
+    #[serde(rename = "JsonSchema", alias = "JsonSchemaDraft07")]
+    JsonSchema { schema: serde_json::Value },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct UrlPattern {
+    #[serde(default = "default_url_schemes")]
+    schemes: Vec<String>,
+    #[serde(default)]
+    require_https: bool,
+    #[serde(default)]
+    allow_relative: bool,
+}
+
+fn default_url_schemes() -> Vec<String> {
+    vec!["http".to_string(), "https".to_string()]
+}
+
+fn validate_url(content: &str, pattern: &UrlPattern) -> bool {
+    match Url::parse(content.trim()) {
+        Ok(url) => {
+            pattern
+                .schemes
+                .iter()
+                .any(|scheme| scheme.eq_ignore_ascii_case(url.scheme()))
+                && (!pattern.require_https || url.scheme() == "https")
+        }
+        Err(url::ParseError::RelativeUrlWithoutBase) => {
+            // Resolve against a placeholder base to reject malformed relative references
+            pattern.allow_relative
+                && !content.trim().is_empty()
+                && Url::parse("https://example.com/")
+                    .and_then(|base| base.join(content.trim()))
+                    .is_ok()
+        }
+        Err(_) => false,
+    }
+}
+
+fn matches_json_schema(content: &str, validator: &jsonschema::Validator) -> bool {
+    serde_json::from_str(content).is_ok_and(|value| validator.is_valid(&value))
 }
 
 fn default_required() -> bool {
@@ -61,15 +125,22 @@ impl MetaTagRule {
 
     fn _validate_content(&self, content: &str) -> bool {
         match &self.pattern {
-            PatternRule::MinLength { value } => content.len() >= *value,
+            PatternRule::MinLength { value } => content.chars().count() >= *value,
+            PatternRule::MaxLength { value } => content.chars().count() <= *value,
+            PatternRule::ExactLength { value } => content.chars().count() == *value,
             PatternRule::OneOf { value } => value.contains(&content.to_string()),
             PatternRule::NonEmpty => !content.is_empty(),
             PatternRule::Exact { value } => content == value,
-            PatternRule::LengthRange { min, max } => content.len() >= *min && content.len() <= *max,
+            PatternRule::LengthRange { min, max } => {
+                (*min..=*max).contains(&content.chars().count())
+            }
             PatternRule::Regex { value } => {
                 let regex = Regex::new(value).unwrap();
                 regex.is_match(content)
             }
+            PatternRule::UrlPattern(pattern) => validate_url(content, pattern),
+            PatternRule::JsonSchema { schema } => json_schema::compile(schema)
+                .is_ok_and(|validator| matches_json_schema(content, &validator)),
         }
     }
 }
@@ -79,12 +150,12 @@ impl HtmlLinter {
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
         let matches = index.query(&rule.selector);
 
-        match rule.condition.as_str() {
-            "max-length" => {
+        match rule.condition {
+            ConditionType::MaxLength => {
                 let max_length = rule
                     .options
                     .get("max_length")
@@ -95,12 +166,12 @@ impl HtmlLinter {
                     if let Some(node) = index.get_node(node_idx) {
                         let text = dom::utils::get_node_text_content(node_idx, index);
                         if text.len() > max_length {
-                            results.push(self.create_lint_result(rule, node, index));
+                            results.report(node, |_| {});
                         }
                     }
                 }
             }
-            "content-length" => {
+            ConditionType::ContentLength => {
                 let min_length = rule
                     .options
                     .get("min_length")
@@ -120,19 +191,11 @@ impl HtmlLinter {
                         .and_then(|v| v.parse().ok())
                         .unwrap_or(false)
                 {
-                    results.push(LintResult {
-                        rule: rule.name.clone(),
-                        severity: rule.severity.clone(),
-                        message: format!(
+                    results.report_document_start(|| {
+                        format!(
                             "Required content with length between {} and {} not found",
                             min_length, max_length
-                        ),
-                        location: Location {
-                            line: 1,
-                            column: 1,
-                            element: "".to_string(),
-                        },
-                        source: "".to_string(),
+                        )
                     });
                 }
 
@@ -140,7 +203,70 @@ impl HtmlLinter {
                     if let Some(node) = index.get_node(node_idx) {
                         let text = dom::utils::get_node_text_content(node_idx, index);
                         if text.len() < min_length || text.len() > max_length {
-                            results.push(self.create_lint_result(rule, node, index));
+                            results.report(node, |_| {});
+                        }
+                    }
+                }
+            }
+            ConditionType::MinLength => {
+                let min_length: usize = rule
+                    .options
+                    .get("min_length")
+                    .and_then(|v| v.parse().ok())
+                    .ok_or_else(|| {
+                        LinterError::RuleError(
+                            "min_length option required for min-length check".to_string(),
+                        )
+                    })?;
+
+                for node_idx in matches {
+                    if let Some(node) = index.get_node(node_idx) {
+                        // Indentation around the text doesn't count towards its length
+                        let text = dom::utils::get_node_text_content(node_idx, index);
+                        let length = text.trim().chars().count();
+                        if length < min_length {
+                            results.report(node, |result| {
+                                result.message = format!(
+                                    "{} (found {} characters, expected at least {})",
+                                    rule.message, length, min_length
+                                );
+                            });
+                        }
+                    }
+                }
+            }
+            ConditionType::NonEmpty => {
+                for node_idx in matches {
+                    if let Some(node) = index.get_node(node_idx) {
+                        let text = dom::utils::get_node_text_content(node_idx, index);
+                        if text.trim().is_empty() {
+                            results.report(node, |_| {});
+                        }
+                    }
+                }
+            }
+            ConditionType::WordCount | ConditionType::SentenceCount => {
+                let (unit, min_key, max_key) = if rule.condition == ConditionType::WordCount {
+                    ("words", "min_words", "max_words")
+                } else {
+                    ("sentences", "min_sentences", "max_sentences")
+                };
+                let (min, max) = count_limits(rule, min_key, max_key)?;
+
+                for node_idx in matches {
+                    if let Some(node) = index.get_node(node_idx) {
+                        let text = dom::utils::get_node_text_content(node_idx, index);
+                        let count = if rule.condition == ConditionType::WordCount {
+                            count_words(&text)
+                        } else {
+                            count_sentences(&text)
+                        };
+
+                        if count < min || count > max {
+                            results.report(node, |result| {
+                                result.message =
+                                    format!("{} (found {} {})", rule.message, count, unit);
+                            });
                         }
                     }
                 }
@@ -152,15 +278,14 @@ impl HtmlLinter {
 
                     for node_idx in matches {
                         if let Some(node) = index.get_node(node_idx) {
-                            let mut text = String::new();
-                            dom::utils::extract_text(node.handle.as_ref().unwrap(), &mut text);
+                            let text = &node.direct_text;
                             let check_mode = rule
                                 .options
                                 .get("check_mode")
                                 .map(String::as_str)
                                 .unwrap_or("normal");
 
-                            let matches = regex.is_match(&text);
+                            let matches = regex.is_match(text);
                             let should_report = match check_mode {
                                 "ensure_existence" => !matches,
                                 "ensure_nonexistence" => matches,
@@ -168,7 +293,7 @@ impl HtmlLinter {
                             };
 
                             if should_report {
-                                results.push(self.create_lint_result(rule, node, index));
+                                results.report(node, |_| {});
                             }
                         }
                     }
@@ -176,30 +301,69 @@ impl HtmlLinter {
             }
         }
 
-        Ok(results)
+        Ok(())
     }
 
     pub(crate) fn check_element_content(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        if rule.condition == ConditionType::ScriptTypeCheck
+            || rule.condition == ConditionType::StyleTypeCheck
+        {
+            self.check_type_attributes(rule, index, results);
+            return Ok(());
+        }
+        if matches!(
+            rule.condition,
+            ConditionType::WordCountRange
+                | ConditionType::CharacterCount
+                | ConditionType::ParagraphCount
+        ) {
+            return self.check_content_density(rule, index, results);
+        }
+        if rule.condition == ConditionType::NonEmpty {
+            return self.check_empty_content(rule, index, results);
+        }
+
+        // Meta tag rules and their schemas are compiled once, not for every matched element
+        let meta_rules: Option<Vec<MetaTagRule>> = match rule.condition {
+            ConditionType::MetaTags => rule
+                .options
+                .get("required_meta_tags")
+                .map(|required_tags| {
+                    serde_json::from_str(required_tags)
+                        .map_err(|e| LinterError::RuleError(e.to_string()))
+                })
+                .transpose()?,
+            _ => None,
+        };
+        let meta_schemas = meta_rules
+            .iter()
+            .flatten()
+            .map(|meta_rule| match &meta_rule.pattern {
+                PatternRule::JsonSchema { schema } => json_schema::compile(schema).map(Some),
+                _ => Ok(None),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
         let matches = index.query(&rule.selector);
 
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
-                let should_report = match rule.condition.as_str() {
-                    "meta-tags" => {
-                        if let Some(required_tags) = rule.options.get("required_meta_tags") {
-                            let meta_rules: Vec<MetaTagRule> = serde_json::from_str(required_tags)
-                                .map_err(|e| LinterError::RuleError(e.to_string()))?;
-                            !self.validate_meta_tags(node_idx, &meta_rules, index)?
-                        } else {
-                            false
+                let should_report = match rule.condition {
+                    ConditionType::MetaTags => match &meta_rules {
+                        Some(meta_rules) => {
+                            !self.validate_meta_tags(node_idx, meta_rules, &meta_schemas, index)?
                         }
-                    }
-                    "empty-or-default" => {
+                        None => false,
+                    },
+                    ConditionType::EmptyOrDefault => {
                         let content = dom::utils::get_node_text_content(node_idx, index);
                         content.is_empty()
                             || content.trim() == "Untitled"
@@ -209,66 +373,500 @@ impl HtmlLinter {
                 };
 
                 if should_report {
-                    results.push(self.create_lint_result(rule, node, index));
+                    results.report(node, |_| {});
                 }
             }
         }
 
-        Ok(results)
+        Ok(())
     }
 
-    pub(crate) fn check_whitespace(
+    // Word, character and paragraph counts of each matched element's text
+    fn check_content_density(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let (unit, min_key, max_key) = match rule.condition {
+            ConditionType::WordCountRange => ("words", "min_words", "max_words"),
+            ConditionType::CharacterCount => ("characters", "min_characters", "max_characters"),
+            _ => ("paragraphs", "min_paragraphs", "max_paragraphs"),
+        };
+        let (min, max) = count_limits(rule, min_key, max_key)?;
 
-        match rule.condition.as_str() {
-            "trailing-whitespace" => {
-                let matches = index.query(&rule.selector);
-                for node_idx in matches {
-                    if let Some(node) = index.get_node(node_idx) {
-                        let lines = node.source_info.source.lines();
-                        for (i, line) in lines.enumerate() {
-                            if line.trim_end().len() != line.len() {
-                                results.push(LintResult {
-                                    rule: rule.name.clone(),
-                                    severity: rule.severity.clone(),
-                                    message: "Line contains trailing whitespace".to_string(),
-                                    location: Location {
-                                        line: node.source_info.line + i,
-                                        column: line.trim_end().len() + 1,
-                                        element: index
-                                            .resolve_symbol(node.tag_name)
-                                            .unwrap_or_default()
-                                            .to_string(),
-                                    },
-                                    source: line.to_string(),
-                                });
-                            }
-                        }
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let text = dom::utils::get_node_text_content(node_idx, index);
+            let count = match rule.condition {
+                ConditionType::WordCountRange => count_words(&text),
+                // Runs of whitespace, e.g. source indentation, count as a single space
+                ConditionType::CharacterCount => {
+                    let words: Vec<&str> = text.split_whitespace().collect();
+                    words.join(" ").chars().count()
+                }
+                _ => match index.query_within(node_idx, "p").len() {
+                    // Without paragraph elements, blank lines separate paragraphs
+                    0 => text
+                        .split("\n\n")
+                        .filter(|block| !block.trim().is_empty())
+                        .count(),
+                    paragraphs => paragraphs,
+                },
+            };
+
+            if count < min || count > max {
+                results.report(node, |result| {
+                    result.message = format!("{} (found {} {})", rule.message, count, unit);
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // Non-void elements with nothing but whitespace inside
+    fn check_empty_content(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let allow_children = rule
+            .options
+            .get("allow_children")
+            .is_some_and(|allow| allow == "true");
+        let allowed_empty_tags = Self::parse_string_list(rule, "allowed_empty_tags")?;
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
+            if dom::utils::is_void_element(&tag_name, self.element_context())
+                || allowed_empty_tags.contains(&tag_name)
+            {
+                continue;
+            }
+            if allow_children
+                && node.children.iter().any(|&child_idx| {
+                    index
+                        .get_node(child_idx)
+                        .is_some_and(|child| child.kind == dom::NodeKind::Element)
+                })
+            {
+                continue;
+            }
+
+            let text = dom::utils::get_node_text_content(node_idx, index);
+            if text.trim().is_empty() {
+                results.report(node, |result| {
+                    result.message = format!("{} (<{}> has no content)", rule.message, tag_name);
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // `type` attributes on scripts, and on styles and stylesheet links
+    fn check_type_attributes(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let allow_redundant = rule
+            .options
+            .get("allow_redundant")
+            .is_some_and(|allow| allow == "true");
+        let (selectors, redundant, valid): (&[&str], &str, &[&str]) =
+            if rule.condition == ConditionType::ScriptTypeCheck {
+                (&["script"], "text/javascript", VALID_SCRIPT_TYPES)
+            } else {
+                (&["style", "link[rel~=stylesheet]"], "text/css", &[])
+            };
+
+        let mut matches: Vec<usize> = selectors
+            .iter()
+            .flat_map(|selector| index.query(selector))
+            .collect();
+        matches.sort_unstable();
+
+        for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let Some(value) = node.attributes.iter().find_map(|attr| {
+                (index.resolve_symbol(attr.name).unwrap_or_default() == "type")
+                    .then(|| index.resolve_symbol(attr.value).unwrap_or_default())
+            }) else {
+                continue;
+            };
+            let essence = value.trim().to_ascii_lowercase();
+
+            let (severity, problem) = if essence.is_empty() || valid.contains(&essence.as_str()) {
+                continue;
+            } else if essence == redundant {
+                if allow_redundant {
+                    continue;
+                }
+                (
+                    Severity::Warning,
+                    format!("type=\"{}\" is redundant in HTML5", value),
+                )
+            } else if JAVASCRIPT_MIME_TYPES.contains(&essence.as_str()) {
+                (
+                    rule.severity.clone(),
+                    format!(
+                        "type=\"{}\" is not recommended; omit the type attribute",
+                        value
+                    ),
+                )
+            } else {
+                (rule.severity.clone(), format!("invalid type \"{}\"", value))
+            };
+
+            results.report_with_severity(node, severity, |result| {
+                result.message = format!("{} ({})", rule.message, problem);
+            });
+        }
+    }
+
+    pub(crate) fn check_whitespace(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        match rule.condition {
+            ConditionType::TrailingWhitespace => {
+                let source_map = index.get_source_map();
+                for line_number in self.selected_lines(rule, index) {
+                    if results.is_full() {
+                        break;
+                    }
+                    let Some(line) = source_map.get_line(line_number) else {
+                        continue;
+                    };
+                    let content_end = line.trim_end().len();
+                    if content_end == line.len() {
+                        continue;
+                    }
+                    let column = source_map.column_of(line, content_end);
+                    results.report_at(line_number, column, |_, rule, _| LintResult {
+                        rule: rule.name.clone(),
+                        severity: rule.severity.clone(),
+                        message: "Line contains trailing whitespace".to_string(),
+                        location: Location {
+                            line: line_number,
+                            column,
+                            element: String::new(),
+                            file: None,
+                        },
+                        source: line[content_end..].to_string(),
+                        context: String::new(),
+                        fix_suggestion: Some(FixSuggestion {
+                            description: "Remove trailing whitespace".to_string(),
+                            replacement: Some(String::new()),
+                            is_safe: true,
+                        }),
+                        element_path: String::new(),
+                        fix_hint: rule.fix_hint.clone(),
+                        ..Default::default()
+                    });
+                }
+            }
+            ConditionType::AttributeWhitespace | ConditionType::ClassListOrder => {
+                self.check_attribute_whitespace(rule, index, results)
+            }
+            ConditionType::ConsistentIndentation => self.check_indentation(rule, index, results)?,
+            ConditionType::LineLength => self.check_line_length(rule, index, results)?,
+            ConditionType::EntityConsistency | ConditionType::UnnecessaryEntities => {
+                self.check_entities(rule, index, results)?
+            }
+            ConditionType::NoMixedIndentation => {
+                for (i, line) in index.get_source_map().lines.iter().enumerate() {
+                    if results.is_full() {
+                        break;
+                    }
+                    let indent = leading_whitespace(line);
+                    if indent.contains(' ') && indent.contains('\t') {
+                        Self::report_indentation(
+                            results,
+                            i + 1,
+                            line,
+                            "Line mixes tabs and spaces in its indentation",
+                        );
                     }
                 }
             }
             _ => {}
         }
 
-        Ok(results)
+        Ok(())
+    }
+
+    // Stray whitespace in attribute values, and unsorted class lists
+    fn check_attribute_whitespace(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let allowed = |option: &str| {
+            rule.options
+                .get(option)
+                .is_some_and(|allow| allow == "true")
+        };
+        let allow_leading = allowed("allow_leading");
+        let allow_trailing = allowed("allow_trailing");
+        let allow_internal_multiple = allowed("allow_internal_multiple");
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+
+            // Values are checked as written, so whitespace written as a character reference
+            // such as `&#32;` is deliberate and left alone
+            let tag = &node.source_info.source;
+            let (_, attributes) = parse_start_tag(tag);
+            let mut problems = Vec::new();
+            for attr in &attributes {
+                let name = tag[attr.name.clone()].to_ascii_lowercase();
+                let Some(value) = attr.value.clone().map(|value| &tag[value]) else {
+                    continue;
+                };
+
+                if rule.condition == ConditionType::ClassListOrder {
+                    if name != "class" {
+                        continue;
+                    }
+                    let classes: Vec<&str> = value.split_ascii_whitespace().collect();
+                    let mut sorted = classes.clone();
+                    super::sort_classes(&mut sorted, &[]);
+                    if classes != sorted {
+                        problems.push(format!(
+                            "class names are not in alphabetical order; expected \"{}\"",
+                            sorted.join(" ")
+                        ));
+                    }
+                    continue;
+                }
+
+                let trimmed = value.trim_matches(|c: char| c.is_ascii_whitespace());
+                if trimmed.is_empty() {
+                    continue;
+                }
+                if !allow_leading && value.starts_with(|c: char| c.is_ascii_whitespace()) {
+                    problems.push(format!("{} has leading whitespace", name));
+                }
+                if !allow_trailing && value.ends_with(|c: char| c.is_ascii_whitespace()) {
+                    problems.push(format!("{} has trailing whitespace", name));
+                }
+                if !allow_internal_multiple
+                    && trimmed
+                        .as_bytes()
+                        .windows(2)
+                        .any(|pair| pair.iter().all(u8::is_ascii_whitespace))
+                {
+                    problems.push(format!("{} has consecutive whitespace", name));
+                }
+            }
+
+            for problem in problems {
+                results.report(node, |result| {
+                    result.message = format!("{} ({})", rule.message, problem);
+                });
+            }
+        }
+    }
+
+    fn check_indentation(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let lines = &index.get_source_map().lines;
+
+        let use_tabs = match rule.options.get("style").map(String::as_str) {
+            Some("tabs") => true,
+            Some("spaces") => false,
+            Some(other) => {
+                return Err(LinterError::RuleError(format!(
+                    "Invalid indentation style: {}",
+                    other
+                )))
+            }
+            // Detect the style from the first indented line
+            None => lines
+                .iter()
+                .map(|line| leading_whitespace(line))
+                .find(|indent| !indent.is_empty())
+                .is_some_and(|indent| indent.starts_with('\t')),
+        };
+
+        let size = rule
+            .options
+            .get("size")
+            .map(|size| {
+                size.parse::<usize>()
+                    .ok()
+                    .filter(|&size| size > 0)
+                    .ok_or_else(|| {
+                        LinterError::RuleError(format!("Invalid indentation size: {}", size))
+                    })
+            })
+            .transpose()?;
+
+        for (i, line) in lines.iter().enumerate() {
+            if results.is_full() {
+                break;
+            }
+            let indent = leading_whitespace(line);
+            let problem = if use_tabs {
+                indent
+                    .contains(' ')
+                    .then(|| "Expected tab indentation but found spaces".to_string())
+            } else if indent.contains('\t') {
+                Some("Expected space indentation but found tabs".to_string())
+            } else {
+                size.filter(|&size| !indent.len().is_multiple_of(size))
+                    .map(|size| {
+                        format!(
+                            "Indentation of {} spaces is not a multiple of {}",
+                            indent.len(),
+                            size
+                        )
+                    })
+            };
+
+            if let Some(problem) = problem {
+                Self::report_indentation(results, i + 1, line, &problem);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_line_length(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let max_length = match rule.options.get("max_length") {
+            Some(max) => max.parse::<usize>().map_err(|_| {
+                LinterError::RuleError(format!("Invalid max_length option: {}", max))
+            })?,
+            None => self.options.max_line_length.ok_or_else(|| {
+                LinterError::RuleError(
+                    "max_length option or LinterOptions::max_line_length required for line-length check"
+                        .to_string(),
+                )
+            })?,
+        };
+
+        let source_map = index.get_source_map();
+        for line_number in self.selected_lines(rule, index) {
+            if results.is_full() {
+                break;
+            }
+            let Some(line) = source_map.get_line(line_number) else {
+                continue;
+            };
+            let length = line.chars().count();
+            if length > max_length {
+                let overflow = line
+                    .char_indices()
+                    .nth(max_length)
+                    .map_or(line.len(), |(i, _)| i);
+                let column = source_map.column_of(line, overflow);
+                results.report_at(line_number, column, |_, rule, _| LintResult {
+                    rule: rule.name.clone(),
+                    severity: rule.severity.clone(),
+                    message: format!(
+                        "{} (line is {} characters, limit is {})",
+                        rule.message, length, max_length
+                    ),
+                    location: Location {
+                        line: line_number,
+                        column,
+                        element: String::new(),
+                        file: None,
+                    },
+                    source: format!("{}…", &line[..overflow]),
+                    context: String::new(),
+                    fix_suggestion: None,
+                    element_path: String::new(),
+                    fix_hint: rule.fix_hint.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // With the `*` selector every line, otherwise the lines spanned by matched elements
+    fn selected_lines(&self, rule: &Rule, index: &DOMIndex) -> Vec<usize> {
+        let mut line_numbers: Vec<usize> = if rule.selector == "*" {
+            (1..=index.get_source_map().line_count()).collect()
+        } else {
+            index
+                .query(&rule.selector)
+                .into_iter()
+                .filter_map(|node_idx| {
+                    dom::utils::get_node_line_range(node_idx, index, self.element_context())
+                })
+                .flat_map(|(start, end)| start..=end)
+                .collect()
+        };
+        line_numbers.sort_unstable();
+        line_numbers.dedup();
+        line_numbers
+    }
+
+    fn report_indentation(results: &mut RuleResults, line: usize, text: &str, problem: &str) {
+        results.report_at(line, 1, |_, rule, _| LintResult {
+            rule: rule.name.clone(),
+            severity: rule.severity.clone(),
+            message: format!("{} - {}", rule.message, problem),
+            location: Location {
+                line,
+                column: 1,
+                element: String::new(),
+                file: None,
+            },
+            source: text.to_string(),
+            context: String::new(),
+            fix_suggestion: None,
+            element_path: String::new(),
+            fix_hint: rule.fix_hint.clone(),
+            ..Default::default()
+        });
     }
 
     fn validate_meta_tags(
         &self,
         node_idx: usize,
         meta_rules: &[MetaTagRule],
+        schemas: &[Option<jsonschema::Validator>],
         index: &DOMIndex,
     ) -> Result<bool, LinterError> {
         if let Some(_node) = index.get_node(node_idx) {
-            for rule in meta_rules {
-                let meta_nodes = index.query("meta");
+            let meta_nodes = index.query_within(node_idx, "meta");
+
+            for (rule, schema) in meta_rules.iter().zip(schemas) {
                 let mut found_valid_tag = false;
 
-                for meta_node_idx in meta_nodes {
+                for &meta_node_idx in &meta_nodes {
                     if let Some(meta_node) = index.get_node(meta_node_idx) {
                         let matches_identifier = meta_node.attributes.iter().any(|attr| {
                             let attr_name = index.resolve_symbol(attr.name).unwrap_or_default();
@@ -292,11 +890,18 @@ impl HtmlLinter {
                                 if name == "content" {
                                     let content = value.as_str();
                                     match &rule.pattern {
+                                        // Search results truncate by characters, so count those rather than bytes
                                         PatternRule::MinLength { value: min_len } => {
-                                            content.len() >= *min_len
+                                            content.chars().count() >= *min_len
+                                        }
+                                        PatternRule::MaxLength { value: max_len } => {
+                                            content.chars().count() <= *max_len
+                                        }
+                                        PatternRule::ExactLength { value: len } => {
+                                            content.chars().count() == *len
                                         }
                                         PatternRule::LengthRange { min, max } => {
-                                            content.len() >= *min && content.len() <= *max
+                                            (*min..=*max).contains(&content.chars().count())
                                         }
                                         PatternRule::OneOf { value } => {
                                             value.contains(&content.to_string())
@@ -307,6 +912,14 @@ impl HtmlLinter {
                                             let regex = Regex::new(regex).unwrap();
                                             regex.is_match(content)
                                         }
+                                        PatternRule::UrlPattern(pattern) => {
+                                            validate_url(content, pattern)
+                                        }
+                                        PatternRule::JsonSchema { .. } => {
+                                            schema.as_ref().is_some_and(|schema| {
+                                                matches_json_schema(content, schema)
+                                            })
+                                        }
                                     }
                                 } else {
                                     false
@@ -331,3 +944,58 @@ impl HtmlLinter {
         }
     }
 }
+
+// Leading whitespace of a line, ignoring lines that contain nothing else
+fn leading_whitespace(line: &str) -> &str {
+    let content = line.trim_start();
+    if content.is_empty() {
+        ""
+    } else {
+        &line[..line.len() - content.len()]
+    }
+}
+
+// The `min_key` and `max_key` options, defaulting to no limit
+fn count_limits(rule: &Rule, min_key: &str, max_key: &str) -> Result<(usize, usize), LinterError> {
+    let parse_limit = |key: &str| {
+        rule.options
+            .get(key)
+            .map(|v| {
+                v.parse::<usize>()
+                    .map_err(|_| LinterError::RuleError(format!("Invalid {} option: {}", key, v)))
+            })
+            .transpose()
+    };
+    Ok((
+        parse_limit(min_key)?.unwrap_or(0),
+        parse_limit(max_key)?.unwrap_or(usize::MAX),
+    ))
+}
+
+// Whitespace-delimited tokens that contain at least one letter or digit
+fn count_words(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+// Sentences end with `.`, `!` or `?` followed by whitespace; the last may be unterminated
+fn count_sentences(text: &str) -> usize {
+    let mut count = 0;
+    let mut has_words = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() {
+            has_words = true;
+        } else if matches!(c, '.' | '!' | '?')
+            && chars.peek().is_none_or(|next| next.is_whitespace())
+            && has_words
+        {
+            count += 1;
+            has_words = false;
+        }
+    }
+
+    count + usize::from(has_words)
+}
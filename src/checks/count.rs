@@ -1,3 +1,4 @@
+use crate::dom::tags::parse_start_tag;
 use crate::*;
 
 impl HtmlLinter {
@@ -5,56 +6,135 @@ impl HtmlLinter {
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
-        let matches = index.query(&rule.selector);
-
-        match rule.condition.as_str() {
-            "max-count" => {
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        match rule.condition {
+            ConditionType::MaxCount => {
                 let max_count: usize = rule
                     .options
                     .get("max")
                     .and_then(|v| v.parse().ok())
                     .unwrap_or(1);
 
-                if matches.len() > max_count {
-                    if let Some(&node_idx) = matches.get(max_count) {
-                        if let Some(node) = index.get_node(node_idx) {
-                            results.push(self.create_lint_result(rule, node, index));
-                        }
+                // Only the first match past the limit is reported
+                if let Some(node_idx) = index.query_iter(&rule.selector).nth(max_count) {
+                    if let Some(node) = index.get_node(node_idx) {
+                        results.report(node, |_| {});
                     }
                 }
             }
+            ConditionType::MinCount => {
+                let min_count = Self::count_option(rule, "min", 1)?;
+                let found = index.query_iter(&rule.selector).count();
+
+                if found < min_count {
+                    Self::count_shortfall(results, rule, found, &format!("at least {}", min_count));
+                }
+            }
+            ConditionType::ExactCount => {
+                let count = Self::count_option(rule, "count", 1)?;
+                let matches = index.query(&rule.selector);
+
+                if matches.len() < count {
+                    Self::count_shortfall(
+                        results,
+                        rule,
+                        matches.len(),
+                        &format!("exactly {}", count),
+                    );
+                } else {
+                    Self::count_excess(index, &matches[count..], results);
+                }
+            }
+            ConditionType::RangeCount => {
+                let min_count = Self::count_option(rule, "min", 0)?;
+                let max_count = Self::count_option(rule, "max", usize::MAX)?;
+                let matches = index.query(&rule.selector);
+
+                if matches.len() < min_count {
+                    Self::count_shortfall(
+                        results,
+                        rule,
+                        matches.len(),
+                        &format!("between {} and {}", min_count, max_count),
+                    );
+                } else if matches.len() > max_count {
+                    Self::count_excess(index, &matches[max_count..], results);
+                }
+            }
             _ => {}
         }
 
-        Ok(results)
+        Ok(())
+    }
+
+    fn count_option(rule: &Rule, name: &str, default: usize) -> Result<usize, LinterError> {
+        match rule.options.get(name) {
+            Some(value) => value.parse().map_err(|_| {
+                LinterError::RuleError(format!(
+                    "Invalid {} option for element count: {}",
+                    name, value
+                ))
+            }),
+            None => Ok(default),
+        }
+    }
+
+    // Too few matches leaves no element to blame, so the result points at the document start
+    fn count_shortfall(results: &mut RuleResults, rule: &Rule, found: usize, expected: &str) {
+        results.report_document_start(|| {
+            format!(
+                "{} (found {} '{}', expected {})",
+                rule.message, found, rule.selector, expected
+            )
+        });
+    }
+
+    fn count_excess(index: &DOMIndex, extra: &[usize], results: &mut RuleResults) {
+        for node in extra
+            .iter()
+            .filter_map(|&node_idx| index.get_node(node_idx))
+        {
+            results.report(node, |_| {});
+            if results.is_full() {
+                break;
+            }
+        }
     }
 
     pub(crate) fn check_element_case(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
         let matches = index.query(&rule.selector);
 
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
-                let element_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
-                let has_uppercase = element_name.chars().any(|c| c.is_uppercase());
-
-                let uppercase_attrs: Vec<_> = node
+                // The parser lowercases names, so casing can only be seen in the source. Names it
+                // keeps in camel case, like SVG's `viewBox`, are spelled correctly as written.
+                let source = &node.source_info.source;
+                let (name_span, source_attrs) = parse_start_tag(source);
+                let parsed_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
+                let parsed_attrs: Vec<String> = node
                     .attributes
                     .iter()
-                    .filter_map(|attr| {
-                        let name = index.resolve_symbol(attr.name).unwrap_or_default();
-                        if name.chars().any(|c| c.is_uppercase()) {
-                            Some(name.to_string())
-                        } else {
-                            None
-                        }
-                    })
+                    .filter_map(|attr| index.resolve_symbol(attr.name))
+                    .collect();
+                let miscased = |name: &str, parsed: &[String]| {
+                    name.chars().any(char::is_uppercase) && !parsed.iter().any(|p| p == name)
+                };
+
+                let element_name = &source[name_span];
+                let has_uppercase = miscased(element_name, std::slice::from_ref(&parsed_name));
+                let uppercase_attrs: Vec<&str> = source_attrs
+                    .iter()
+                    .map(|attr| &source[attr.name.clone()])
+                    .filter(|name| miscased(name, &parsed_attrs))
                     .collect();
 
                 if has_uppercase || !uppercase_attrs.is_empty() {
@@ -66,21 +146,28 @@ impl HtmlLinter {
                         message.push_str(&format!(" (attributes: {})", uppercase_attrs.join(", ")));
                     }
 
-                    results.push(LintResult {
-                        rule: rule.name.clone(),
-                        severity: rule.severity.clone(),
-                        message,
-                        location: Location {
-                            line: node.source_info.line,
-                            column: node.source_info.column,
-                            element: element_name.to_string(),
-                        },
-                        source: node.source_info.source.clone(),
+                    results.report(node, |result| {
+                        result.message = message;
+                        result.fix_suggestion = Some(FixSuggestion {
+                            description: "Lowercase element and attribute names".to_string(),
+                            replacement: Some(lowercase_names(&node.source_info.source)),
+                            is_safe: true,
+                        });
                     });
                 }
             }
         }
 
-        Ok(results)
+        Ok(())
+    }
+}
+
+// `tag` with its element and attribute names lowercased, leaving values as written
+fn lowercase_names(tag: &str) -> String {
+    let (name, attributes) = parse_start_tag(tag);
+    let mut result = tag.to_string();
+    for span in attributes.into_iter().map(|attr| attr.name).chain([name]) {
+        result.replace_range(span.clone(), &tag[span].to_ascii_lowercase());
     }
+    result
 }
@@ -1,7 +1,7 @@
-use crate::dom::utils::extract_text;
+use crate::dom::utils::{get_node_depth, has_ancestor_in, missing_references};
 use crate::*;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum CompoundCondition {
     TextContent {
@@ -22,6 +22,30 @@ pub enum CompoundCondition {
     ElementPresence {
         selector: String,
     },
+    ChildPresence {
+        selector: String,
+        #[serde(default)]
+        min_count: usize,
+        #[serde(default)]
+        max_count: Option<usize>,
+    },
+    AncestorPresence {
+        selector: String,
+        required: bool,
+    },
+    DescendantPresence {
+        selector: String,
+        #[serde(default)]
+        min_count: usize,
+        #[serde(default)]
+        max_count: Option<usize>,
+    },
+    NestingDepth {
+        #[serde(default)]
+        min: usize,
+        #[serde(default)]
+        max: Option<usize>,
+    },
     Compound {
         selector: String,
         conditions: Vec<CompoundCondition>,
@@ -40,11 +64,14 @@ impl HtmlLinter {
         rule: &Rule,
         validator: &str,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
         let matches = index.query(&rule.selector);
 
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
                 let (should_report, detailed_message) = match validator {
                     "no-empty-links" => {
@@ -69,33 +96,22 @@ impl HtmlLinter {
                 };
 
                 if should_report {
-                    results.push(LintResult {
-                        rule: rule.name.clone(),
-                        severity: rule.severity.clone(),
-                        message: format!("{} - {}", rule.message, detailed_message),
-                        location: Location {
-                            line: node.source_info.line,
-                            column: node.source_info.column,
-                            element: index
-                                .resolve_symbol(node.tag_name)
-                                .unwrap_or_default()
-                                .to_string(),
-                        },
-                        source: node.source_info.source.clone(),
+                    results.report(node, |result| {
+                        result.message = format!("{} - {}", rule.message, detailed_message);
                     });
                 }
             }
         }
 
-        Ok(results)
+        Ok(())
     }
 
     pub(crate) fn check_compound(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
         let matches = index.query(&rule.selector);
 
         let conditions: Vec<CompoundCondition> = rule
@@ -115,7 +131,24 @@ impl HtmlLinter {
             .map(String::as_str)
             .unwrap_or("all");
 
+        let required_count = match check_mode {
+            "n_of_m" | "exactly_n" => rule
+                .options
+                .get("required_count")
+                .and_then(|count| count.parse::<usize>().ok())
+                .ok_or_else(|| {
+                    LinterError::RuleError(format!(
+                        "required_count option required for {} check mode",
+                        check_mode
+                    ))
+                })?,
+            _ => 0,
+        };
+
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
                 let matching_conditions: Vec<bool> = conditions
                     .iter()
@@ -132,6 +165,10 @@ impl HtmlLinter {
                         let count = matching_conditions.iter().filter(|&&x| x).count();
                         count <= conditions.len() / 2
                     }
+                    "n_of_m" => matching_conditions.iter().filter(|&&x| x).count() < required_count,
+                    "exactly_n" => {
+                        matching_conditions.iter().filter(|&&x| x).count() != required_count
+                    }
                     "weighted" => {
                         let weights = rule
                             .options
@@ -220,6 +257,14 @@ impl HtmlLinter {
                             total_conditions,
                             (total_conditions / 2) + 1
                         ),
+                        "n_of_m" => format!(
+                            "Only {} of {} conditions met; at least {} required",
+                            matching_count, total_conditions, required_count
+                        ),
+                        "exactly_n" => format!(
+                            "{} of {} conditions met; exactly {} required",
+                            matching_count, total_conditions, required_count
+                        ),
                         "weighted" => {
                             let weights = rule
                                 .options
@@ -327,6 +372,47 @@ impl HtmlLinter {
                                         if matched { "exists" } else { "does not exist" }
                                     )
                                 }
+                                CompoundCondition::ChildPresence {
+                                    selector,
+                                    min_count,
+                                    max_count,
+                                } => format!(
+                                    "{} Between {} and {} direct children matching '{}'",
+                                    status,
+                                    min_count,
+                                    max_count
+                                        .map(|max| max.to_string())
+                                        .unwrap_or_else(|| "any number of".to_string()),
+                                    selector
+                                ),
+                                CompoundCondition::DescendantPresence {
+                                    selector,
+                                    min_count,
+                                    max_count,
+                                } => format!(
+                                    "{} Between {} and {} descendants matching '{}'",
+                                    status,
+                                    min_count,
+                                    max_count
+                                        .map(|max| max.to_string())
+                                        .unwrap_or_else(|| "any number of".to_string()),
+                                    selector
+                                ),
+                                CompoundCondition::NestingDepth { min, max } => format!(
+                                    "{} Nesting depth between {} and {}",
+                                    status,
+                                    min,
+                                    max.map(|max| max.to_string())
+                                        .unwrap_or_else(|| "any".to_string())
+                                ),
+                                CompoundCondition::AncestorPresence { selector, required } => {
+                                    format!(
+                                        "{} {} be inside an element matching '{}'",
+                                        status,
+                                        if *required { "Must" } else { "Must not" },
+                                        selector
+                                    )
+                                }
                                 CompoundCondition::Compound {
                                     selector,
                                     conditions,
@@ -344,30 +430,19 @@ impl HtmlLinter {
                         })
                         .collect();
 
-                    results.push(LintResult {
-                        rule: rule.name.clone(),
-                        severity: rule.severity.clone(),
-                        message: format!(
+                    results.report(node, |result| {
+                        result.message = format!(
                             "{} - {} \nCondition details:\n{}",
                             rule.message,
                             detailed_message,
                             condition_details.join("\n")
-                        ),
-                        location: Location {
-                            line: node.source_info.line,
-                            column: node.source_info.column,
-                            element: index
-                                .resolve_symbol(node.tag_name)
-                                .unwrap_or_default()
-                                .to_string(),
-                        },
-                        source: node.source_info.source.clone(),
+                        );
                     });
                 }
             }
         }
 
-        Ok(results)
+        Ok(())
     }
 
     fn check_single_condition(
@@ -416,18 +491,13 @@ impl HtmlLinter {
             }
             CompoundCondition::TextContent { pattern } => {
                 let node = index.get_node(node_idx).unwrap();
-                let mut content = String::new();
-                if let Some(handle) = &node.handle {
-                    extract_text(handle, &mut content);
-                    if content.trim().is_empty() {
-                        return false;
-                    }
-                    Regex::new(pattern)
-                        .map(|regex| regex.is_match(content.trim()))
-                        .unwrap_or(false)
-                } else {
-                    false
+                let content = node.direct_text.trim();
+                if content.is_empty() {
+                    return false;
                 }
+                Regex::new(pattern)
+                    .map(|regex| regex.is_match(content))
+                    .unwrap_or(false)
             }
             CompoundCondition::AttributeValue {
                 attribute,
@@ -487,8 +557,7 @@ impl HtmlLinter {
                     }) {
                         let value = index.resolve_symbol(attr.value).unwrap_or_default();
                         if !value.trim().is_empty() {
-                            let referenced_selector = format!("[id=\"{}\"]", value.trim());
-                            let exists = !index.query(&referenced_selector).is_empty();
+                            let exists = missing_references(&value, "id", index).is_empty();
                             return exists == *reference_must_exist;
                         }
                     }
@@ -496,13 +565,39 @@ impl HtmlLinter {
                 false
             }
             CompoundCondition::ElementPresence { selector } => {
-                if let Some(node) = index.get_node(node_idx) {
-                    let current_selector = format!("{} {}", node.get_selector(index), selector);
-                    let matches = index.query(&current_selector);
-                    !matches.is_empty()
-                } else {
-                    false
-                }
+                !index.query_within(node_idx, selector).is_empty()
+            }
+            CompoundCondition::ChildPresence {
+                selector,
+                min_count,
+                max_count,
+            } => {
+                let count = index
+                    .query(selector)
+                    .into_iter()
+                    .filter(|&idx| {
+                        index
+                            .get_node(idx)
+                            .is_some_and(|child| child.parent == Some(node_idx))
+                    })
+                    .count();
+                count >= *min_count && max_count.is_none_or(|max| count <= max)
+            }
+            CompoundCondition::AncestorPresence { selector, required } => {
+                let ancestors = index.query(selector);
+                has_ancestor_in(node_idx, &ancestors, index) == *required
+            }
+            CompoundCondition::DescendantPresence {
+                selector,
+                min_count,
+                max_count,
+            } => {
+                let count = index.query_within(node_idx, selector).len();
+                count >= *min_count && max_count.is_none_or(|max| count <= max)
+            }
+            CompoundCondition::NestingDepth { min, max } => {
+                let depth = get_node_depth(node_idx, index, self.element_context());
+                depth >= *min && max.is_none_or(|max| depth <= max)
             }
         }
     }
@@ -0,0 +1,173 @@
+use crate::dom::utils::get_attribute;
+use crate::*;
+
+// The whole charset declaration must fit within the first 1024 bytes (HTML5 §4.2.5.4)
+const CHARSET_SCAN_LIMIT: usize = 1024;
+
+// Common labels from the WHATWG Encoding Standard, which browsers map to IANA encodings
+const VALID_CHARSETS: &[&str] = &[
+    "utf-8",
+    "utf8",
+    "unicode-1-1-utf-8",
+    "utf-16",
+    "utf-16le",
+    "utf-16be",
+    "us-ascii",
+    "ascii",
+    "iso-8859-1",
+    "latin1",
+    "iso-8859-2",
+    "iso-8859-3",
+    "iso-8859-4",
+    "iso-8859-5",
+    "iso-8859-6",
+    "iso-8859-7",
+    "iso-8859-8",
+    "iso-8859-8-i",
+    "iso-8859-10",
+    "iso-8859-13",
+    "iso-8859-14",
+    "iso-8859-15",
+    "iso-8859-16",
+    "windows-874",
+    "windows-1250",
+    "windows-1251",
+    "windows-1252",
+    "windows-1253",
+    "windows-1254",
+    "windows-1255",
+    "windows-1256",
+    "windows-1257",
+    "windows-1258",
+    "koi8-r",
+    "koi8-u",
+    "ibm866",
+    "macintosh",
+    "x-mac-cyrillic",
+    "gbk",
+    "gb2312",
+    "gb18030",
+    "big5",
+    "euc-jp",
+    "iso-2022-jp",
+    "shift_jis",
+    "euc-kr",
+];
+
+impl HtmlLinter {
+    pub(crate) fn check_encoding(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let declarations = Self::charset_declarations(index);
+
+        match rule.condition {
+            ConditionType::CharsetDeclared => match declarations.first() {
+                None => results.report_document_start(|| {
+                    format!("{} (no <meta charset> declaration)", rule.message)
+                }),
+                Some(&(node_idx, _)) => {
+                    let Some(node) = index.get_node(node_idx) else {
+                        return Ok(());
+                    };
+                    let end = Self::declaration_offset(node, index)
+                        .map(|offset| offset + node.source_info.source.len());
+                    if let Some(end) = end.filter(|&end| end > CHARSET_SCAN_LIMIT) {
+                        results.report(node, |result| {
+                            result.message = format!(
+                                "{} (the charset declaration must be within the first {} bytes of the document; it ends at byte {})",
+                                rule.message, CHARSET_SCAN_LIMIT, end
+                            );
+                        });
+                    }
+                }
+            },
+            ConditionType::CharsetValid => {
+                for (node_idx, charset) in declarations {
+                    if VALID_CHARSETS.contains(&charset.to_ascii_lowercase().as_str()) {
+                        continue;
+                    }
+                    if let Some(node) = index.get_node(node_idx) {
+                        results.report(node, |result| {
+                            result.message =
+                                format!("{} (unknown charset '{}')", rule.message, charset);
+                        });
+                    }
+                }
+            }
+            ConditionType::BomDetected if index.get_source().starts_with('\u{FEFF}') => {
+                results.report_document_start(|| {
+                    format!(
+                        "{} (document starts with a UTF-8 byte order mark)",
+                        rule.message
+                    )
+                });
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    // `<meta charset>` and `<meta http-equiv="content-type" content="...; charset=...">`,
+    // in document order, with the declared charset
+    pub(crate) fn charset_declarations(index: &DOMIndex) -> Vec<(usize, String)> {
+        index
+            .query("meta")
+            .into_iter()
+            .filter_map(|node_idx| {
+                let node = index.get_node(node_idx)?;
+                let attribute = |name: &str| get_attribute(node, name, index);
+
+                let charset = attribute("charset").or_else(|| {
+                    attribute("http-equiv")
+                        .filter(|equiv| equiv.trim().eq_ignore_ascii_case("content-type"))
+                        .and(attribute("content"))
+                        .and_then(|content| {
+                            let lower = content.to_ascii_lowercase();
+                            let start = lower.find("charset=")? + "charset=".len();
+                            Some(
+                                content[start..]
+                                    .split(';')
+                                    .next()
+                                    .unwrap_or_default()
+                                    .to_string(),
+                            )
+                        })
+                })?;
+                Some((
+                    node_idx,
+                    charset
+                        .trim()
+                        .trim_matches(|c| c == '"' || c == '\'')
+                        .to_string(),
+                ))
+            })
+            .collect()
+    }
+
+    // Byte offset of the declaring tag, from its line and column. Tags whose position is
+    // unknown fall back to the first `<meta` carrying a charset in the raw source.
+    fn declaration_offset(node: &IndexedNode, index: &DOMIndex) -> Option<usize> {
+        let source_map = index.get_source_map();
+        let line = node.source_info.line;
+        if line > 0 {
+            let text = source_map.get_line(line)?;
+            let line_start = *source_map.line_offsets.get(line - 1)?;
+            return Some(line_start + source_map.byte_index(text, node.source_info.column));
+        }
+
+        let lower = index.get_source().to_ascii_lowercase();
+        lower
+            .match_indices("<meta")
+            .map(|(offset, _)| offset)
+            .find(|&offset| {
+                lower[offset..]
+                    .split('>')
+                    .next()
+                    .is_some_and(|tag| tag.contains("charset"))
+            })
+    }
+}
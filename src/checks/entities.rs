@@ -0,0 +1,251 @@
+use crate::dom::tags::SourceTags;
+use crate::*;
+use html5ever::data::NAMED_ENTITIES;
+use std::collections::HashMap;
+
+// Characters that are markup in HTML source and always need some form of escaping
+const MARKUP_CHARACTERS: &[char] = &['&', '<', '>', '"', '\''];
+
+// Invisible characters that are clearer as references even in UTF-8 documents
+const INVISIBLE_CHARACTERS: &[char] = &[
+    '\u{00AD}', '\u{200B}', '\u{200C}', '\u{200D}', '\u{200E}', '\u{200F}', '\u{2060}', '\u{2061}',
+    '\u{2062}', '\u{2063}', '\u{2064}', '\u{FEFF}',
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum CharacterForm {
+    Named,
+    Numeric,
+    Unicode,
+}
+
+// One way a character is written in the source: a character reference or the raw character
+struct CharacterOccurrence {
+    start: usize, // Byte offsets into the source
+    end: usize,
+    character: char,
+    form: CharacterForm,
+}
+
+impl HtmlLinter {
+    /// Handles the `entity-consistency` and `unnecessary-entities` whitespace conditions.
+    pub(crate) fn check_entities(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let prefer = match rule.options.get("prefer").map(String::as_str) {
+            Some("named") => Some(CharacterForm::Named),
+            Some("numeric") => Some(CharacterForm::Numeric),
+            Some("unicode") => Some(CharacterForm::Unicode),
+            Some(other) => {
+                return Err(LinterError::RuleError(format!(
+                    "Invalid prefer option: {}",
+                    other
+                )))
+            }
+            None => None,
+        };
+
+        let source = index.get_source();
+        let occurrences = character_occurrences(source);
+
+        if rule.condition == ConditionType::UnnecessaryEntities {
+            // Only UTF-8 documents can carry every character unencoded
+            let utf8 = Self::charset_declarations(index)
+                .first()
+                .is_none_or(|(_, charset)| {
+                    matches!(
+                        charset.to_ascii_lowercase().as_str(),
+                        "utf-8" | "utf8" | "unicode-1-1-utf-8"
+                    )
+                });
+            if !utf8 {
+                return Ok(());
+            }
+
+            for occurrence in &occurrences {
+                if results.is_full() {
+                    break;
+                }
+                if occurrence.form != CharacterForm::Unicode
+                    && can_be_unencoded(occurrence.character)
+                {
+                    let replacement = occurrence.character.to_string();
+                    Self::report_entity(
+                        results,
+                        index,
+                        occurrence,
+                        &replacement,
+                        "can be written as",
+                    );
+                }
+            }
+            return Ok(());
+        }
+
+        // Without a preference, the first form used for each character is the convention
+        let mut conventions: HashMap<char, String> = HashMap::new();
+        for occurrence in &occurrences {
+            if results.is_full() {
+                break;
+            }
+            let text = &source[occurrence.start..occurrence.end];
+            let expected = match prefer {
+                Some(form) => preferred_text(occurrence.character, form),
+                None => {
+                    let convention = conventions
+                        .entry(occurrence.character)
+                        .or_insert_with(|| text.to_string());
+                    (form_of(convention) != occurrence.form).then(|| convention.clone())
+                }
+            };
+
+            if let Some(expected) = expected.filter(|expected| expected != text) {
+                if form_of(&expected) != occurrence.form {
+                    Self::report_entity(
+                        results,
+                        index,
+                        occurrence,
+                        &expected,
+                        "should be written as",
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn report_entity(
+        results: &mut RuleResults,
+        index: &DOMIndex,
+        occurrence: &CharacterOccurrence,
+        replacement: &str,
+        problem: &str,
+    ) {
+        let text = &index.get_source()[occurrence.start..occurrence.end];
+        let (line, column) = index.get_source_map().get_position(occurrence.start);
+
+        results.report_at(line, column, |_, rule, _| LintResult {
+            rule: rule.name.clone(),
+            severity: rule.severity.clone(),
+            message: format!(
+                "{} - `{}` {} `{}`",
+                rule.message, text, problem, replacement
+            ),
+            location: Location {
+                line,
+                column,
+                element: String::new(),
+                file: None,
+            },
+            source: text.to_string(),
+            context: String::new(),
+            fix_suggestion: Some(FixSuggestion {
+                description: format!("Replace `{}` with `{}`", text, replacement),
+                replacement: Some(replacement.to_string()),
+                is_safe: true,
+            }),
+            element_path: String::new(),
+            fix_hint: rule.fix_hint.clone(),
+            ..Default::default()
+        });
+    }
+}
+
+// Character references that resolve to a single character, plus raw non-ASCII characters.
+// Only text is scanned; markup, comments and raw text such as scripts are left alone.
+fn character_occurrences(source: &str) -> Vec<CharacterOccurrence> {
+    let reference =
+        Regex::new(r"&(?:#[xX]([0-9a-fA-F]{1,6})|#([0-9]{1,7})|([A-Za-z][A-Za-z0-9]*));").unwrap();
+
+    let mut occurrences = Vec::new();
+    for range in SourceTags::scan(source).text {
+        let text = &source[range.clone()];
+        let mut references = Vec::new();
+        for caps in reference.captures_iter(text) {
+            let whole = caps.get(0).unwrap();
+            let (character, form) = if let Some(hex) = caps.get(1) {
+                let code = u32::from_str_radix(hex.as_str(), 16).ok();
+                (code.and_then(char::from_u32), CharacterForm::Numeric)
+            } else if let Some(decimal) = caps.get(2) {
+                let code = decimal.as_str().parse::<u32>().ok();
+                (code.and_then(char::from_u32), CharacterForm::Numeric)
+            } else {
+                let name = format!("{};", &caps[3]);
+                let character = NAMED_ENTITIES
+                    .get(name.as_str())
+                    .filter(|&&(_, second)| second == 0)
+                    .and_then(|&(code, _)| char::from_u32(code));
+                (character, CharacterForm::Named)
+            };
+            if let Some(character) = character {
+                references.push(CharacterOccurrence {
+                    start: range.start + whole.start(),
+                    end: range.start + whole.end(),
+                    character,
+                    form,
+                });
+            }
+        }
+
+        for (start, character) in text.char_indices().filter(|(_, c)| !c.is_ascii()) {
+            references.push(CharacterOccurrence {
+                start: range.start + start,
+                end: range.start + start + character.len_utf8(),
+                character,
+                form: CharacterForm::Unicode,
+            });
+        }
+
+        references.sort_by_key(|occurrence| occurrence.start);
+        occurrences.extend(references);
+    }
+
+    occurrences
+}
+
+fn form_of(text: &str) -> CharacterForm {
+    if text.starts_with("&#") {
+        CharacterForm::Numeric
+    } else if text.starts_with('&') && text.len() > 1 {
+        CharacterForm::Named
+    } else {
+        CharacterForm::Unicode
+    }
+}
+
+fn can_be_unencoded(character: char) -> bool {
+    !MARKUP_CHARACTERS.contains(&character)
+        && !INVISIBLE_CHARACTERS.contains(&character)
+        && !character.is_control()
+        && !character.is_whitespace()
+}
+
+// How `character` is written in the preferred form, or `None` when that form can't express it
+fn preferred_text(character: char, form: CharacterForm) -> Option<String> {
+    match form {
+        CharacterForm::Named => entity_name(character).map(|name| format!("&{}", name)),
+        CharacterForm::Numeric => Some(format!("&#{};", character as u32)),
+        CharacterForm::Unicode => can_be_unencoded(character).then(|| character.to_string()),
+    }
+}
+
+// The shortest name for `character`, e.g. `mdash;`, preferring lowercase spellings such as `copy;`
+fn entity_name(character: char) -> Option<&'static str> {
+    NAMED_ENTITIES
+        .entries()
+        .filter(|(name, &(code, second))| {
+            name.ends_with(';') && second == 0 && code == character as u32
+        })
+        .map(|(name, _)| *name)
+        .min_by_key(|name| {
+            (
+                name.len(),
+                name.bytes().any(|b| b.is_ascii_uppercase()),
+                *name,
+            )
+        })
+}
@@ -0,0 +1,217 @@
+use crate::*;
+
+// Markup flagged `true` when the rule accepts it, `false` when it is reported
+type Example = (bool, &'static str);
+
+// Longer descriptions and examples for the built-in WCAG and security rules, by rule name
+const BUILTIN_RULE_DOCS: &[(&str, &str, &[Example])] = &[
+    (
+        "wcag-img-alt",
+        "Screen readers announce an image by its alt text, and fall back to the file name \
+         when it is missing. Decorative images should have an empty alt so that they are skipped.",
+        &[
+            (false, r#"<img src="chart.png">"#),
+            (true, r#"<img src="chart.png" alt="Sales doubled in 2024">"#),
+            (true, r#"<img src="divider.png" alt="">"#),
+        ],
+    ),
+    (
+        "wcag-table-headers",
+        "Header cells let assistive technology announce which row and column a data cell \
+         belongs to. Headers styled to look bold with <td> carry no such meaning.",
+        &[
+            (
+                false,
+                "<table><tr><td><b>Name</b></td></tr><tr><td>Ada</td></tr></table>",
+            ),
+            (
+                true,
+                "<table><tr><th>Name</th></tr><tr><td>Ada</td></tr></table>",
+            ),
+        ],
+    ),
+    (
+        "wcag-input-purpose",
+        "Inputs that collect personal data should say what they collect with an autocomplete \
+         token, so browsers can fill them in and assistive technology can add familiar icons.",
+        &[
+            (false, r#"<input type="email" name="email">"#),
+            (
+                true,
+                r#"<input type="email" name="email" autocomplete="email">"#,
+            ),
+        ],
+    ),
+    (
+        "wcag-page-title",
+        "The title is the first thing announced when a page loads and names its browser tab.",
+        &[
+            (false, "<html><head></head><body></body></html>"),
+            (
+                true,
+                "<html><head><title>Order history - Shop</title></head><body></body></html>",
+            ),
+        ],
+    ),
+    (
+        "wcag-heading-order",
+        "Screen reader users navigate by headings, and a skipped level suggests that a \
+         section is missing from the outline.",
+        &[
+            (false, "<h1>Products</h1><h3>Shoes</h3>"),
+            (true, "<h1>Products</h1><h2>Shoes</h2>"),
+        ],
+    ),
+    (
+        "wcag-html-lang",
+        "The page language tells screen readers which pronunciation rules to use and lets \
+         browsers offer translation.",
+        &[
+            (false, "<html><body></body></html>"),
+            (true, r#"<html lang="en"><body></body></html>"#),
+        ],
+    ),
+    (
+        "wcag-control-name",
+        "A form control without a label is announced only by its type, leaving users to \
+         guess what to enter.",
+        &[
+            (false, r#"<input type="text" name="city">"#),
+            (
+                true,
+                r#"<label for="city">City</label><input type="text" id="city" name="city">"#,
+            ),
+        ],
+    ),
+    (
+        "wcag-link-name",
+        "Links are announced by their content, so a link without any is announced as just \
+         \"link\" and gives no hint of where it leads.",
+        &[
+            (false, r#"<a href="/cart"></a>"#),
+            (true, r#"<a href="/cart">Cart</a>"#),
+        ],
+    ),
+    (
+        "wcag-aria-role",
+        "Some ARIA roles only make sense with their states, such as whether a checkbox is \
+         checked. Without them assistive technology can't report the widget's value.",
+        &[
+            (false, r#"<div role="checkbox">Subscribe</div>"#),
+            (
+                true,
+                r#"<div role="checkbox" aria-checked="false">Subscribe</div>"#,
+            ),
+        ],
+    ),
+    (
+        "no-inline-event-handlers",
+        "Inline handlers such as onclick run as script from attribute text, which defeats a \
+         Content Security Policy and makes injected markup executable.",
+        &[
+            (false, r#"<button onclick="save()">Save</button>"#),
+            (true, r#"<button id="save">Save</button>"#),
+        ],
+    ),
+    (
+        "no-javascript-urls",
+        "A javascript: URL runs script when followed, so links built from user input become \
+         an XSS vector.",
+        &[
+            (false, r#"<a href="javascript:save()">Save</a>"#),
+            (true, r#"<a href="/save">Save</a>"#),
+        ],
+    ),
+    (
+        "no-data-uri-src",
+        "data: URIs embed content that never passes through the server, which can hide \
+         untrusted markup or script in images and frames.",
+        &[
+            (
+                false,
+                r#"<iframe src="data:text/html;base64,PHNjcmlwdD4="></iframe>"#,
+            ),
+            (true, r#"<iframe src="/embed/video"></iframe>"#),
+        ],
+    ),
+    (
+        "safe-link-targets",
+        "A page opened with target=\"_blank\" can navigate its opener through window.opener \
+         unless the link sets rel=\"noopener\".",
+        &[
+            (
+                false,
+                r#"<a href="https://example.com" target="_blank">Docs</a>"#,
+            ),
+            (
+                true,
+                r#"<a href="https://example.com" target="_blank" rel="noopener">Docs</a>"#,
+            ),
+        ],
+    ),
+    (
+        "no-meta-refresh",
+        "Meta refresh redirects happen without user action and can send visitors to a \
+         phishing page; they also disorient screen reader users.",
+        &[
+            (
+                false,
+                r#"<meta http-equiv="refresh" content="0; url=https://example.com">"#,
+            ),
+            (true, r#"<meta charset="utf-8">"#),
+        ],
+    ),
+];
+
+impl HtmlLinter {
+    /// Documents the configured rule called `name`, or the built-in WCAG or security rule of
+    /// that name. Built-in rules come with a longer description and examples; other rules
+    /// are described by their message.
+    pub fn explain_rule(&self, name: &str) -> Option<RuleDoc> {
+        let configured = self.rules.iter().find(|rule| rule.name == name).cloned();
+        let rule = configured.or_else(|| {
+            Self::wcag_aa_rules()
+                .into_iter()
+                .chain(Self::security_rules())
+                .find(|rule| rule.name == name)
+        })?;
+        Some(Self::rule_doc(&rule))
+    }
+
+    /// Names of the configured rules, in order.
+    pub fn list_rules(&self) -> Vec<String> {
+        self.rules.iter().map(|rule| rule.name.clone()).collect()
+    }
+
+    pub(crate) fn rule_doc(rule: &Rule) -> RuleDoc {
+        let builtin = BUILTIN_RULE_DOCS
+            .iter()
+            .find(|(name, _, _)| *name == rule.name);
+
+        RuleDoc {
+            name: rule.name.clone(),
+            rule_type: match &rule.rule_type {
+                RuleType::Custom(validator) => format!("Custom({})", validator),
+                rule_type => format!("{:?}", rule_type),
+            },
+            severity: rule.severity.clone(),
+            description: builtin
+                .map(|(_, description, _)| description.to_string())
+                .unwrap_or_else(|| rule.message.clone()),
+            examples: builtin
+                .map(|(_, _, examples)| {
+                    examples
+                        .iter()
+                        .map(|&(valid, html)| RuleExample {
+                            valid,
+                            html: html.to_string(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            message: rule.message.clone(),
+            fix_hint: rule.fix_hint.clone(),
+            tags: rule.tags.clone(),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::dom::utils::get_attribute;
+use crate::*;
+use std::collections::BTreeSet;
+
+const FORM_CHECKS: &[&str] = &[
+    "input-label-association",
+    "required-fields",
+    "form-method",
+    "autocomplete",
+];
+
+// Input types that are labelled by their own value or need no label at all
+const UNLABELLED_INPUT_TYPES: &[&str] = &["hidden", "submit", "reset", "button", "image"];
+
+const TEXT_INPUT_TYPES: &[&str] = &["text", "email", "tel", "url", "search", "password"];
+
+const FORM_METHODS: &[&str] = &["get", "post", "dialog"];
+
+impl HtmlLinter {
+    pub(crate) fn check_form_validation(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let checks: Vec<String> = match rule.condition {
+            ConditionType::FormValidation => match rule.options.get("checks") {
+                Some(checks) => serde_json::from_str(checks)
+                    .map_err(|e| LinterError::RuleError(format!("Invalid checks JSON: {}", e)))?,
+                None => FORM_CHECKS.iter().map(|check| check.to_string()).collect(),
+            },
+            ref condition => vec![condition.to_string()],
+        };
+        if let Some(unknown) = checks
+            .iter()
+            .find(|check| !FORM_CHECKS.contains(&check.as_str()))
+        {
+            return Err(LinterError::RuleError(format!(
+                "Unknown form validation check: {}",
+                unknown
+            )));
+        }
+
+        // The selector scopes the checks; matched elements and their descendants are inspected
+        let mut scope = BTreeSet::new();
+        for node_idx in index.query(&rule.selector) {
+            // Query results are in document order, so a scoped ancestor already covers this subtree
+            if scope.insert(node_idx) {
+                scope.extend(index.query_within(node_idx, "*"));
+            }
+        }
+
+        for node_idx in scope {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
+            let attribute = |name: &str| get_attribute(node, name, index);
+            let input_type = attribute("type")
+                .map(|value| value.trim().to_ascii_lowercase())
+                .unwrap_or_else(|| "text".to_string());
+            let is_control = match tag_name.as_str() {
+                "input" => !UNLABELLED_INPUT_TYPES.contains(&input_type.as_str()),
+                "select" | "textarea" => true,
+                _ => false,
+            };
+
+            for check in &checks {
+                let problem = match check.as_str() {
+                    "input-label-association" => (is_control
+                        && !self.has_label_parent(node_idx, index)
+                        && !self.has_matching_label(node_idx, index))
+                    .then(|| "Form control has no associated label".to_string()),
+                    "required-fields" => (attribute("required").is_some()
+                        && attribute("name").is_none_or(|name| name.trim().is_empty()))
+                    .then(|| "Required field has no name".to_string()),
+                    "form-method" => attribute("method")
+                        .filter(|method| {
+                            tag_name == "form"
+                                && !FORM_METHODS
+                                    .contains(&method.trim().to_ascii_lowercase().as_str())
+                        })
+                        .map(|method| format!("Invalid form method '{}'", method)),
+                    "autocomplete" => (tag_name == "input"
+                        && TEXT_INPUT_TYPES.contains(&input_type.as_str())
+                        && attribute("autocomplete").is_none())
+                    .then(|| "Text input has no autocomplete attribute".to_string()),
+                    _ => None,
+                };
+
+                if let Some(problem) = problem {
+                    results.report(node, |result| {
+                        result.message = format!("{} - {}", rule.message, problem);
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use super::json_schema;
+use crate::*;
+use serde_json::Value;
+
+const SOURCE_PREVIEW_CHARS: usize = 200;
+
+impl HtmlLinter {
+    pub(crate) fn check_json_ld(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let required_type = rule.options.get("required_type");
+        let schema_version = rule.options.get("schema_version");
+        let required_fields = Self::parse_string_list(rule, "required_fields")?;
+        let required_schemas = Self::parse_string_list(rule, "required_schemas")?;
+        let schema = rule
+            .options
+            .get("schema")
+            .map(|schema| {
+                serde_json::from_str(schema)
+                    .map_err(|e| LinterError::RuleError(format!("Invalid schema JSON: {}", e)))
+                    .and_then(|schema| json_schema::compile(&schema))
+            })
+            .transpose()?;
+
+        let mut found_types: Vec<String> = Vec::new();
+        let matches = index.query(&rule.selector);
+
+        for &node_idx in &matches {
+            if results.is_full() {
+                break;
+            }
+            if let Some(node) = index.get_node(node_idx) {
+                let json = node.direct_text.trim();
+
+                let mut problems = Vec::new();
+                match serde_json::from_str::<Value>(json) {
+                    Ok(value) => {
+                        let types = json_ld_types(&value);
+
+                        if let Some(required_type) = required_type {
+                            if !types.contains(required_type) {
+                                problems.push(format!("missing @type '{}'", required_type));
+                            }
+                        }
+
+                        if let Some(version) = schema_version {
+                            if !has_context(&value, version) {
+                                problems.push(format!("@context must be '{}'", version));
+                            }
+                        }
+
+                        for pointer in &required_fields {
+                            if value.pointer(pointer).is_none_or(Value::is_null) {
+                                problems.push(format!("missing required field '{}'", pointer));
+                            }
+                        }
+
+                        if let Some(schema) = &schema {
+                            problems.extend(json_schema::validate(schema, &value));
+                        }
+
+                        found_types.extend(types);
+                    }
+                    Err(e) => problems.push(format!("invalid JSON: {}", e)),
+                }
+
+                if !problems.is_empty() {
+                    results.report(node, |result| {
+                        result.message = format!("{} - {}", rule.message, problems.join("; "));
+                        result.source = json.chars().take(SOURCE_PREVIEW_CHARS).collect();
+                    });
+                }
+            }
+        }
+
+        // Required schemas are checked across every JSON-LD block in the document
+        let missing_schemas: Vec<&str> = required_schemas
+            .iter()
+            .filter(|schema| !found_types.contains(schema))
+            .map(String::as_str)
+            .collect();
+        if !missing_schemas.is_empty() {
+            let message = format!(
+                "{} - missing structured data for {}",
+                rule.message,
+                missing_schemas.join(", ")
+            );
+            // Reported at the first JSON-LD block, or the document start when there is none
+            let first_block = matches
+                .first()
+                .and_then(|&node_idx| index.get_node(node_idx))
+                .filter(|node| node.source_info.line > 0);
+            match first_block {
+                Some(node) => results.report(node, |result| {
+                    result.message = message;
+                    result.source = String::new();
+                }),
+                None => results.report_document_start(|| message),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn parse_string_list(rule: &Rule, option: &str) -> Result<Vec<String>, LinterError> {
+        rule.options
+            .get(option)
+            .map(|list| {
+                serde_json::from_str(list)
+                    .map_err(|e| LinterError::RuleError(format!("Invalid {} JSON: {}", option, e)))
+            })
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
+
+/// Collects every `@type` declared by a JSON-LD document, including arrays and `@graph` entries.
+fn json_ld_types(value: &Value) -> Vec<String> {
+    let mut types = Vec::new();
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                types.extend(json_ld_types(item));
+            }
+        }
+        Value::Object(map) => {
+            match map.get("@type") {
+                Some(Value::String(t)) => types.push(t.clone()),
+                Some(Value::Array(ts)) => {
+                    types.extend(ts.iter().filter_map(Value::as_str).map(str::to_string))
+                }
+                _ => {}
+            }
+            if let Some(graph) = map.get("@graph") {
+                types.extend(json_ld_types(graph));
+            }
+        }
+        _ => {}
+    }
+    types
+}
+
+fn has_context(value: &Value, expected: &str) -> bool {
+    let expected = expected.trim_end_matches('/');
+    let matches = |context: &Value| {
+        context
+            .as_str()
+            .is_some_and(|c| c.trim_end_matches('/') == expected)
+    };
+
+    match value {
+        Value::Array(items) => {
+            !items.is_empty() && items.iter().all(|item| has_context(item, expected))
+        }
+        Value::Object(map) => match map.get("@context") {
+            Some(Value::Array(contexts)) => contexts.iter().any(matches),
+            Some(context) => matches(context),
+            None => false,
+        },
+        _ => false,
+    }
+}
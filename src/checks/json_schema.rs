@@ -0,0 +1,26 @@
+use crate::LinterError;
+use jsonschema::{Draft, Validator};
+use serde_json::Value;
+
+/// Compiles a JSON Schema (draft-07, asserting `format`) so a rule builds it once rather
+/// than once per validated value. Schemas that don't compile, such as one with an invalid
+/// `pattern`, are rule errors.
+pub(super) fn compile(schema: &Value) -> Result<Validator, LinterError> {
+    jsonschema::options()
+        .with_draft(Draft::Draft7)
+        .should_validate_formats(true)
+        .build(schema)
+        .map_err(|e| LinterError::RuleError(format!("Invalid JSON schema: {}", e)))
+}
+
+/// Validates `value`, returning one message per violation prefixed with the JSON Pointer of
+/// the offending value.
+pub(super) fn validate(validator: &Validator, value: &Value) -> Vec<String> {
+    validator
+        .iter_errors(value)
+        .map(|error| {
+            let path = error.instance_path.as_str();
+            format!("{}: {}", if path.is_empty() { "/" } else { path }, error)
+        })
+        .collect()
+}
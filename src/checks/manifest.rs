@@ -0,0 +1,121 @@
+use crate::dom::utils::{closest_ancestor_of_type, get_attribute};
+use crate::*;
+use url::Url;
+
+const CROSSORIGIN_VALUES: &[&str] = &["anonymous", "use-credentials"];
+
+const WEB_APP_CAPABLE_META: &[&str] = &["mobile-web-app-capable", "apple-mobile-web-app-capable"];
+
+impl HtmlLinter {
+    pub(crate) fn check_manifest(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        if rule.condition == ConditionType::WebAppCapable {
+            let capable = index.query("meta[name]").into_iter().any(|idx| {
+                index
+                    .get_node(idx)
+                    .and_then(|node| get_attribute(node, "name", index))
+                    .is_some_and(|name| {
+                        WEB_APP_CAPABLE_META.contains(&name.to_ascii_lowercase().as_str())
+                    })
+            });
+            if !capable {
+                results.report_document_start(|| {
+                    format!(
+                        "{} (missing <meta name=\"mobile-web-app-capable\">)",
+                        rule.message
+                    )
+                });
+            }
+            return Ok(());
+        }
+
+        let require_crossorigin = rule
+            .options
+            .get("require_crossorigin")
+            .is_some_and(|require| require == "true");
+        let allow_absolute = rule
+            .options
+            .get("allow_absolute")
+            .is_some_and(|allow| allow == "true");
+
+        let links = index.query("link[rel~=manifest]");
+        if links.is_empty() {
+            results.report_document_start(|| {
+                format!("{} (missing <link rel=\"manifest\">)", rule.message)
+            });
+            return Ok(());
+        }
+
+        let document_url = Self::document_url(index);
+        for link_idx in links {
+            if results.is_full() {
+                break;
+            }
+            let Some(link) = index.get_node(link_idx) else {
+                continue;
+            };
+            let mut problems = Vec::new();
+
+            if closest_ancestor_of_type(link_idx, "head", index).is_none() {
+                problems.push("manifest link must be in <head>".to_string());
+            }
+
+            let href = get_attribute(link, "href", index).unwrap_or_default();
+            let href = href.trim();
+            // Protocol-relative URLs such as `//cdn.example.com/app.webmanifest` name a host too
+            let absolute = href.starts_with("//") || Url::parse(href).is_ok();
+            let cross_origin = match &document_url {
+                Some(document_url) => document_url
+                    .join(href)
+                    .is_ok_and(|url| url.origin() != document_url.origin()),
+                None => absolute,
+            };
+            if href.is_empty() {
+                problems.push("missing href".to_string());
+            } else if absolute && !allow_absolute {
+                problems.push(format!(
+                    "manifest href \"{}\" should be a relative URL",
+                    href
+                ));
+            }
+
+            let crossorigin = get_attribute(link, "crossorigin", index);
+            match crossorigin {
+                Some(value)
+                    if !CROSSORIGIN_VALUES.contains(&value.to_ascii_lowercase().as_str()) =>
+                {
+                    problems.push(format!(
+                        "crossorigin must be \"anonymous\" or \"use-credentials\", not \"{}\"",
+                        value
+                    ))
+                }
+                None if require_crossorigin || cross_origin => {
+                    problems.push("missing crossorigin attribute".to_string())
+                }
+                _ => {}
+            }
+
+            for problem in problems {
+                results.report(link, |result| {
+                    result.message = format!("{} ({})", rule.message, problem);
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // The document's own URL, when `<base href>` or a canonical link states it absolutely
+    fn document_url(index: &DOMIndex) -> Option<Url> {
+        ["base[href]", "link[rel~=canonical][href]"]
+            .into_iter()
+            .flat_map(|selector| index.query(selector))
+            .filter_map(|idx| index.get_node(idx))
+            .filter_map(|node| get_attribute(node, "href", index))
+            .find_map(|href| Url::parse(href.trim()).ok())
+    }
+}
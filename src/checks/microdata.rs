@@ -0,0 +1,131 @@
+use crate::dom::utils::{get_attribute, get_node_text_content};
+use crate::*;
+use url::Url;
+
+// Elements whose microdata value comes from an attribute rather than their text
+const VALUE_ATTRIBUTES: &[(&[&str], &str)] = &[
+    (&["meta"], "content"),
+    (
+        &[
+            "audio", "embed", "iframe", "img", "source", "track", "video",
+        ],
+        "src",
+    ),
+    (&["a", "area", "link"], "href"),
+    (&["object"], "data"),
+    (&["data", "meter"], "value"),
+    (&["time"], "datetime"),
+];
+
+impl HtmlLinter {
+    pub(crate) fn check_microdata(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let required_types = Self::parse_string_list(rule, "required_types")?;
+
+        for scope_idx in index.query("[itemscope]") {
+            if results.is_full() {
+                break;
+            }
+            let Some(scope) = index.get_node(scope_idx) else {
+                continue;
+            };
+            let mut problems = Vec::new();
+
+            match get_attribute(scope, "itemtype", index) {
+                None => problems.push("missing itemtype".to_string()),
+                Some(item_type) => {
+                    // `itemtype` may list several types from the same vocabulary
+                    let types: Vec<&str> = item_type.split_ascii_whitespace().collect();
+                    if types.is_empty() {
+                        problems.push("itemtype is empty".to_string());
+                    }
+                    for item_type in &types {
+                        if Url::parse(item_type).is_err() {
+                            problems.push(format!("itemtype \"{}\" is not a valid URL", item_type));
+                        }
+                    }
+                    if !required_types.is_empty()
+                        && !types.is_empty()
+                        && !types
+                            .iter()
+                            .any(|item_type| required_types.iter().any(|t| t == item_type))
+                    {
+                        problems.push(format!(
+                            "itemtype \"{}\" is not one of the accepted types",
+                            item_type.trim()
+                        ));
+                    }
+                }
+            }
+
+            if closest_item_scope(scope_idx, index).is_some()
+                && get_attribute(scope, "itemprop", index).is_none()
+            {
+                problems.push("nested itemscope has no itemprop".to_string());
+            }
+
+            for prop_idx in index.query_within(scope_idx, "[itemprop]") {
+                let Some(prop) = index.get_node(prop_idx) else {
+                    continue;
+                };
+                // Properties of nested items are checked with their own scope
+                if closest_item_scope(prop_idx, index) != Some(scope_idx)
+                    || get_attribute(prop, "itemscope", index).is_some()
+                {
+                    continue;
+                }
+
+                let name = get_attribute(prop, "itemprop", index).unwrap_or_default();
+                if name.trim().is_empty() {
+                    problems.push("itemprop attribute is empty".to_string());
+                } else if property_value(prop_idx, index).trim().is_empty() {
+                    problems.push(format!("itemprop \"{}\" has no value", name.trim()));
+                }
+            }
+
+            for problem in problems {
+                results.report(scope, |result| {
+                    result.message = format!("{} ({})", rule.message, problem);
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Nearest ancestor of `node_idx` that starts an item.
+fn closest_item_scope(node_idx: usize, index: &DOMIndex) -> Option<usize> {
+    let mut current = index.get_node(node_idx).and_then(|node| node.parent);
+    while let Some(parent_idx) = current {
+        let parent = index.get_node(parent_idx)?;
+        if get_attribute(parent, "itemscope", index).is_some() {
+            return Some(parent_idx);
+        }
+        current = parent.parent;
+    }
+    None
+}
+
+// The property value per the microdata spec: an attribute for media, links and the like,
+// otherwise the element's text. `time` falls back to its text without `datetime`.
+fn property_value(node_idx: usize, index: &DOMIndex) -> String {
+    let Some(node) = index.get_node(node_idx) else {
+        return String::new();
+    };
+    let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
+
+    match VALUE_ATTRIBUTES
+        .iter()
+        .find(|(tags, _)| tags.contains(&tag_name.as_str()))
+    {
+        Some((_, "datetime")) => get_attribute(node, "datetime", index)
+            .unwrap_or_else(|| get_node_text_content(node_idx, index)),
+        Some((_, attribute)) => get_attribute(node, attribute, index).unwrap_or_default(),
+        None => get_node_text_content(node_idx, index),
+    }
+}
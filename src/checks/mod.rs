@@ -1,7 +1,83 @@
+mod aria;
 mod attributes;
 mod content;
 mod count;
 mod custom;
+mod encoding;
+mod entities;
+mod explain;
+mod forms;
+mod json_ld;
+mod json_schema;
+mod manifest;
+mod microdata;
 mod presence;
+mod security;
 mod semantics;
+mod social;
 mod structure;
+mod wcag;
+
+use crate::dom::tags::SourceTag;
+use crate::{DOMIndex, HtmlLinter, LintResult, Location, Rule};
+
+impl HtmlLinter {
+    // Something missing from the document has no element to blame, so it's reported at the
+    // document start
+    pub(crate) fn document_start_result(rule: &Rule, message: String) -> LintResult {
+        LintResult {
+            rule: rule.name.clone(),
+            severity: rule.severity.clone(),
+            message,
+            location: Location {
+                line: 1,
+                column: 1,
+                ..Default::default()
+            },
+            fix_hint: rule.fix_hint.clone(),
+            ..Default::default()
+        }
+    }
+
+    // Markup the parser repairs or drops has no element of its own, so it's reported at the tag
+    // as written
+    pub(crate) fn source_tag_result(
+        rule: &Rule,
+        tag: &SourceTag,
+        index: &DOMIndex,
+        message: String,
+    ) -> LintResult {
+        let (line, column) = index.get_source_map().get_position(tag.span.start);
+        LintResult {
+            rule: rule.name.clone(),
+            severity: rule.severity.clone(),
+            message,
+            location: Location {
+                line,
+                column,
+                element: tag.name.clone(),
+                file: None,
+            },
+            source: index.get_source()[tag.span.clone()].to_string(),
+            context: index.get_source_map().context(line, column),
+            fix_hint: rule.fix_hint.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+// Sorts class names for `class-order` and `class-list-order`: alphabetically ignoring case, or
+// with `prefix_groups` base classes first and then each group in turn. The sort is stable, so
+// classes keep their relative order within a group.
+fn sort_classes(classes: &mut [&str], prefix_groups: &[String]) {
+    if prefix_groups.is_empty() {
+        classes.sort_by_key(|class| class.to_lowercase());
+    } else {
+        classes.sort_by_key(|class| {
+            prefix_groups
+                .iter()
+                .position(|prefix| class.starts_with(prefix.as_str()))
+                .map_or(0, |group| group + 1)
+        });
+    }
+}
@@ -5,55 +5,123 @@ impl HtmlLinter {
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
-        let matches = index.query(&rule.selector);
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        // A missing element has no location of its own, so report it at the document start.
+        // Only existence matters here, so the query stops at the first match.
+        if matches!(
+            rule.condition,
+            ConditionType::ElementPresent | ConditionType::Required
+        ) {
+            let violated = index.query_first(&rule.selector).is_none();
+            if violated {
+                results.report_document_start(|| rule.message.clone());
+            }
+            return Ok(());
+        }
 
-        for node_idx in matches {
+        // Also document-level: one result when the trigger is present without its requirement
+        if rule.condition == ConditionType::ConditionalPresence {
+            let trigger = rule
+                .options
+                .get("trigger_selector")
+                .unwrap_or(&rule.selector);
+            let required = rule.options.get("required_selector").ok_or_else(|| {
+                LinterError::RuleError(
+                    "required_selector option required for conditional-presence".to_string(),
+                )
+            })?;
+            if index.query_first(trigger).is_some() && index.query_first(required).is_none() {
+                results.report_document_start(|| {
+                    format!("{} (found {} but no {})", rule.message, trigger, required)
+                });
+            }
+            return Ok(());
+        }
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
-                let should_report = match rule.condition.as_str() {
-                    "required" => false,
-                    "forbidden" => true,
-                    "semantic-alternative-available" => {
+                let should_report = match rule.condition {
+                    ConditionType::Forbidden | ConditionType::ElementAbsent => true,
+                    ConditionType::SemanticAlternativeAvailable => {
                         !self.check_semantic_alternative(node_idx, index)
                     }
-                    "element-present" => false,
-                    "doctype-present" => !index.has_doctype(),
+                    ConditionType::DoctypePresent => !index.has_doctype(),
                     _ => false,
                 };
 
                 if should_report {
-                    results.push(self.create_lint_result(rule, node, index));
+                    results.report(node, |_| {});
                 }
             }
         }
 
-        Ok(results)
+        Ok(())
     }
 
     pub(crate) fn check_attribute_presence(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        if rule.condition == ConditionType::MutuallyExclusive {
+            return self.check_mutually_exclusive_attributes(rule, index, results);
+        }
+        if rule.condition == ConditionType::RequiredWhenParent {
+            return self.check_required_when_parent(rule, index, results);
+        }
+
+        let not_empty_attribute = if rule.condition == ConditionType::AttributeNotEmpty {
+            rule.options
+                .get("attribute")
+                .map(String::as_str)
+                .ok_or_else(|| {
+                    LinterError::RuleError(
+                        "attribute option required for attribute-not-empty check".to_string(),
+                    )
+                })?
+        } else {
+            ""
+        };
+        let allow_whitespace_only = rule
+            .options
+            .get("allow_whitespace_only")
+            .is_some_and(|allow| allow == "true");
+
         let matches = index.query(&rule.selector);
 
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
-                let should_report = match rule.condition.as_str() {
-                    "duplicate-attributes" => Self::has_duplicate_attributes(node, index),
-                    "alt-missing" => Self::is_attribute_missing(node, index, &rule.condition),
-                    "style-attribute" if !self.options.allow_inline_styles => {
+                let should_report = match rule.condition {
+                    ConditionType::DuplicateAttributes => {
+                        Self::has_duplicate_attributes(node, index)
+                    }
+                    ConditionType::AltMissing => {
+                        Self::is_attribute_missing(node, index, rule.condition.as_str())
+                    }
+                    ConditionType::StyleAttribute if !self.options.allow_inline_styles => {
                         Self::has_style_attribute(node, index)
                     }
-                    "alt-attribute" => Self::is_attribute_missing(node, index, "alt"),
-                    "lang-attribute" => Self::is_attribute_missing(node, index, "lang"),
+                    ConditionType::AltAttribute => Self::is_attribute_missing(node, index, "alt"),
+                    ConditionType::LangAttribute => Self::is_attribute_missing(node, index, "lang"),
+                    ConditionType::AttributeNotEmpty => Self::is_attribute_value_empty(
+                        node,
+                        index,
+                        not_empty_attribute,
+                        allow_whitespace_only,
+                    ),
                     _ => false,
                 };
 
                 if should_report {
-                    let message = if rule.condition == "duplicate-attributes" {
+                    let message = if rule.condition == ConditionType::DuplicateAttributes {
                         let mut duplicates = Vec::new();
                         let mut seen = std::collections::HashMap::new();
 
@@ -69,29 +137,158 @@ impl HtmlLinter {
                         }
 
                         format!("{} (duplicates: {})", rule.message, duplicates.join(", "))
+                    } else if rule.condition == ConditionType::AttributeNotEmpty {
+                        format!("{} ({} is empty)", rule.message, not_empty_attribute)
                     } else {
                         rule.message.clone()
                     };
 
-                    results.push(LintResult {
-                        rule: rule.name.clone(),
-                        severity: rule.severity.clone(),
-                        message,
-                        location: Location {
-                            line: node.source_info.line,
-                            column: node.source_info.column,
-                            element: index
-                                .resolve_symbol(node.tag_name)
-                                .unwrap_or_default()
-                                .to_string(),
-                        },
-                        source: node.source_info.source.clone(),
+                    results.report(node, |result| {
+                        result.message = message;
                     });
                 }
             }
         }
 
-        Ok(results)
+        Ok(())
+    }
+
+    fn check_mutually_exclusive_attributes(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let groups: Vec<Vec<String>> = rule
+            .options
+            .get("attribute_groups")
+            .ok_or_else(|| {
+                LinterError::RuleError(
+                    "attribute_groups option required for mutually-exclusive check".to_string(),
+                )
+            })
+            .and_then(|json| {
+                serde_json::from_str(json).map_err(|e| {
+                    LinterError::RuleError(format!("Invalid attribute_groups JSON: {}", e))
+                })
+            })?;
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            // Group entries are attribute names, or `name=value` to only count that value
+            let present = |entry: &String| {
+                let (name, value) = match entry.split_once('=') {
+                    Some((name, value)) => (name.trim(), Some(value.trim())),
+                    None => (entry.trim(), None),
+                };
+                node.attributes.iter().any(|attr| {
+                    index
+                        .resolve_symbol(attr.name)
+                        .unwrap_or_default()
+                        .eq_ignore_ascii_case(name)
+                        && value.is_none_or(|value| {
+                            index.resolve_symbol(attr.value).unwrap_or_default().trim() == value
+                        })
+                })
+            };
+
+            for group in &groups {
+                let found: Vec<&str> = group
+                    .iter()
+                    .filter(|entry| present(entry))
+                    .map(String::as_str)
+                    .collect();
+                if found.len() > 1 {
+                    results.report(node, |result| {
+                        result.message = format!(
+                            "{} ({} cannot be used together)",
+                            rule.message,
+                            found.join(" and ")
+                        );
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_required_when_parent(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let option = |name: &str| {
+            rule.options.get(name).ok_or_else(|| {
+                LinterError::RuleError(format!(
+                    "{} option required for required-when-parent check",
+                    name
+                ))
+            })
+        };
+        let attribute = option("attribute")?;
+        let parents: HashSet<usize> = index
+            .query(option("parent_selector")?)
+            .into_iter()
+            .collect();
+        // 1 only accepts the direct parent; without it any ancestor counts
+        let depth = rule
+            .options
+            .get("require_depth")
+            .map(|depth| {
+                depth
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&depth| depth > 0)
+                    .ok_or_else(|| {
+                        LinterError::RuleError(format!("Invalid require_depth option: {}", depth))
+                    })
+            })
+            .transpose()?;
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+
+            let mut in_parent = false;
+            let mut current = node.parent;
+            let mut level = 1;
+            while let Some(parent_idx) = current {
+                if depth.is_some_and(|depth| level > depth) {
+                    break;
+                }
+                if parents.contains(&parent_idx) && depth.is_none_or(|depth| level == depth) {
+                    in_parent = true;
+                    break;
+                }
+                current = index.get_node(parent_idx).and_then(|parent| parent.parent);
+                level += 1;
+            }
+
+            let has_attribute = node.attributes.iter().any(|attr| {
+                index
+                    .resolve_symbol(attr.name)
+                    .unwrap_or_default()
+                    .eq_ignore_ascii_case(attribute)
+            });
+            if in_parent && !has_attribute {
+                results.report(node, |result| {
+                    result.message = format!("{} (missing {} attribute)", rule.message, attribute);
+                });
+            }
+        }
+
+        Ok(())
     }
 
     fn check_semantic_alternative(&self, node_idx: usize, index: &DOMIndex) -> bool {
@@ -210,6 +407,27 @@ impl HtmlLinter {
             .any(|attr| index.resolve_symbol(attr.name).unwrap_or_default() == attr_name)
     }
 
+    // Present but blank; absent attributes are left to the `*-missing` conditions
+    #[inline]
+    fn is_attribute_value_empty(
+        node: &IndexedNode,
+        index: &DOMIndex,
+        attr_name: &str,
+        allow_whitespace_only: bool,
+    ) -> bool {
+        node.attributes
+            .iter()
+            .find(|attr| index.resolve_symbol(attr.name).unwrap_or_default() == attr_name)
+            .and_then(|attr| index.resolve_symbol(attr.value))
+            .is_some_and(|value| {
+                if allow_whitespace_only {
+                    value.is_empty()
+                } else {
+                    value.trim().is_empty()
+                }
+            })
+    }
+
     #[inline]
     fn has_style_attribute(node: &IndexedNode, index: &DOMIndex) -> bool {
         node.attributes
@@ -0,0 +1,132 @@
+use crate::dom::utils::{get_attribute, url_scheme};
+use crate::*;
+
+const SAFE_TARGETS: &[&str] = &["_self", "_parent", "_top"];
+
+impl HtmlLinter {
+    pub(crate) fn check_security_pattern(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let event_attribute = Regex::new(r"^on[a-z]+$").unwrap();
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
+            let attribute = |name: &str| get_attribute(node, name, index);
+
+            let problems: Vec<String> = match rule.condition {
+                ConditionType::OnEventAttributes => node
+                    .attributes
+                    .iter()
+                    .filter_map(|attr| index.resolve_symbol(attr.name))
+                    .filter(|name| event_attribute.is_match(name))
+                    .map(|name| format!("inline event handler '{}'", name))
+                    .collect(),
+                ConditionType::JavascriptHref => attribute("href")
+                    .filter(|href| url_scheme(href).as_deref() == Some("javascript"))
+                    .map(|_| "javascript: URL in href".to_string())
+                    .into_iter()
+                    .collect(),
+                ConditionType::DataUriSrc => attribute("src")
+                    .filter(|src| {
+                        matches!(tag_name.as_str(), "img" | "iframe")
+                            && url_scheme(src).as_deref() == Some("data")
+                    })
+                    .map(|_| format!("data: URI in <{}> src", tag_name))
+                    .into_iter()
+                    .collect(),
+                ConditionType::UnsafeTarget => attribute("target")
+                    .filter(|target| {
+                        !SAFE_TARGETS.contains(&target.trim().to_ascii_lowercase().as_str())
+                            && !attribute("rel").is_some_and(|rel| {
+                                rel.split_ascii_whitespace().any(|token| {
+                                    token.eq_ignore_ascii_case("noopener")
+                                        || token.eq_ignore_ascii_case("noreferrer")
+                                })
+                            })
+                    })
+                    .map(|target| format!("target='{}' without rel='noopener'", target))
+                    .into_iter()
+                    .collect(),
+                ConditionType::MetaRefresh => attribute("http-equiv")
+                    .filter(|equiv| {
+                        tag_name == "meta" && equiv.trim().eq_ignore_ascii_case("refresh")
+                    })
+                    .map(|_| "meta refresh redirect".to_string())
+                    .into_iter()
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            for problem in problems {
+                results.report(node, |result| {
+                    result.message = format!("{} - {}", rule.message, problem);
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Built-in rules covering every `SecurityPattern` condition, all reported as errors.
+    pub fn security_rules() -> Vec<Rule> {
+        [
+            (
+                "no-inline-event-handlers",
+                "*",
+                "on-event-attributes",
+                "Inline event handlers are XSS-prone",
+                "Move the handler into a script and attach it with addEventListener",
+            ),
+            (
+                "no-javascript-urls",
+                "[href]",
+                "javascript-href",
+                "javascript: URLs are XSS-prone",
+                "Use a button with an event listener, or link to a real URL",
+            ),
+            (
+                "no-data-uri-src",
+                "[src]",
+                "data-uri-src",
+                "data: URIs can smuggle untrusted content",
+                "Serve the resource from its own URL instead of inlining it as a data: URI",
+            ),
+            (
+                "safe-link-targets",
+                "[target]",
+                "unsafe-target",
+                "Links opening a new context should use rel='noopener'",
+                r#"Add rel="noopener" (or rel="noreferrer") alongside target="_blank""#,
+            ),
+            (
+                "no-meta-refresh",
+                "meta",
+                "meta-refresh",
+                "Meta refresh redirects can be abused for phishing",
+                "Redirect on the server with a 301 or 302 response instead",
+            ),
+        ]
+        .into_iter()
+        .map(|(name, selector, condition, message, fix_hint)| Rule {
+            name: name.to_string(),
+            rule_type: RuleType::SecurityPattern,
+            severity: Severity::Error,
+            selector: selector.to_string(),
+            condition: condition.into(),
+            message: message.to_string(),
+            options: HashMap::new(),
+            tags: vec!["security".to_string()],
+            fix_hint: fix_hint.to_string(),
+        })
+        .collect()
+    }
+}
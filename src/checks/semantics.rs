@@ -1,34 +1,819 @@
+use super::structure::{is_interactive, INTERACTIVE_ELEMENTS};
+use crate::dom::tags::SourceTags;
+use crate::dom::utils::{
+    build_element_path, closest_ancestor_of_type, get_attribute, has_ancestor_in,
+};
+use crate::dom::NodeKind;
 use crate::*;
+use std::collections::BTreeMap;
+
+// Landmarks that may appear at most once per page. `header` and `footer` only count as
+// page landmarks when they aren't scoped to sectioning content or `main`.
+const UNIQUE_LANDMARKS: &[&str] = &["main", "header", "footer"];
+const LANDMARK_SCOPES: &[&str] = &["article", "aside", "main", "nav", "section"];
+
+// (element, attribute, replacement advice); `*` matches any element. `type` stays valid on
+// `ol`, where it picks the numbering style.
+const DEPRECATED_ATTRIBUTES: &[(&str, &str, &str)] = &[
+    ("*", "align", "use CSS instead"),
+    ("*", "valign", "use CSS instead"),
+    ("body", "background", "use CSS instead"),
+    ("body", "bgcolor", "use CSS instead"),
+    ("body", "text", "use CSS instead"),
+    ("body", "link", "use CSS instead"),
+    ("body", "vlink", "use CSS instead"),
+    ("body", "alink", "use CSS instead"),
+    ("table", "bgcolor", "use CSS instead"),
+    ("table", "border", "use CSS instead"),
+    ("table", "cellpadding", "use CSS instead"),
+    ("table", "cellspacing", "use CSS instead"),
+    ("table", "frame", "use CSS instead"),
+    ("table", "rules", "use CSS instead"),
+    ("table", "width", "use CSS instead"),
+    ("tr", "bgcolor", "use CSS instead"),
+    ("td", "bgcolor", "use CSS instead"),
+    ("td", "height", "use CSS instead"),
+    ("td", "nowrap", "use CSS instead"),
+    ("td", "width", "use CSS instead"),
+    ("th", "bgcolor", "use CSS instead"),
+    ("th", "height", "use CSS instead"),
+    ("th", "nowrap", "use CSS instead"),
+    ("th", "width", "use CSS instead"),
+    ("img", "border", "use CSS instead"),
+    ("img", "hspace", "use CSS instead"),
+    ("img", "vspace", "use CSS instead"),
+    ("hr", "noshade", "use CSS instead"),
+    ("hr", "size", "use CSS instead"),
+    ("hr", "width", "use CSS instead"),
+    ("br", "clear", "use CSS instead"),
+    ("ul", "type", "use CSS instead"),
+    ("li", "type", "use CSS instead"),
+    ("iframe", "frameborder", "use CSS instead"),
+    ("iframe", "scrolling", "use CSS instead"),
+    ("script", "language", "use the type attribute instead"),
+    ("a", "name", "use id instead"),
+    ("html", "version", "remove it"),
+    ("head", "profile", "remove it"),
+];
+
+// (element, replacement, `deprecated-usage` reason, `semantic-elements` purpose) for
+// presentational elements with a semantic replacement
+const DEPRECATED_ELEMENTS: &[(&str, &str, &str, &str)] = &[
+    ("b", "strong", "for semantic emphasis", "strong importance"),
+    ("i", "em", "for semantic emphasis", "emphasized text"),
+];
+
+// Class fragments that mark an `<hr>` as decoration rather than a thematic break
+const DECORATIVE_HR_CLASSES: &[&str] = &["decor", "ornament", "spacer", "flourish", "fancy"];
+
+// States and properties each role requires (WAI-ARIA 1.1), for the `role-required-attributes`
+// condition. Stricter than the AriaRole table, which follows ARIA 1.2's defaults for value ranges.
+const ROLE_REQUIRED_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("checkbox", &["aria-checked"]),
+    ("combobox", &["aria-expanded"]),
+    ("heading", &["aria-level"]),
+    ("menuitemcheckbox", &["aria-checked"]),
+    ("menuitemradio", &["aria-checked"]),
+    ("meter", &["aria-valuenow"]),
+    ("option", &["aria-selected"]),
+    ("radio", &["aria-checked"]),
+    (
+        "scrollbar",
+        &[
+            "aria-controls",
+            "aria-valuenow",
+            "aria-valuemin",
+            "aria-valuemax",
+        ],
+    ),
+    (
+        "slider",
+        &["aria-valuenow", "aria-valuemin", "aria-valuemax"],
+    ),
+    (
+        "spinbutton",
+        &["aria-valuenow", "aria-valuemin", "aria-valuemax"],
+    ),
+    ("switch", &["aria-checked"]),
+];
+
+// (children, parents they must sit directly inside) for the list- and table-context conditions
+const LIST_CONTEXTS: &[(&[&str], &[&str])] =
+    &[(&["li"], &["ul", "ol", "menu"]), (&["dt", "dd"], &["dl"])];
+const TABLE_CONTEXTS: &[(&[&str], &[&str])] = &[
+    (&["td", "th"], &["tr"]),
+    (&["tr"], &["thead", "tbody", "tfoot", "table"]),
+];
+
+// The input purposes listed by WCAG 2.1 section 7, used by the `autocomplete` condition
+const AUTOCOMPLETE_TOKENS: &[&str] = &[
+    "name",
+    "honorific-prefix",
+    "given-name",
+    "additional-name",
+    "family-name",
+    "honorific-suffix",
+    "nickname",
+    "username",
+    "new-password",
+    "current-password",
+    "organization-title",
+    "organization",
+    "street-address",
+    "address-line1",
+    "address-line2",
+    "address-line3",
+    "address-level4",
+    "address-level3",
+    "address-level2",
+    "address-level1",
+    "country",
+    "country-name",
+    "postal-code",
+    "cc-name",
+    "cc-given-name",
+    "cc-additional-name",
+    "cc-family-name",
+    "cc-number",
+    "cc-exp",
+    "cc-exp-month",
+    "cc-exp-year",
+    "cc-csc",
+    "cc-type",
+    "transaction-currency",
+    "transaction-amount",
+    "language",
+    "bday",
+    "bday-day",
+    "bday-month",
+    "bday-year",
+    "sex",
+    "url",
+    "photo",
+    "tel",
+    "tel-country-code",
+    "tel-national",
+    "tel-area-code",
+    "tel-local",
+    "tel-local-prefix",
+    "tel-local-suffix",
+    "tel-extension",
+    "email",
+    "impp",
+];
+const AUTOCOMPLETE_QUERIES: &[&str] = &[
+    "input[type=text]",
+    "input[type=email]",
+    "input[type=tel]",
+    "input[type=url]",
+    "input[name]",
+];
+// Input types that never collect personal data as free text
+const NON_TEXT_INPUT_TYPES: &[&str] = &[
+    "hidden", "submit", "button", "reset", "image", "checkbox", "radio", "file", "range", "color",
+];
+
+#[derive(Deserialize)]
+struct DeprecatedAttribute {
+    element: String,
+    attribute: String,
+}
 
 impl HtmlLinter {
     pub(crate) fn check_semantics(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        if rule.condition == ConditionType::LandmarkUniqueness {
+            self.check_landmark_uniqueness(rule, index, results);
+            return Ok(());
+        }
+
+        if rule.condition == ConditionType::DeprecatedAttributes {
+            return self.check_deprecated_attributes(rule, index, results);
+        }
+
+        if rule.condition == ConditionType::DeprecatedUsage {
+            return self.check_deprecated_usage(rule, index, results);
+        }
+
+        if rule.condition == ConditionType::FormFieldsetLegend {
+            self.check_fieldset_legend(rule, index, results);
+            return Ok(());
+        }
+
+        if rule.condition == ConditionType::AriaLiveRegions {
+            self.check_aria_live_regions(rule, index, results);
+            return Ok(());
+        }
+
+        if rule.condition == ConditionType::ListContext {
+            self.check_list_context(rule, index, results);
+            return Ok(());
+        }
+
+        if rule.condition == ConditionType::TableContext {
+            self.check_table_context(rule, index, results);
+            return Ok(());
+        }
+
+        if rule.condition == ConditionType::FigcaptionContent
+            || rule.condition == ConditionType::FigureStructure
+        {
+            self.check_figures(rule, index, results);
+            return Ok(());
+        }
+
+        if rule.condition == ConditionType::Autocomplete {
+            return self.check_autocomplete(rule, index, results);
+        }
+
+        if rule.condition == ConditionType::RoleRequiredAttributes {
+            self.check_role_required_attributes(rule, index, results);
+            return Ok(());
+        }
+
         let matches = index.query(&rule.selector);
 
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(_node) = index.get_node(node_idx) {
-                let should_report = match rule.condition.as_str() {
-                    "semantic-elements" => self.check_semantic_elements(rule, index)?,
-                    "semantic-landmarks" => self.check_semantic_landmarks(node_idx, index),
-                    "semantic-buttons" => self.check_semantic_buttons(node_idx, index),
-                    "semantic-tables" => self.check_semantic_tables(node_idx, index),
-                    _ => vec![],
+                match rule.condition {
+                    ConditionType::SemanticElements => {
+                        self.check_semantic_elements(index, results)?
+                    }
+                    ConditionType::SemanticLandmarks => {
+                        self.check_semantic_landmarks(node_idx, index, results)
+                    }
+                    ConditionType::SemanticButtons => {
+                        self.check_semantic_buttons(node_idx, index, results)
+                    }
+                    ConditionType::SemanticTables => {
+                        self.check_semantic_tables(node_idx, index, results)
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_landmark_uniqueness(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let mut scopes: Vec<usize> = LANDMARK_SCOPES
+            .iter()
+            .flat_map(|&scope| index.query(scope))
+            .collect();
+        scopes.sort_unstable();
+
+        for &landmark in UNIQUE_LANDMARKS {
+            let page_level = index
+                .query(landmark)
+                .into_iter()
+                .filter(|&idx| landmark == "main" || !has_ancestor_in(idx, &scopes, index));
+
+            // The first occurrence is fine; every later one is reported where it appears
+            for node_idx in page_level.skip(1) {
+                if results.is_full() {
+                    break;
+                }
+                if let Some(node) = index.get_node(node_idx) {
+                    results.report(node, |result| {
+                        result.message =
+                            format!("{} (more than one page-level <{}>)", rule.message, landmark);
+                    });
+                }
+            }
+        }
+    }
+
+    fn check_list_context(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let tag_of = |node_idx: usize| {
+            index
+                .get_node(node_idx)
+                .and_then(|node| index.resolve_symbol(node.tag_name))
+                .unwrap_or_default()
+        };
+
+        for &(children, parents) in LIST_CONTEXTS {
+            let mut matches: Vec<usize> =
+                children.iter().flat_map(|&tag| index.query(tag)).collect();
+            matches.sort_unstable();
+
+            for node_idx in matches {
+                if results.is_full() {
+                    break;
+                }
+                let Some(node) = index.get_node(node_idx) else {
+                    continue;
                 };
+                let Some(parent_idx) = node.parent else {
+                    continue;
+                };
+                // Same-named elements inside SVG or MathML aren't HTML lists or tables
+                if ["svg", "math"]
+                    .iter()
+                    .any(|foreign| closest_ancestor_of_type(node_idx, foreign, index).is_some())
+                {
+                    continue;
+                }
+                let mut parent = tag_of(parent_idx);
+                // `dl` may group each name and its values in a `div`
+                if parent == "div" && parents.contains(&"dl") {
+                    if let Some(grandparent_idx) = index.get_node(parent_idx).and_then(|p| p.parent)
+                    {
+                        if tag_of(grandparent_idx) == "dl" {
+                            parent = "dl".to_string();
+                        }
+                    }
+                }
+                if parents.contains(&parent.as_str()) {
+                    continue;
+                }
+
+                results.report(node, |result| {
+                    result.message = context_message(rule, &tag_of(node_idx), parents, &parent);
+                });
+            }
+        }
+    }
+
+    // The parser moves or drops misplaced cells and rows, so tables are checked as written
+    fn check_table_context(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let source = index.get_source();
+        let scanned = SourceTags::scan(source);
+
+        for nested in scanned.nesting(source) {
+            let Some(&(_, parents)) = TABLE_CONTEXTS
+                .iter()
+                .find(|(children, _)| children.contains(&nested.tag.name.as_str()))
+            else {
+                continue;
+            };
+            // Same-named elements inside SVG or MathML aren't table parts
+            if nested.foreign {
+                continue;
+            }
+            // A fragment's top level has an unknown parent, and a document's is its body
+            let parent = match nested.parent {
+                Some(parent) => parent,
+                None if index.is_fragment() => continue,
+                None => "body",
+            };
+            if !parents.contains(&parent) {
+                let message = context_message(rule, &nested.tag.name, parents, parent);
+                results.report_source_tag(nested.tag, || message);
+            }
+        }
+    }
+
+    // Captions are descriptive, so they shouldn't hold controls, and each figure has one
+    // caption as its first or last child
+    fn check_figures(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let mut report = |node_idx: usize, problem: String| {
+            if let Some(node) = index.get_node(node_idx) {
+                results.report(node, |result| {
+                    result.message = format!("{} ({})", rule.message, problem);
+                });
+            }
+        };
+        let tag_of = |node_idx: usize| {
+            index
+                .get_node(node_idx)
+                .and_then(|node| index.resolve_symbol(node.tag_name))
+                .unwrap_or_default()
+        };
+
+        if rule.condition == ConditionType::FigcaptionContent {
+            for caption_idx in index.query("figcaption") {
+                let mut pending: Vec<usize> = index
+                    .get_node(caption_idx)
+                    .map(|caption| caption.children.iter().rev().copied().collect())
+                    .unwrap_or_default();
+                while let Some(node_idx) = pending.pop() {
+                    let tag = tag_of(node_idx);
+                    if INTERACTIVE_ELEMENTS.contains(&tag.as_str())
+                        && is_interactive(node_idx, index)
+                    {
+                        report(
+                            node_idx,
+                            format!("interactive <{}> inside <figcaption>", tag),
+                        );
+                    }
+                    if let Some(node) = index.get_node(node_idx) {
+                        pending.extend(node.children.iter().rev());
+                    }
+                }
+            }
+            return;
+        }
+
+        for figure_idx in index.query("figure") {
+            let Some(figure) = index.get_node(figure_idx) else {
+                continue;
+            };
+            let children: Vec<usize> = figure
+                .children
+                .iter()
+                .copied()
+                .filter(|&child_idx| {
+                    index
+                        .get_node(child_idx)
+                        .is_some_and(|child| child.kind == NodeKind::Element)
+                })
+                .collect();
+            let captions: Vec<usize> = children
+                .iter()
+                .copied()
+                .filter(|&child_idx| tag_of(child_idx) == "figcaption")
+                .collect();
+
+            match captions.as_slice() {
+                [] => report(figure_idx, "figure has no figcaption".to_string()),
+                [caption] => {
+                    if children.first() != Some(caption) && children.last() != Some(caption) {
+                        report(
+                            *caption,
+                            "figcaption must be the first or last child of its figure".to_string(),
+                        );
+                    }
+                }
+                captions => report(
+                    figure_idx,
+                    format!("figure has {} figcaptions", captions.len()),
+                ),
+            }
+        }
+    }
+
+    fn check_fieldset_legend(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        // Problems are collected first so they're reported in document order
+        let mut problems: Vec<(&IndexedNode, String)> = Vec::new();
+        let mut report = |node_idx: usize, problem: String| {
+            if let Some(node) = index.get_node(node_idx) {
+                problems.push((node, problem));
+            }
+        };
+
+        for fieldset_idx in index.query("fieldset") {
+            let Some(fieldset) = index.get_node(fieldset_idx) else {
+                continue;
+            };
+            let legends = fieldset
+                .children
+                .iter()
+                .filter(|&&child_idx| {
+                    index.get_node(child_idx).is_some_and(|child| {
+                        index.resolve_symbol(child.tag_name).unwrap_or_default() == "legend"
+                    })
+                })
+                .count();
+            match legends {
+                1 => {}
+                0 => report(fieldset_idx, "fieldset has no legend".to_string()),
+                n => report(fieldset_idx, format!("fieldset has {} legends", n)),
+            }
+        }
+
+        // Radio buttons sharing a name form one group, which needs a single enclosing fieldset
+        let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for radio_idx in index.query("input[type=radio]") {
+            let name = index.get_node(radio_idx).and_then(|radio| {
+                radio
+                    .attributes
+                    .iter()
+                    .find(|attr| index.resolve_symbol(attr.name).unwrap_or_default() == "name")
+                    .map(|attr| index.resolve_symbol(attr.value).unwrap_or_default())
+            });
+            if let Some(name) = name.filter(|name| !name.is_empty()) {
+                groups.entry(name).or_default().push(radio_idx);
+            }
+        }
+        for (name, radios) in groups {
+            if radios.len() < 2 {
+                continue;
+            }
+            let fieldsets: Vec<Option<usize>> = radios
+                .iter()
+                .map(|&radio_idx| closest_ancestor_of_type(radio_idx, "fieldset", index))
+                .collect();
+            let shared = fieldsets[0].is_some() && fieldsets.iter().all(|f| *f == fieldsets[0]);
+            if !shared {
+                report(
+                    radios[0],
+                    format!("radio group \"{}\" is not grouped in one fieldset", name),
+                );
+            }
+        }
+
+        problems.sort_by_key(|(node, _)| (node.source_info.line, node.source_info.column));
+        for (node, problem) in problems {
+            results.report(node, |result| {
+                result.message = format!("{} ({})", rule.message, problem);
+            });
+        }
+    }
+
+    fn check_role_required_attributes(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) {
+        for node_idx in index.query("[role]") {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let attribute = |name: &str| get_attribute(node, name, index);
+
+            // The first token is the role in effect; later tokens are fallbacks
+            let role = attribute("role").unwrap_or_default().to_ascii_lowercase();
+            let Some(role) = role.split_whitespace().next() else {
+                continue;
+            };
+            let Some((_, required)) = ROLE_REQUIRED_ATTRIBUTES
+                .iter()
+                .find(|(name, _)| *name == role)
+            else {
+                continue;
+            };
+
+            for name in required.iter() {
+                if attribute(name).is_none_or(|value| value.trim().is_empty()) {
+                    results.report(node, |result| {
+                        result.message =
+                            format!("{} (role=\"{}\" requires {})", rule.message, role, name);
+                    });
+                }
+            }
+        }
+    }
+
+    fn check_aria_live_regions(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let attribute = |node_idx: usize, name: &str| {
+            index
+                .get_node(node_idx)
+                .and_then(|node| get_attribute(node, name, index))
+                .map(|value| value.trim().to_ascii_lowercase())
+        };
+
+        let mut regions: Vec<usize> = ["[aria-live]", "[role=alert]", "[role=status]"]
+            .iter()
+            .flat_map(|selector| index.query(selector))
+            .collect();
+        regions.sort_unstable();
+        regions.dedup();
+
+        for node_idx in regions {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let live = attribute(node_idx, "aria-live");
+            let role = attribute(node_idx, "role");
+            let mut problems: Vec<(Severity, String)> = Vec::new();
+
+            match live.as_deref() {
+                None | Some("off") => {}
+                Some("assertive") => {
+                    // Alerts are assertive by definition, so only bare regions get the nudge
+                    if role.as_deref() != Some("alert") {
+                        problems.push((
+                            Severity::Info,
+                            "aria-live=\"assertive\" interrupts the user; prefer \"polite\" unless the update is urgent".to_string(),
+                        ));
+                    }
+                }
+                Some("polite") => {
+                    let mut ancestor = node.parent;
+                    while let Some(ancestor_idx) = ancestor {
+                        if attribute(ancestor_idx, "aria-live").as_deref() == Some("polite") {
+                            problems.push((
+                                rule.severity.clone(),
+                                "polite live region is nested inside another polite live region"
+                                    .to_string(),
+                            ));
+                            break;
+                        }
+                        ancestor = index.get_node(ancestor_idx).and_then(|node| node.parent);
+                    }
+                }
+                Some(value) => problems.push((
+                    rule.severity.clone(),
+                    format!("invalid aria-live value \"{}\"", value),
+                )),
+            }
+
+            let implied = match role.as_deref() {
+                Some("alert") => Some("assertive"),
+                Some("status") => Some("polite"),
+                _ => None,
+            };
+            if let (Some(implied), Some(live)) = (implied, live.as_deref()) {
+                if live != implied {
+                    problems.push((
+                        rule.severity.clone(),
+                        format!(
+                            "role=\"{}\" implies aria-live=\"{}\", not \"{}\"",
+                            role.as_deref().unwrap_or_default(),
+                            implied,
+                            live
+                        ),
+                    ));
+                }
+            }
+
+            for (severity, problem) in problems {
+                results.report_with_severity(node, severity, |result| {
+                    result.message = format!("{} ({})", rule.message, problem);
+                });
+            }
+        }
+    }
+
+    fn check_autocomplete(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let allowed_values = match rule.options.get("allowed_values") {
+            Some(_) => Self::parse_string_list(rule, "allowed_values")?,
+            None => AUTOCOMPLETE_TOKENS
+                .iter()
+                .map(|token| token.to_string())
+                .collect(),
+        };
+
+        let mut inputs: Vec<usize> = AUTOCOMPLETE_QUERIES
+            .iter()
+            .flat_map(|selector| index.query(selector))
+            .collect();
+        inputs.sort_unstable();
+        inputs.dedup();
+
+        for node_idx in inputs {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let attribute = |name: &str| get_attribute(node, name, index);
+            let input_type = attribute("type")
+                .map(|value| value.trim().to_ascii_lowercase())
+                .unwrap_or_default();
+            if NON_TEXT_INPUT_TYPES.contains(&input_type.as_str()) {
+                continue;
+            }
+
+            let problem = match attribute("autocomplete") {
+                None => Some("missing autocomplete attribute".to_string()),
+                Some(value) => match autocomplete_field_name(&value) {
+                    Some(field) if allowed_values.contains(&field) => None,
+                    _ => Some(format!(
+                        "unrecognized autocomplete value \"{}\"",
+                        value.trim()
+                    )),
+                },
+            };
+
+            if let Some(problem) = problem {
+                results.report(node, |result| {
+                    result.message = format!("{} ({})", rule.message, problem);
+                });
+            }
+        }
 
-                results.extend(should_report);
+        Ok(())
+    }
+
+    fn check_deprecated_attributes(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let additional: Vec<DeprecatedAttribute> = match rule.options.get("additional_deprecated") {
+            Some(json) => serde_json::from_str(json).map_err(|e| {
+                LinterError::RuleError(format!("Invalid additional_deprecated JSON: {}", e))
+            })?,
+            None => Vec::new(),
+        };
+        let deprecated: Vec<(&str, &str, &str)> = DEPRECATED_ATTRIBUTES
+            .iter()
+            .copied()
+            .chain(additional.iter().map(|entry| {
+                (
+                    entry.element.as_str(),
+                    entry.attribute.as_str(),
+                    "use CSS instead",
+                )
+            }))
+            .collect();
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
+            for attr in &node.attributes {
+                let attr_name = index.resolve_symbol(attr.name).unwrap_or_default();
+                let advice = deprecated.iter().find_map(|&(element, attribute, advice)| {
+                    ((element == "*" || element == tag_name) && attribute == attr_name)
+                        .then_some(advice)
+                });
+                if let Some(advice) = advice {
+                    results.report(node, |result| {
+                        result.message = format!(
+                            "The `{}` attribute on `{}` is deprecated; {}",
+                            attr_name, tag_name, advice
+                        );
+                    });
+                }
             }
         }
 
-        Ok(results)
+        Ok(())
     }
 
-    fn check_semantic_landmarks(&self, node_idx: usize, index: &DOMIndex) -> Vec<LintResult> {
-        let mut results = Vec::new();
+    fn check_deprecated_usage(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        // Entries from `deprecated_map` replace built-in ones for the same element
+        let custom: BTreeMap<String, String> = match rule.options.get("deprecated_map") {
+            Some(json) => serde_json::from_str(json).map_err(|e| {
+                LinterError::RuleError(format!("Invalid deprecated_map JSON: {}", e))
+            })?,
+            None => BTreeMap::new(),
+        };
+        let replacement_for = |tag_name: &str| -> Option<String> {
+            if let Some(replacement) = custom.get(tag_name) {
+                return Some(format!(
+                    "Consider using `<{}>` instead of `<{}>`",
+                    replacement, tag_name
+                ));
+            }
+            DEPRECATED_ELEMENTS
+                .iter()
+                .find(|(element, _, _, _)| *element == tag_name)
+                .map(|(element, replacement, reason, _)| {
+                    format!(
+                        "Consider using `<{}>` instead of `<{}>` {}",
+                        replacement, element, reason
+                    )
+                })
+        };
+
+        for node_idx in index.query(&rule.selector) {
+            if results.is_full() {
+                break;
+            }
+            let Some(node) = index.get_node(node_idx) else {
+                continue;
+            };
+            let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
+            let message = if tag_name == "hr" {
+                decorative_hr_reason(node, index).map(|reason| {
+                    format!(
+                        "`<hr>` marks a thematic break but {}; use a CSS border for decoration",
+                        reason
+                    )
+                })
+            } else {
+                replacement_for(&tag_name)
+            };
+            if let Some(message) = message {
+                results.report(node, |result| {
+                    result.message = message;
+                });
+            }
+        }
+
+        Ok(())
+    }
 
+    fn check_semantic_landmarks(
+        &self,
+        node_idx: usize,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) {
         if let Some(node) = index.get_node(node_idx) {
             let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
 
@@ -50,19 +835,20 @@ impl HtmlLinter {
                             line: node.source_info.line,
                             column: node.source_info.column,
                             element: tag_name.to_string(),
+                            file: None,
                         },
                         source: node.source_info.source.clone(),
+                        context: String::new(),
+                        fix_suggestion: None,
+                        element_path: build_element_path(node_idx, index),
+                        ..Default::default()
                     });
                 }
             }
         }
-
-        results
     }
 
-    fn check_semantic_buttons(&self, node_idx: usize, index: &DOMIndex) -> Vec<LintResult> {
-        let mut results = Vec::new();
-
+    fn check_semantic_buttons(&self, node_idx: usize, index: &DOMIndex, results: &mut RuleResults) {
         if let Some(node) = index.get_node(node_idx) {
             let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
 
@@ -84,19 +870,20 @@ impl HtmlLinter {
                             line: node.source_info.line,
                             column: node.source_info.column,
                             element: tag_name.to_string(),
+                            file: None,
                         },
                         source: node.source_info.source.clone(),
+                        context: String::new(),
+                        fix_suggestion: None,
+                        element_path: build_element_path(node_idx, index),
+                        ..Default::default()
                     });
                 }
             }
         }
-
-        results
     }
 
-    fn check_semantic_tables(&self, node_idx: usize, index: &DOMIndex) -> Vec<LintResult> {
-        let mut results = Vec::new();
-
+    fn check_semantic_tables(&self, node_idx: usize, index: &DOMIndex, results: &mut RuleResults) {
         if let Some(node) = index.get_node(node_idx) {
             let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
 
@@ -137,66 +924,106 @@ impl HtmlLinter {
                             line: node.source_info.line,
                             column: node.source_info.column,
                             element: tag_name.to_string(),
+                            file: None,
                         },
                         source: node.source_info.source.clone(),
+                        context: String::new(),
+                        fix_suggestion: None,
+                        element_path: build_element_path(node_idx, index),
+                        ..Default::default()
                     });
                 }
             }
         }
-
-        results
     }
 
     fn check_semantic_elements(
         &self,
-        rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
-
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
         // Check for common non-semantic pattern replacements
-        let patterns = [
-            ("b", "strong", "Use <strong> for strong importance"),
-            ("i", "em", "Use <em> for emphasized text"),
+        let elements = DEPRECATED_ELEMENTS
+            .iter()
+            .map(|(element, replacement, _, purpose)| {
+                (*element, format!("Use <{}> for {}", replacement, purpose))
+            });
+        let roles = [
             (
                 "div[role=button]",
-                "button",
-                "Use <button> instead of div with button role",
+                "Use <button> instead of div with button role".to_string(),
             ),
             (
                 "div[role=navigation]",
-                "nav",
-                "Use <nav> instead of div with navigation role",
+                "Use <nav> instead of div with navigation role".to_string(),
             ),
             (
                 "div[role=main]",
-                "main",
-                "Use <main> instead of div with main role",
+                "Use <main> instead of div with main role".to_string(),
             ),
         ];
 
-        for (non_semantic, _semantic, message) in patterns {
+        for (non_semantic, message) in elements.chain(roles) {
             let matches = index.query(non_semantic);
             for node_idx in matches {
+                if results.is_full() {
+                    break;
+                }
                 if let Some(node) = index.get_node(node_idx) {
-                    results.push(LintResult {
-                        rule: rule.name.clone(),
-                        severity: rule.severity.clone(),
-                        message: message.to_string(),
-                        location: Location {
-                            line: node.source_info.line,
-                            column: node.source_info.column,
-                            element: index
-                                .resolve_symbol(node.tag_name)
-                                .unwrap_or_default()
-                                .to_string(),
-                        },
-                        source: node.source_info.source.clone(),
+                    results.report(node, |result| {
+                        result.message = message.clone();
                     });
                 }
             }
         }
 
-        Ok(results)
+        Ok(())
+    }
+}
+
+// The list- and table-context message for a `tag` whose parent isn't one of `parents`
+fn context_message(rule: &Rule, tag: &str, parents: &[&str], parent: &str) -> String {
+    let expected = parents
+        .iter()
+        .map(|parent| format!("<{}>", parent))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "{} (<{}> must be a direct child of {}, not <{}>)",
+        rule.message, tag, expected, parent
+    )
+}
+
+// Why an `<hr>` looks cosmetic: inline styling, or a class naming it as decoration
+fn decorative_hr_reason(node: &IndexedNode, index: &DOMIndex) -> Option<String> {
+    let attribute = |name: &str| get_attribute(node, name, index);
+    if attribute("style").is_some_and(|style| !style.trim().is_empty()) {
+        return Some("is styled inline".to_string());
+    }
+    let class = attribute("class")?.to_ascii_lowercase();
+    class
+        .split_whitespace()
+        .find(|token| {
+            DECORATIVE_HR_CLASSES
+                .iter()
+                .any(|fragment| token.contains(fragment))
+        })
+        .map(|token| format!("has the decorative class `{}`", token))
+}
+
+// The field name of an autofill detail such as `section-a shipping street-address`, or `None`
+// when the tokens before it aren't a valid section, address type or contact type
+fn autocomplete_field_name(value: &str) -> Option<String> {
+    let value = value.to_ascii_lowercase();
+    let mut tokens: Vec<&str> = value.split_ascii_whitespace().collect();
+    if tokens.last() == Some(&"webauthn") {
+        tokens.pop();
     }
+    let (field, details) = tokens.split_last()?;
+
+    let mut details = details.iter().peekable();
+    details.next_if(|token| token.starts_with("section-"));
+    details.next_if(|token| matches!(**token, "shipping" | "billing"));
+    details.next_if(|token| matches!(**token, "home" | "work" | "mobile" | "fax" | "pager"));
+    details.next().is_none().then(|| field.to_string())
 }
@@ -0,0 +1,259 @@
+use crate::dom::utils::get_attribute;
+use crate::*;
+use std::collections::HashMap;
+use url::Url;
+
+const DEFAULT_REQUIRED_PROPERTIES: &[&str] = &["og:title", "og:type", "og:image", "og:url"];
+
+// Object types defined by the Open Graph protocol (https://ogp.me/#types)
+const OPEN_GRAPH_TYPES: &[&str] = &[
+    "website",
+    "article",
+    "book",
+    "profile",
+    "music.song",
+    "music.album",
+    "music.playlist",
+    "music.radio_station",
+    "video.movie",
+    "video.episode",
+    "video.tv_show",
+    "video.other",
+];
+
+// Properties whose content must be an absolute http(s) URL
+const URL_PROPERTIES: &[&str] = &[
+    "og:url",
+    "og:image",
+    "og:image:url",
+    "og:image:secure_url",
+    "og:video",
+    "og:video:url",
+    "og:video:secure_url",
+    "og:audio",
+    "og:audio:url",
+    "og:audio:secure_url",
+];
+
+const DIMENSION_PROPERTIES: &[&str] = &[
+    "og:image:width",
+    "og:image:height",
+    "og:video:width",
+    "og:video:height",
+    "twitter:player:width",
+    "twitter:player:height",
+];
+
+// Properties each card type needs; every entry lists alternatives, one of which must be present
+const TWITTER_CARD_REQUIREMENTS: &[(&str, &[&[&str]])] = &[
+    ("summary", &[&["twitter:title"]]),
+    ("summary_large_image", &[&["twitter:title"]]),
+    (
+        "app",
+        &[&["twitter:app:id:iphone", "twitter:app:id:googleplay"]],
+    ),
+    (
+        "player",
+        &[
+            &["twitter:title"],
+            &["twitter:player"],
+            &["twitter:player:width"],
+            &["twitter:player:height"],
+        ],
+    ),
+];
+
+impl HtmlLinter {
+    pub(crate) fn check_open_graph(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let required_properties = match rule.options.get("required_properties") {
+            Some(_) => Self::parse_string_list(rule, "required_properties")?,
+            None => DEFAULT_REQUIRED_PROPERTIES
+                .iter()
+                .map(|property| property.to_string())
+                .collect(),
+        };
+        let type_specific: HashMap<String, Vec<String>> = match rule.options.get("type_specific") {
+            Some(json) => serde_json::from_str(json).map_err(|e| {
+                LinterError::RuleError(format!("Invalid type_specific JSON: {}", e))
+            })?,
+            None => HashMap::from([("article".to_string(), vec!["article:author".to_string()])]),
+        };
+
+        let properties = meta_properties(index, "meta[property]", "property");
+
+        let mut report = |node_idx: usize, problem: String| {
+            if let Some(node) = index.get_node(node_idx) {
+                results.report(node, |result| {
+                    result.message = format!("{} - {}", rule.message, problem);
+                });
+            }
+        };
+
+        for (node_idx, property, content) in &properties {
+            let content = content.trim();
+            if content.is_empty() {
+                report(*node_idx, format!("{} property is empty", property));
+                continue;
+            }
+
+            if property == "og:type" && !OPEN_GRAPH_TYPES.contains(&content) {
+                report(*node_idx, format!("unknown og:type \"{}\"", content));
+            } else if URL_PROPERTIES.contains(&property.as_str())
+                && !Url::parse(content).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+            {
+                report(
+                    *node_idx,
+                    format!("{} must be an absolute URL, got \"{}\"", property, content),
+                );
+            } else if DIMENSION_PROPERTIES.contains(&property.as_str())
+                && content.parse::<u32>().is_err()
+            {
+                report(
+                    *node_idx,
+                    format!("{} must be a whole number of pixels", property),
+                );
+            }
+        }
+
+        let og_type = properties
+            .iter()
+            .find(|(_, property, _)| property == "og:type")
+            .map(|(_, _, content)| content.trim());
+        let mut required: Vec<&String> = required_properties.iter().collect();
+        if let Some(specific) = og_type.and_then(|og_type| type_specific.get(og_type)) {
+            required.extend(specific);
+        }
+
+        let has_property = |name: &str| {
+            properties
+                .iter()
+                .any(|(_, property, _)| property.eq_ignore_ascii_case(name))
+        };
+        for property in required {
+            if !has_property(property) {
+                let problem = match og_type.filter(|_| !required_properties.contains(property)) {
+                    Some(og_type) => format!(
+                        "missing {} property required for og:type \"{}\"",
+                        property, og_type
+                    ),
+                    None => format!("missing required {} property", property),
+                };
+                results.report_document_start(|| format!("{} - {}", rule.message, problem));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_twitter_card(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let properties = meta_properties(index, "meta[name^='twitter:']", "name");
+
+        let mut report = |node_idx: usize, problem: String| {
+            if let Some(node) = index.get_node(node_idx) {
+                results.report(node, |result| {
+                    result.message = format!("{} - {}", rule.message, problem);
+                });
+            }
+        };
+
+        let card = properties
+            .iter()
+            .find(|(_, property, _)| property == "twitter:card");
+        let requirements = match card {
+            Some((node_idx, _, content)) => {
+                let card_type = content.trim();
+                let requirements = TWITTER_CARD_REQUIREMENTS
+                    .iter()
+                    .find(|(card, _)| *card == card_type)
+                    .map(|(_, requirements)| *requirements);
+                if requirements.is_none() {
+                    report(*node_idx, format!("unknown twitter:card \"{}\"", card_type));
+                }
+                requirements.map(|requirements| (card_type, requirements))
+            }
+            None => None,
+        };
+
+        for (node_idx, property, content) in &properties {
+            let content = content.trim();
+            if content.is_empty() {
+                report(*node_idx, format!("{} property is empty", property));
+            } else if property == "twitter:player"
+                && !Url::parse(content).is_ok_and(|url| url.scheme() == "https")
+            {
+                report(
+                    *node_idx,
+                    format!("twitter:player must be an HTTPS URL, got \"{}\"", content),
+                );
+            } else if property == "twitter:image"
+                && !Url::parse(content).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+            {
+                report(
+                    *node_idx,
+                    format!("twitter:image must be an absolute URL, got \"{}\"", content),
+                );
+            } else if DIMENSION_PROPERTIES.contains(&property.as_str())
+                && content.parse::<u32>().is_err()
+            {
+                report(
+                    *node_idx,
+                    format!("{} must be a whole number of pixels", property),
+                );
+            }
+        }
+
+        if card.is_none() {
+            results.report_document_start(|| {
+                format!("{} - missing required twitter:card property", rule.message)
+            });
+        }
+        if let Some((card_type, requirements)) = requirements {
+            for alternatives in requirements {
+                let present = alternatives
+                    .iter()
+                    .any(|name| properties.iter().any(|(_, property, _)| property == name));
+                if !present {
+                    results.report_document_start(|| {
+                        format!(
+                            "{} - missing {} property required for twitter:card \"{}\"",
+                            rule.message,
+                            alternatives.join(" or "),
+                            card_type
+                        )
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// (node, property, content) for every meta tag matching `selector`, in document order,
+// with the property name taken from `attribute` and lowercased
+fn meta_properties(
+    index: &DOMIndex,
+    selector: &str,
+    attribute: &str,
+) -> Vec<(usize, String, String)> {
+    index
+        .query(selector)
+        .into_iter()
+        .filter_map(|node_idx| {
+            let node = index.get_node(node_idx)?;
+            let value = |name: &str| get_attribute(node, name, index);
+            let property = value(attribute)?.trim().to_ascii_lowercase();
+            Some((node_idx, property, value("content").unwrap_or_default()))
+        })
+        .collect()
+}
@@ -1,18 +1,26 @@
+use crate::dom::tags::{SourceTag, SourceTags};
+use crate::dom::utils::{
+    get_attribute, get_node_depth, is_block_element, is_element, is_inline_element, is_void_element,
+};
 use crate::*;
+use std::collections::HashSet;
+
+/// Elements that may not contain other interactive content. `a` only counts with an `href`,
+/// and hidden inputs are excluded.
+pub(super) const INTERACTIVE_ELEMENTS: &[&str] =
+    &["a", "button", "details", "input", "select", "textarea"];
 
 impl HtmlLinter {
     pub(crate) fn check_element_order(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
-
-        if rule.condition == "sequential-order" {
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        if rule.condition == ConditionType::SequentialOrder {
             let mut heading_stack = Vec::new();
 
-            // More efficient iteration using direct node access
-            for node_idx in 0..index.get_nodes().len() {
+            for node_idx in index.topological_order() {
                 if let Some(node) = index.get_node(node_idx) {
                     let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
 
@@ -22,19 +30,11 @@ impl HtmlLinter {
                             Some(&prev_level) => {
                                 // Check for skipped heading levels
                                 if level > prev_level + 1 {
-                                    results.push(LintResult {
-                                        rule: rule.name.clone(),
-                                        severity: rule.severity.clone(),
-                                        message: format!(
+                                    results.report(node, |result| {
+                                        result.message = format!(
                                             "Heading level jumped from h{} to h{}",
                                             prev_level, level
-                                        ),
-                                        location: Location {
-                                            line: node.source_info.line,
-                                            column: node.source_info.column,
-                                            element: tag_name.clone(),
-                                        },
-                                        source: node.source_info.source.clone(),
+                                        );
                                     });
                                 }
 
@@ -59,45 +59,359 @@ impl HtmlLinter {
             }
         }
 
-        Ok(results)
+        Ok(())
     }
 
     pub(crate) fn check_nesting(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        // Special handling for max-depth condition
+        if rule.condition == ConditionType::MaxDepth {
+            return self.check_max_depth(rule, index, results);
+        }
+        if rule.condition == ConditionType::InteractiveInInteractive {
+            self.check_interactive_nesting(rule, index, results);
+            return Ok(());
+        }
+        if rule.condition == ConditionType::DlStructure {
+            self.check_dl_structure(rule, index, results);
+            return Ok(());
+        }
+        if rule.condition == ConditionType::TableStructure {
+            self.check_table_structure(rule, index, results);
+            return Ok(());
+        }
+
         let matches = index.query(&rule.selector);
 
         for node_idx in matches {
+            if results.is_full() {
+                break;
+            }
             if let Some(node) = index.get_node(node_idx) {
-                let should_report = match rule.condition.as_str() {
-                    "parent-label-or-for" => {
+                let should_report = match rule.condition {
+                    ConditionType::ParentLabelOrFor => {
                         !self.has_label_parent(node_idx, index)
                             && !self.has_matching_label(node_idx, index)
                     }
+                    ConditionType::BlockInInline => {
+                        is_block_element(
+                            &index.resolve_symbol(node.tag_name).unwrap_or_default(),
+                            self.element_context(),
+                        ) && self.has_inline_ancestor(node_idx, index)
+                    }
                     _ => false,
                 };
 
                 if should_report {
-                    results.push(self.create_lint_result(rule, node, index));
+                    results.report(node, |_| {});
                 }
             }
         }
 
-        Ok(results)
+        Ok(())
     }
 
-    pub(crate) fn check_document_structure(
+    fn check_max_depth(
         &self,
         rule: &Rule,
         index: &DOMIndex,
-    ) -> Result<Vec<LintResult>, LinterError> {
-        let mut results = Vec::new();
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        let max = rule
+            .options
+            .get("max")
+            .and_then(|m| m.parse::<usize>().ok())
+            .ok_or_else(|| {
+                LinterError::RuleError("max option required for max-depth check".to_string())
+            })?;
+
+        let too_deep: Vec<(usize, usize)> = index
+            .query(&rule.selector)
+            .into_iter()
+            .filter(|&idx| index.get_node(idx).is_some_and(is_element))
+            .map(|idx| (idx, get_node_depth(idx, index, self.element_context())))
+            .filter(|&(_, depth)| depth > max)
+            .collect();
+
+        // Only the deepest element of each offending chain is reported
+        let offending: HashSet<usize> = too_deep.iter().map(|&(idx, _)| idx).collect();
+        let mut has_deeper = HashSet::new();
+        for &(idx, _) in &too_deep {
+            let mut current = index.get_node(idx).and_then(|n| n.parent);
+            while let Some(parent_idx) = current {
+                if offending.contains(&parent_idx) && !has_deeper.insert(parent_idx) {
+                    break;
+                }
+                current = index.get_node(parent_idx).and_then(|n| n.parent);
+            }
+        }
+
+        for (idx, depth) in too_deep {
+            if results.is_full() {
+                break;
+            }
+            if has_deeper.contains(&idx) {
+                continue;
+            }
+            if let Some(node) = index.get_node(idx) {
+                results.report(node, |result| {
+                    result.message = format!(
+                        "{} (depth {} exceeds maximum of {})",
+                        rule.message, depth, max
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_dl_structure(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let mut problems: Vec<(usize, String)> = Vec::new();
+        let tag_of = |idx: usize| {
+            index
+                .get_node(idx)
+                .map(|node| index.resolve_symbol(node.tag_name).unwrap_or_default())
+                .unwrap_or_default()
+        };
+        let element_children = |idx: usize| -> Vec<usize> {
+            index
+                .get_node(idx)
+                .map(|node| {
+                    node.children
+                        .iter()
+                        .copied()
+                        .filter(|&child| index.get_node(child).is_some_and(is_element))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        for dl_idx in index.query("dl") {
+            // Each div wrapper holds its own name-value group; bare children form a single run
+            let mut groups: Vec<Vec<usize>> = vec![Vec::new()];
+            for child in element_children(dl_idx) {
+                match tag_of(child).as_str() {
+                    "dt" | "dd" => groups[0].push(child),
+                    "div" => {
+                        let mut group = Vec::new();
+                        for item in element_children(child) {
+                            match tag_of(item).as_str() {
+                                "dt" | "dd" => group.push(item),
+                                "script" | "template" => {}
+                                tag => problems.push((
+                                    item,
+                                    format!("<{}> is not allowed in a <dl> <div>", tag),
+                                )),
+                            }
+                        }
+                        groups.push(group);
+                    }
+                    "script" | "template" => {}
+                    tag => problems.push((child, format!("<{}> is not allowed in <dl>", tag))),
+                }
+            }
+
+            if groups.iter().all(Vec::is_empty) {
+                problems.push((dl_idx, "<dl> has no <dt>/<dd> pairs".to_string()));
+                continue;
+            }
+
+            for (group_idx, group) in groups.iter().enumerate() {
+                let in_div = group_idx > 0;
+                let mut seen_dt = false;
+                let mut seen_dd = false;
+                for (position, &item) in group.iter().enumerate() {
+                    if tag_of(item) == "dd" {
+                        if !seen_dt {
+                            problems.push((item, "<dd> has no preceding <dt>".to_string()));
+                        }
+                        seen_dd = true;
+                        continue;
+                    }
+                    if in_div && seen_dd {
+                        problems.push((item, "a <dl> <div> may hold only one group".to_string()));
+                    }
+                    seen_dt = true;
+                    seen_dd = false;
+                    if group.get(position + 1).is_none() {
+                        problems.push((item, "<dt> is not followed by a <dd>".to_string()));
+                    }
+                }
+            }
+        }
+
+        // dt and dd only belong directly in a dl, or in a div directly inside one
+        for item in index.query("dt").into_iter().chain(index.query("dd")) {
+            let parent = index.get_node(item).and_then(|node| node.parent);
+            let in_dl = parent.is_some_and(|parent| {
+                tag_of(parent) == "dl"
+                    || (tag_of(parent) == "div"
+                        && index
+                            .get_node(parent)
+                            .and_then(|div| div.parent)
+                            .is_some_and(|grandparent| tag_of(grandparent) == "dl"))
+            });
+            if !in_dl {
+                problems.push((item, format!("<{}> outside a <dl>", tag_of(item))));
+            }
+        }
+
+        problems.sort_by_key(|(idx, _)| *idx);
+        let problems = problems
+            .into_iter()
+            .filter_map(|(idx, problem)| Some(Problem::Element(index.get_node(idx)?, problem)))
+            .collect();
+        report_in_order(rule, index, problems, results);
+    }
+
+    fn check_table_structure(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let mut problems: Vec<(usize, String)> = Vec::new();
+        let tag_of = |idx: usize| {
+            index
+                .get_node(idx)
+                .map(|node| index.resolve_symbol(node.tag_name).unwrap_or_default())
+                .unwrap_or_default()
+        };
+        let element_children = |idx: usize| -> Vec<usize> {
+            index
+                .get_node(idx)
+                .map(|node| {
+                    node.children
+                        .iter()
+                        .copied()
+                        .filter(|&child| index.get_node(child).is_some_and(is_element))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        for table_idx in index.query("table") {
+            // The latest section seen so far, which every later child must not precede
+            let mut latest: Option<(usize, String)> = None;
+            let mut seen: Vec<String> = Vec::new();
+            for (position, child) in element_children(table_idx).into_iter().enumerate() {
+                let tag = tag_of(child);
+                let rank = match tag.as_str() {
+                    "caption" => 0,
+                    "colgroup" => 1,
+                    "col" => 2,
+                    "thead" => 3,
+                    "tbody" => 4,
+                    "tfoot" => 5,
+                    "script" | "template" => continue,
+                    _ => {
+                        problems.push((child, format!("<{}> is not allowed in <table>", tag)));
+                        continue;
+                    }
+                };
+
+                if matches!(tag.as_str(), "caption" | "thead" | "tfoot") && seen.contains(&tag) {
+                    problems.push((child, format!("<table> has more than one <{}>", tag)));
+                } else if tag == "caption" && position > 0 {
+                    problems.push((
+                        child,
+                        "<caption> must be the first child of <table>".to_string(),
+                    ));
+                } else if let Some((_, later)) = latest
+                    .as_ref()
+                    .filter(|(latest_rank, _)| *latest_rank > rank)
+                {
+                    problems.push((child, format!("<{}> must come before <{}>", tag, later)));
+                }
+
+                if latest
+                    .as_ref()
+                    .is_none_or(|(latest_rank, _)| rank > *latest_rank)
+                {
+                    latest = Some((rank, tag.clone()));
+                }
+                seen.push(tag);
+            }
+        }
+
+        // Header cells lead a row; a <th> after a <td> mixes the two without a header pattern
+        for row_idx in index.query("tr") {
+            let mut seen_td = false;
+            for cell in element_children(row_idx) {
+                match tag_of(cell).as_str() {
+                    "td" => seen_td = true,
+                    "th" if seen_td => {
+                        problems.push((cell, "<th> follows a <td> in the same row".to_string()))
+                    }
+                    "th" | "script" | "template" => {}
+                    tag => problems.push((cell, format!("<{}> is not allowed in <tr>", tag))),
+                }
+            }
+        }
 
-        match rule.condition.as_str() {
-            "doctype-present" => {
+        problems.sort_by_key(|(idx, _)| *idx);
+        let mut problems: Vec<Problem> = problems
+            .into_iter()
+            .filter_map(|(idx, problem)| Some(Problem::Element(index.get_node(idx)?, problem)))
+            .collect();
+
+        // The parser wraps rows written directly in <table> in a <tbody>, so only the source
+        // shows them
+        let source = index.get_source();
+        let scanned = SourceTags::scan(source);
+        for nested in scanned.nesting(source) {
+            if nested.tag.name == "tr" && nested.parent == Some("table") && !nested.foreign {
+                problems.push(Problem::Tag(
+                    nested.tag,
+                    "<tr> must be inside <thead>, <tbody> or <tfoot>".to_string(),
+                ));
+            }
+        }
+        report_in_order(rule, index, problems, results);
+    }
+
+    fn check_interactive_nesting(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let mut interactive: Vec<usize> = INTERACTIVE_ELEMENTS
+            .iter()
+            .flat_map(|&tag| index.query(tag))
+            .filter(|&idx| is_interactive(idx, index))
+            .collect();
+        interactive.sort_unstable();
+
+        for &node_idx in &interactive {
+            if results.is_full() {
+                break;
+            }
+            let mut current = index.get_node(node_idx).and_then(|n| n.parent);
+            while let Some(parent_idx) = current {
+                if interactive.binary_search(&parent_idx).is_ok() {
+                    if let (Some(node), Some(parent)) =
+                        (index.get_node(node_idx), index.get_node(parent_idx))
+                    {
+                        results.report(node, |result| {
+                            result.message = format!(
+                                "{} (<{}> inside <{}>)",
+                                rule.message,
+                                index.resolve_symbol(node.tag_name).unwrap_or_default(),
+                                index.resolve_symbol(parent.tag_name).unwrap_or_default()
+                            );
+                        });
+                    }
+                    break;
+                }
+                current = index.get_node(parent_idx).and_then(|n| n.parent);
+            }
+        }
+    }
+
+    pub(crate) fn check_document_structure(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        results: &mut RuleResults,
+    ) -> Result<(), LinterError> {
+        match rule.condition {
+            ConditionType::DoctypePresent => {
                 let has_doctype = index
                     .get_source_map()
                     .lines
@@ -105,26 +419,136 @@ impl HtmlLinter {
                     .any(|line| line.trim().to_lowercase().starts_with("<!doctype"));
 
                 if !has_doctype {
-                    results.push(LintResult {
-                        rule: rule.name.clone(),
-                        severity: rule.severity.clone(),
-                        message: rule.message.clone(),
-                        location: Location {
-                            line: 1,
-                            column: 1,
-                            element: String::new(),
-                        },
-                        source: String::new(),
-                    });
+                    results.report_document_start(|| rule.message.clone());
                 }
             }
+            ConditionType::RequiredStructure => self.check_required_structure(rule, index, results),
+            ConditionType::NoContentBeforeHead => {
+                self.check_content_before_head(rule, index, results)
+            }
             _ => {}
         }
 
-        Ok(results)
+        Ok(())
+    }
+
+    fn check_required_structure(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let mut problems: Vec<Problem> = Vec::new();
+        let mut element_problem = |node_idx: usize, problem: &str| {
+            if let Some(node) = index.get_node(node_idx) {
+                problems.push(Problem::Element(node, problem.to_string()));
+            }
+        };
+        let tag_of = |idx: usize| {
+            index
+                .get_node(idx)
+                .filter(|node| is_element(node))
+                .map(|node| index.resolve_symbol(node.tag_name).unwrap_or_default())
+        };
+
+        // html5ever repairs the tree, so check both the DOM it built and the tags as written
+        let roots: Vec<usize> = index
+            .get_node(0)
+            .map(|document| {
+                document
+                    .children
+                    .iter()
+                    .copied()
+                    .filter(|&idx| tag_of(idx).is_some())
+                    .collect()
+            })
+            .unwrap_or_default();
+        for &root in &roots {
+            if tag_of(root).as_deref() != Some("html") {
+                element_problem(root, "<html> must be the root element");
+            }
+        }
+        for (idx, node) in index.get_nodes().iter().enumerate() {
+            let tag = tag_of(idx);
+            if !matches!(tag.as_deref(), Some("head" | "body")) {
+                continue;
+            }
+            if node.parent.and_then(tag_of).as_deref() != Some("html") {
+                let problem = format!(
+                    "<{}> must be a direct child of <html>",
+                    tag.unwrap_or_default()
+                );
+                element_problem(idx, &problem);
+            }
+        }
+        if let Some(&html) = roots.first() {
+            let children: Vec<String> = index
+                .get_node(html)
+                .map(|node| node.children.iter().copied().filter_map(tag_of).collect())
+                .unwrap_or_default();
+            let head = children.iter().position(|tag| tag == "head");
+            let body = children.iter().position(|tag| tag == "body");
+            if let (Some(head), Some(body)) = (head, body) {
+                if body < head {
+                    element_problem(html, "<head> must come before <body>");
+                }
+            }
+        }
+
+        let scanned = SourceTags::scan(index.get_source());
+        let tags: Vec<&SourceTag> = scanned.tags.iter().filter(|tag| !tag.closing).collect();
+        for name in ["html", "head", "body"] {
+            for tag in tags.iter().filter(|tag| tag.name == name).skip(1) {
+                problems.push(Problem::Tag(
+                    tag,
+                    format!("<{}> appears more than once", name),
+                ));
+            }
+        }
+        let first = |name: &str| tags.iter().copied().find(|tag| tag.name == name);
+        if let (Some(head), Some(body)) = (first("head"), first("body")) {
+            if body.span.start < head.span.start {
+                problems.push(Problem::Tag(
+                    head,
+                    "<head> must come before <body>".to_string(),
+                ));
+            }
+        }
+
+        report_in_order(rule, index, problems, results);
+    }
+
+    fn check_content_before_head(&self, rule: &Rule, index: &DOMIndex, results: &mut RuleResults) {
+        let scanned = SourceTags::scan(index.get_source());
+        let tags: Vec<&SourceTag> = scanned.tags.iter().filter(|tag| !tag.closing).collect();
+        let Some(head) = tags.iter().position(|tag| tag.name == "head") else {
+            return;
+        };
+
+        for tag in tags[..head].iter().filter(|tag| tag.name != "html") {
+            results.report_source_tag(tag, || {
+                format!("{} (<{}> appears before <head>)", rule.message, tag.name)
+            });
+            if results.is_full() {
+                break;
+            }
+        }
+    }
+
+    fn has_inline_ancestor(&self, node_idx: usize, index: &DOMIndex) -> bool {
+        let mut current = index.get_node(node_idx).and_then(|n| n.parent);
+        while let Some(parent_idx) = current {
+            let Some(parent) = index.get_node(parent_idx) else {
+                break;
+            };
+            // Whatever follows a custom void element is parsed as its child, but isn't one
+            let tag_name = index.resolve_symbol(parent.tag_name).unwrap_or_default();
+            if is_inline_element(&tag_name, self.element_context())
+                && !is_void_element(&tag_name, self.element_context())
+            {
+                return true;
+            }
+            current = parent.parent;
+        }
+        false
     }
 
-    fn has_label_parent(&self, node_idx: usize, index: &DOMIndex) -> bool {
+    pub(super) fn has_label_parent(&self, node_idx: usize, index: &DOMIndex) -> bool {
         let mut current_idx = node_idx;
         while let Some(parent_idx) = index.get_node(current_idx).and_then(|n| n.parent) {
             if let Some(parent_node) = index.get_node(parent_idx) {
@@ -141,7 +565,7 @@ impl HtmlLinter {
         false
     }
 
-    fn has_matching_label(&self, node_idx: usize, index: &DOMIndex) -> bool {
+    pub(super) fn has_matching_label(&self, node_idx: usize, index: &DOMIndex) -> bool {
         // Get the ID of the current node
         if let Some(node) = index.get_node(node_idx) {
             let node_id = node.attributes.iter().find_map(|attr| {
@@ -155,19 +579,13 @@ impl HtmlLinter {
             // If the node has no ID, it can't have a matching label
             if let Some(id) = node_id {
                 // Look for any label with a matching "for" attribute
-                index.get_nodes().iter().any(|other_node| {
-                    if index
-                        .resolve_symbol(other_node.tag_name)
-                        .unwrap_or_default()
-                        == "label"
-                    {
-                        other_node.attributes.iter().any(|attr| {
+                index.get_elements_by_tag("label").iter().any(|&label_idx| {
+                    index.get_node(label_idx).is_some_and(|label| {
+                        label.attributes.iter().any(|attr| {
                             index.resolve_symbol(attr.name).unwrap_or_default() == "for"
                                 && index.resolve_symbol(attr.value).unwrap_or_default() == id
                         })
-                    } else {
-                        false
-                    }
+                    })
                 })
             } else {
                 false
@@ -189,3 +607,54 @@ fn parse_heading_level(tag_name: &str) -> Option<i32> {
         .ok()
         .filter(|&level| level >= 1 && level <= 6)
 }
+
+// Whether an element from `INTERACTIVE_ELEMENTS` is interactive: links need an `href`, and
+// hidden inputs aren't
+pub(super) fn is_interactive(node_idx: usize, index: &DOMIndex) -> bool {
+    let Some(node) = index.get_node(node_idx) else {
+        return false;
+    };
+    let attribute = |name: &str| get_attribute(node, name, index);
+
+    match index
+        .resolve_symbol(node.tag_name)
+        .unwrap_or_default()
+        .as_str()
+    {
+        "a" => attribute("href").is_some(),
+        "input" => attribute("type").is_none_or(|t| !t.trim().eq_ignore_ascii_case("hidden")),
+        _ => true,
+    }
+}
+
+// A problem with an element, or with a tag as written when the parser moved or dropped it
+enum Problem<'a> {
+    Element(&'a IndexedNode, String),
+    Tag(&'a SourceTag, String),
+}
+
+// Reports `problems` in document order, stopping once the rule is full
+fn report_in_order(
+    rule: &Rule,
+    index: &DOMIndex,
+    mut problems: Vec<Problem>,
+    results: &mut RuleResults,
+) {
+    problems.sort_by_key(|problem| match problem {
+        Problem::Element(node, _) => (node.source_info.line, node.source_info.column),
+        Problem::Tag(tag, _) => index.get_source_map().get_position(tag.span.start),
+    });
+    for problem in problems {
+        match problem {
+            Problem::Element(node, problem) => results.report(node, |result| {
+                result.message = format!("{} ({})", rule.message, problem);
+            }),
+            Problem::Tag(tag, problem) => {
+                results.report_source_tag(tag, || format!("{} ({})", rule.message, problem))
+            }
+        }
+        if results.is_full() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum WcagLevel {
+    A,
+    AA,
+}
+
+// Every built-in WCAG 2.1 rule with its conformance level, reported as errors
+fn wcag_rules() -> Vec<(WcagLevel, Rule)> {
+    let rule = |level: WcagLevel,
+                name: &str,
+                rule_type: RuleType,
+                selector: &str,
+                condition: &str,
+                message: &str,
+                fix_hint: &str,
+                options: &[(&str, &str)]| {
+        (
+            level,
+            Rule {
+                name: name.to_string(),
+                rule_type,
+                severity: Severity::Error,
+                selector: selector.to_string(),
+                condition: condition.into(),
+                message: message.to_string(),
+                options: options
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+                tags: vec![
+                    "wcag".to_string(),
+                    match level {
+                        WcagLevel::A => "wcag-a".to_string(),
+                        WcagLevel::AA => "wcag-aa".to_string(),
+                    },
+                ],
+                fix_hint: fix_hint.to_string(),
+            },
+        )
+    };
+
+    vec![
+        // 1.1.1 Non-text Content: images need a text alternative
+        rule(
+            WcagLevel::A,
+            "wcag-img-alt",
+            RuleType::AttributePresence,
+            "img",
+            "alt-missing",
+            "Images must have an alt attribute (WCAG 1.1.1)",
+            r#"Add alt="" for decorative images or a descriptive text for informational images"#,
+            &[],
+        ),
+        // 1.3.1 Info and Relationships: data tables mark up their header cells
+        rule(
+            WcagLevel::A,
+            "wcag-table-headers",
+            RuleType::Compound,
+            "table",
+            "table-headers",
+            "Tables must have header cells (WCAG 1.3.1)",
+            "Mark up header cells with <th> so each data cell has a header",
+            &[(
+                "conditions",
+                r#"[{"type": "DescendantPresence", "selector": "th", "min_count": 1}]"#,
+            )],
+        ),
+        // 1.3.5 Identify Input Purpose: personal data inputs expose their purpose
+        rule(
+            WcagLevel::AA,
+            "wcag-input-purpose",
+            RuleType::FormValidation,
+            "body",
+            "autocomplete",
+            "Inputs should identify their purpose with autocomplete (WCAG 1.3.5)",
+            r#"Add an autocomplete token such as "email" or "tel" to inputs that collect personal data"#,
+            &[],
+        ),
+        // 2.4.2 Page Titled
+        rule(
+            WcagLevel::A,
+            "wcag-page-title",
+            RuleType::ElementPresence,
+            "title",
+            "element-present",
+            "Pages must have a title (WCAG 2.4.2)",
+            "Add a <title> to the <head> that describes the page",
+            &[],
+        ),
+        // 2.4.6 Headings and Labels: heading levels describe the outline without gaps
+        rule(
+            WcagLevel::AA,
+            "wcag-heading-order",
+            RuleType::ElementOrder,
+            "h1, h2, h3, h4, h5, h6",
+            "sequential-order",
+            "Heading levels should not be skipped (WCAG 2.4.6)",
+            "Use the next heading level down, e.g. an <h2> under an <h1>, instead of skipping levels",
+            &[],
+        ),
+        // 3.1.1 Language of Page
+        rule(
+            WcagLevel::A,
+            "wcag-html-lang",
+            RuleType::AttributePresence,
+            "html",
+            "lang-attribute",
+            "The html element must have a lang attribute (WCAG 3.1.1)",
+            r#"Add a lang attribute with the page language, e.g. <html lang="en">"#,
+            &[],
+        ),
+        // 4.1.2 Name, Role, Value: form controls have an accessible name
+        rule(
+            WcagLevel::A,
+            "wcag-control-name",
+            RuleType::FormValidation,
+            "body",
+            "input-label-association",
+            "Form controls must have a label (WCAG 4.1.2)",
+            r#"Associate a <label for="..."> with the control, or name it with aria-label"#,
+            &[],
+        ),
+        // 4.1.2 Name, Role, Value: links have content to name them
+        rule(
+            WcagLevel::A,
+            "wcag-link-name",
+            RuleType::Custom("no-empty-links".to_string()),
+            "a",
+            "no-empty-links",
+            "Links must have an accessible name (WCAG 4.1.2)",
+            "Give the link text content, or an aria-label when it only contains an icon",
+            &[],
+        ),
+        // 4.1.2 Name, Role, Value: ARIA roles carry their required states
+        rule(
+            WcagLevel::A,
+            "wcag-aria-role",
+            RuleType::AriaRole,
+            "[role]",
+            "aria-role",
+            "ARIA roles must have their required states and properties (WCAG 4.1.2)",
+            r#"Add the states and properties the role requires, e.g. aria-checked for role="checkbox""#,
+            &[],
+        ),
+    ]
+}
+
+impl HtmlLinter {
+    /// Built-in rules for the WCAG 2.1 Level A success criteria this linter can check:
+    /// 1.1.1, 1.3.1, 2.4.2, 3.1.1 and 4.1.2. Rules are tagged `wcag` and `wcag-a`.
+    pub fn wcag_a_rules() -> Vec<Rule> {
+        wcag_rules()
+            .into_iter()
+            .filter(|(level, _)| *level == WcagLevel::A)
+            .map(|(_, rule)| rule)
+            .collect()
+    }
+
+    /// [`HtmlLinter::wcag_a_rules`] plus the Level AA criteria 1.3.5 (input purpose) and
+    /// 2.4.6 (headings). The AA rules are tagged `wcag` and `wcag-aa`.
+    pub fn wcag_aa_rules() -> Vec<Rule> {
+        wcag_rules().into_iter().map(|(_, rule)| rule).collect()
+    }
+}
@@ -0,0 +1,311 @@
+use crate::dom::tags::SourceTag;
+use crate::dom::IndexedNode;
+use crate::stats::most_common_rule;
+use crate::{DOMIndex, HtmlLinter, LintFilters, LintResult, LintStats, Rule, Severity};
+use std::collections::HashMap;
+
+/// Receives the results a rule reports once they've passed directives, ignores and caps.
+pub(crate) trait ResultCollector: Default + Send {
+    // Collectors that only count never have results built for them
+    const KEEPS_RESULTS: bool;
+
+    fn collect(&mut self, rule: &str, reported: Reported);
+
+    fn append(&mut self, other: Self);
+}
+
+impl ResultCollector for Vec<LintResult> {
+    const KEEPS_RESULTS: bool = true;
+
+    fn collect(&mut self, _rule: &str, reported: Reported) {
+        if let Reported::Results(mut results) = reported {
+            Vec::append(self, &mut results);
+        }
+    }
+
+    fn append(&mut self, mut other: Self) {
+        Vec::append(self, &mut other);
+    }
+}
+
+/// Counts results per severity and rule for `HtmlLinter::dry_run`. It only ever receives
+/// severities, so checks don't build a `LintResult` for a dry run.
+#[derive(Debug, Default)]
+pub(crate) struct DryRunAccumulator {
+    error_count: usize,
+    warning_count: usize,
+    info_count: usize,
+    rules_triggered: HashMap<String, usize>,
+}
+
+impl ResultCollector for DryRunAccumulator {
+    const KEEPS_RESULTS: bool = false;
+
+    fn collect(&mut self, rule: &str, reported: Reported) {
+        let Reported::Severities(severities) = reported else {
+            return;
+        };
+        for (severity, renamed) in severities {
+            match severity {
+                Severity::Error => self.error_count += 1,
+                Severity::Warning => self.warning_count += 1,
+                Severity::Info => self.info_count += 1,
+            }
+            let rule = renamed.as_deref().unwrap_or(rule);
+            match self.rules_triggered.get_mut(rule) {
+                Some(count) => *count += 1,
+                None => {
+                    self.rules_triggered.insert(rule.to_string(), 1);
+                }
+            }
+        }
+    }
+
+    fn append(&mut self, other: Self) {
+        self.error_count += other.error_count;
+        self.warning_count += other.warning_count;
+        self.info_count += other.info_count;
+        for (rule, count) in other.rules_triggered {
+            *self.rules_triggered.entry(rule).or_default() += count;
+        }
+    }
+}
+
+impl DryRunAccumulator {
+    /// The stats of the counted results, whose severities were already promoted as `lint`
+    /// promotes them.
+    pub(crate) fn into_stats(self) -> LintStats {
+        LintStats {
+            error_count: self.error_count,
+            warning_count: self.warning_count,
+            info_count: self.info_count,
+            total_count: self.error_count + self.warning_count + self.info_count,
+            most_common_rule: most_common_rule(&self.rules_triggered),
+            rules_triggered: self.rules_triggered,
+        }
+    }
+}
+
+/// What a rule reported: its results, or for a dry run only their severities. A severity
+/// carries a rule name when its result was reported under a name other than the rule's.
+pub(crate) enum Reported {
+    Results(Vec<LintResult>),
+    Severities(Vec<(Severity, Option<String>)>),
+}
+
+impl Reported {
+    /// Keeps results until `budget` of them have counted toward the limit, and returns
+    /// whether the budget ran out.
+    pub(crate) fn truncate(
+        &mut self,
+        budget: &mut usize,
+        counts: impl Fn(&Severity) -> bool,
+    ) -> bool {
+        let mut take = |severity: &Severity| {
+            if *budget == 0 {
+                return false;
+            }
+            if counts(severity) {
+                *budget -= 1;
+            }
+            true
+        };
+        match self {
+            Reported::Results(results) => {
+                let kept = results
+                    .iter()
+                    .position(|result| !take(&result.severity))
+                    .unwrap_or(results.len());
+                results.truncate(kept);
+            }
+            Reported::Severities(severities) => {
+                let kept = severities
+                    .iter()
+                    .position(|(severity, _)| !take(severity))
+                    .unwrap_or(severities.len());
+                severities.truncate(kept);
+            }
+        }
+        *budget == 0
+    }
+}
+
+/// The results of one rule, filtered as its check reports them.
+///
+/// Results on lines where the rule is disabled, or on ignored elements, are dropped as
+/// they're reported, and severities are adjusted and promoted before they count toward
+/// `max_errors_per_rule`. When a result arrives after the limit, the last counted result is
+/// marked `truncated` and the rule is full, so checks stop looking for more. For a dry run
+/// results are never built, only their severities recorded.
+pub(crate) struct RuleResults<'a> {
+    linter: &'a HtmlLinter,
+    rule: &'a Rule,
+    index: &'a DOMIndex,
+    filters: &'a LintFilters,
+    reported: Reported,
+    counted: usize,
+    last_counted: Option<usize>,
+    full: bool,
+}
+
+impl<'a> RuleResults<'a> {
+    pub(crate) fn new(
+        linter: &'a HtmlLinter,
+        rule: &'a Rule,
+        index: &'a DOMIndex,
+        filters: &'a LintFilters,
+        keep_results: bool,
+    ) -> Self {
+        Self {
+            linter,
+            rule,
+            index,
+            filters,
+            reported: if keep_results {
+                Reported::Results(Vec::new())
+            } else {
+                Reported::Severities(Vec::new())
+            },
+            counted: 0,
+            last_counted: None,
+            full: false,
+        }
+    }
+
+    /// Whether `max_errors_per_rule` has cut the rule's results short.
+    pub(crate) fn is_full(&self) -> bool {
+        self.full
+    }
+
+    /// Reports `node`, building its result with `create_lint_result` and then `complete`
+    /// only when the result is kept.
+    pub(crate) fn report(&mut self, node: &IndexedNode, complete: impl FnOnce(&mut LintResult)) {
+        self.report_with_severity(node, self.rule.severity.clone(), complete);
+    }
+
+    /// Reports `node` like `report`, but with a severity other than the rule's.
+    pub(crate) fn report_with_severity(
+        &mut self,
+        node: &IndexedNode,
+        severity: Severity,
+        complete: impl FnOnce(&mut LintResult),
+    ) {
+        let (line, column) = (node.source_info.line, node.source_info.column);
+        let Some(severity) = self.admit(line, column, &severity) else {
+            return;
+        };
+        match &mut self.reported {
+            Reported::Results(results) => {
+                let mut result = self.linter.create_lint_result(self.rule, node, self.index);
+                complete(&mut result);
+                result.severity = severity;
+                results.push(result);
+            }
+            Reported::Severities(severities) => severities.push((severity, None)),
+        }
+    }
+
+    /// Reports a result at `line` and `column` that `build` makes only when it's kept.
+    pub(crate) fn report_at(
+        &mut self,
+        line: usize,
+        column: usize,
+        build: impl FnOnce(&HtmlLinter, &Rule, &DOMIndex) -> LintResult,
+    ) {
+        let Some(severity) = self.admit(line, column, &self.rule.severity) else {
+            return;
+        };
+        match &mut self.reported {
+            Reported::Results(results) => {
+                let mut result = build(self.linter, self.rule, self.index);
+                result.severity = severity;
+                Self::fill_context(&mut result, self.index);
+                results.push(result);
+            }
+            Reported::Severities(severities) => severities.push((severity, None)),
+        }
+    }
+
+    /// Reports something missing from the document, see `HtmlLinter::document_start_result`.
+    pub(crate) fn report_document_start(&mut self, message: impl FnOnce() -> String) {
+        self.report_at(1, 1, |_, rule, _| {
+            HtmlLinter::document_start_result(rule, message())
+        });
+    }
+
+    /// Reports markup at `tag` as written, see `HtmlLinter::source_tag_result`.
+    pub(crate) fn report_source_tag(&mut self, tag: &SourceTag, message: impl FnOnce() -> String) {
+        let (line, column) = self.index.get_source_map().get_position(tag.span.start);
+        self.report_at(line, column, |_, rule, index| {
+            HtmlLinter::source_tag_result(rule, tag, index, message())
+        });
+    }
+
+    /// Reports a result that's already built, with its own severity and rule name, such as
+    /// one from a plugin.
+    pub(crate) fn push(&mut self, mut result: LintResult) {
+        let (line, column) = (result.location.line, result.location.column);
+        let Some(severity) = self.admit(line, column, &result.severity) else {
+            return;
+        };
+        match &mut self.reported {
+            Reported::Results(results) => {
+                result.severity = severity;
+                Self::fill_context(&mut result, self.index);
+                results.push(result);
+            }
+            Reported::Severities(severities) => {
+                let renamed = Some(result.rule).filter(|name| *name != self.rule.name);
+                severities.push((severity, renamed));
+            }
+        }
+    }
+
+    pub(crate) fn into_reported(self) -> Reported {
+        self.reported
+    }
+
+    // The severity a result at `line` and `column` is reported with, or `None` when it's
+    // filtered out or over the limit
+    fn admit(&mut self, line: usize, column: usize, severity: &Severity) -> Option<Severity> {
+        let severity =
+            self.linter
+                .reported_severity(self.rule, self.filters, line, column, severity)?;
+        if !self.linter.counts_toward_limit(&severity) {
+            return Some(severity);
+        }
+
+        if self
+            .linter
+            .options
+            .max_errors_per_rule
+            .is_some_and(|max| self.counted >= max)
+        {
+            if !self.full {
+                self.full = true;
+                if let (Reported::Results(results), Some(last)) =
+                    (&mut self.reported, self.last_counted)
+                {
+                    results[last].truncated = true;
+                }
+            }
+            return None;
+        }
+
+        self.counted += 1;
+        self.last_counted = match &self.reported {
+            Reported::Results(results) => Some(results.len()),
+            Reported::Severities(_) => None,
+        };
+        Some(severity)
+    }
+
+    // Checks that build results by hand leave the context for us to fill in
+    fn fill_context(result: &mut LintResult, index: &DOMIndex) {
+        if result.context.is_empty() {
+            result.context = index
+                .get_source_map()
+                .context(result.location.line, result.location.column);
+        }
+    }
+}
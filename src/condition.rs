@@ -0,0 +1,275 @@
+use crate::RuleType;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Declares the built-in conditions alongside their kebab-case names, so that parsing and
+// printing can't drift apart
+macro_rules! condition_types {
+    ($($variant:ident => $name:literal,)*) => {
+        /// The check a rule performs, written in configs as its kebab-case name, e.g.
+        /// `"alt-missing"`. Names that aren't built in parse as `Custom`, which is how
+        /// pattern-based, plugin and custom validator rules name their own conditions.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(from = "String", into = "String")]
+        pub enum ConditionType {
+            $($variant,)*
+            Custom(String),
+        }
+
+        // Pairs with the default `RuleType::ElementPresence`
+        impl Default for ConditionType {
+            fn default() -> Self {
+                ConditionType::ElementPresent
+            }
+        }
+
+        impl ConditionType {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(ConditionType::$variant => $name,)*
+                    ConditionType::Custom(name) => name,
+                }
+            }
+        }
+
+        impl From<&str> for ConditionType {
+            fn from(name: &str) -> Self {
+                match name {
+                    $($name => ConditionType::$variant,)*
+                    other => ConditionType::Custom(other.to_string()),
+                }
+            }
+        }
+    };
+}
+
+condition_types! {
+    // ElementPresence
+    ElementPresent => "element-present",
+    Required => "required",
+    ConditionalPresence => "conditional-presence",
+    Forbidden => "forbidden",
+    ElementAbsent => "element-absent",
+    SemanticAlternativeAvailable => "semantic-alternative-available",
+    DoctypePresent => "doctype-present",
+    // AttributePresence
+    MutuallyExclusive => "mutually-exclusive",
+    DuplicateAttributes => "duplicate-attributes",
+    AltMissing => "alt-missing",
+    StyleAttribute => "style-attribute",
+    AltAttribute => "alt-attribute",
+    LangAttribute => "lang-attribute",
+    RequiredWhenParent => "required-when-parent",
+    AttributeNotEmpty => "attribute-not-empty",
+    // AttributeValue
+    UniqueId => "unique-id",
+    PositiveNumber => "positive-number",
+    NumericRange => "numeric-range",
+    IntegerOnly => "integer-only",
+    ReferenceExists => "reference-exists",
+    UrlScheme => "url-scheme",
+    BooleanAttribute => "boolean-attribute",
+    ClassOrder => "class-order",
+    // ElementOrder
+    SequentialOrder => "sequential-order",
+    // TextContent
+    MaxLength => "max-length",
+    MinLength => "min-length",
+    NonEmpty => "non-empty",
+    ContentLength => "content-length",
+    WordCount => "word-count",
+    SentenceCount => "sentence-count",
+    // ElementContent
+    ScriptTypeCheck => "script-type-check",
+    StyleTypeCheck => "style-type-check",
+    MetaTags => "meta-tags",
+    EmptyOrDefault => "empty-or-default",
+    WordCountRange => "word-count-range",
+    CharacterCount => "character-count",
+    ParagraphCount => "paragraph-count",
+    // WhiteSpace
+    TrailingWhitespace => "trailing-whitespace",
+    AttributeWhitespace => "attribute-whitespace",
+    ClassListOrder => "class-list-order",
+    ConsistentIndentation => "consistent-indentation",
+    NoMixedIndentation => "no-mixed-indentation",
+    LineLength => "line-length",
+    EntityConsistency => "entity-consistency",
+    UnnecessaryEntities => "unnecessary-entities",
+    // Nesting
+    MaxDepth => "max-depth",
+    InteractiveInInteractive => "interactive-in-interactive",
+    DlStructure => "dl-structure",
+    TableStructure => "table-structure",
+    ParentLabelOrFor => "parent-label-or-for",
+    BlockInInline => "block-in-inline",
+    // Semantics
+    LandmarkUniqueness => "landmark-uniqueness",
+    DeprecatedAttributes => "deprecated-attributes",
+    FormFieldsetLegend => "form-fieldset-legend",
+    AriaLiveRegions => "aria-live-regions",
+    ListContext => "list-context",
+    TableContext => "table-context",
+    Autocomplete => "autocomplete",
+    SemanticElements => "semantic-elements",
+    SemanticLandmarks => "semantic-landmarks",
+    SemanticButtons => "semantic-buttons",
+    SemanticTables => "semantic-tables",
+    FigcaptionContent => "figcaption-content",
+    FigureStructure => "figure-structure",
+    RoleRequiredAttributes => "role-required-attributes",
+    DeprecatedUsage => "deprecated-usage",
+    // DocumentStructure
+    RequiredStructure => "required-structure",
+    NoContentBeforeHead => "no-content-before-head",
+    // ElementCount
+    MaxCount => "max-count",
+    MinCount => "min-count",
+    ExactCount => "exact-count",
+    RangeCount => "range-count",
+    // Encoding
+    CharsetDeclared => "charset-declared",
+    CharsetValid => "charset-valid",
+    BomDetected => "bom-detected",
+    // SecurityPattern
+    OnEventAttributes => "on-event-attributes",
+    JavascriptHref => "javascript-href",
+    DataUriSrc => "data-uri-src",
+    UnsafeTarget => "unsafe-target",
+    MetaRefresh => "meta-refresh",
+    // FormValidation
+    FormValidation => "form-validation",
+    InputLabelAssociation => "input-label-association",
+    RequiredFields => "required-fields",
+    FormMethod => "form-method",
+    // Manifest
+    WebAppCapable => "web-app-capable",
+}
+
+impl ConditionType {
+    /// Whether rules of `rule_type` know how to check this condition. Rule types that
+    /// fall back to a pattern, or don't look at the condition at all, accept any.
+    pub fn is_compatible_with(&self, rule_type: &RuleType) -> bool {
+        use ConditionType::*;
+
+        match rule_type {
+            RuleType::ElementPresence => matches!(
+                self,
+                ElementPresent
+                    | Required
+                    | ConditionalPresence
+                    | Forbidden
+                    | ElementAbsent
+                    | SemanticAlternativeAvailable
+                    | DoctypePresent
+            ),
+            RuleType::AttributePresence => matches!(
+                self,
+                MutuallyExclusive
+                    | DuplicateAttributes
+                    | AltMissing
+                    | StyleAttribute
+                    | AltAttribute
+                    | LangAttribute
+                    | RequiredWhenParent
+                    | AttributeNotEmpty
+            ),
+            RuleType::ElementOrder => matches!(self, SequentialOrder),
+            RuleType::ElementContent => matches!(
+                self,
+                ScriptTypeCheck
+                    | StyleTypeCheck
+                    | MetaTags
+                    | EmptyOrDefault
+                    | WordCountRange
+                    | CharacterCount
+                    | ParagraphCount
+                    | NonEmpty
+            ),
+            RuleType::WhiteSpace => matches!(
+                self,
+                TrailingWhitespace
+                    | AttributeWhitespace
+                    | ClassListOrder
+                    | ConsistentIndentation
+                    | NoMixedIndentation
+                    | LineLength
+                    | EntityConsistency
+                    | UnnecessaryEntities
+            ),
+            RuleType::Nesting => matches!(
+                self,
+                MaxDepth
+                    | InteractiveInInteractive
+                    | DlStructure
+                    | TableStructure
+                    | ParentLabelOrFor
+                    | BlockInInline
+            ),
+            RuleType::Semantics => matches!(
+                self,
+                LandmarkUniqueness
+                    | DeprecatedAttributes
+                    | FormFieldsetLegend
+                    | AriaLiveRegions
+                    | ListContext
+                    | TableContext
+                    | Autocomplete
+                    | SemanticElements
+                    | SemanticLandmarks
+                    | SemanticButtons
+                    | SemanticTables
+                    | FigcaptionContent
+                    | FigureStructure
+                    | RoleRequiredAttributes
+                    | DeprecatedUsage
+            ),
+            RuleType::DocumentStructure => matches!(
+                self,
+                DoctypePresent | RequiredStructure | NoContentBeforeHead
+            ),
+            RuleType::ElementCount => {
+                matches!(self, MaxCount | MinCount | ExactCount | RangeCount)
+            }
+            RuleType::Encoding => matches!(self, CharsetDeclared | CharsetValid | BomDetected),
+            RuleType::SecurityPattern => matches!(
+                self,
+                OnEventAttributes | JavascriptHref | DataUriSrc | UnsafeTarget | MetaRefresh
+            ),
+            RuleType::FormValidation => matches!(
+                self,
+                FormValidation | InputLabelAssociation | RequiredFields | FormMethod | Autocomplete
+            ),
+            // Any other condition is a pattern check
+            RuleType::AttributeValue | RuleType::TextContent => true,
+            RuleType::Compound
+            | RuleType::Custom(_)
+            | RuleType::ElementCase
+            | RuleType::AttributeQuotes
+            | RuleType::AriaRole
+            | RuleType::JsonLd
+            | RuleType::OpenGraph
+            | RuleType::Twitter
+            | RuleType::Microdata
+            | RuleType::Manifest => true,
+        }
+    }
+}
+
+impl From<String> for ConditionType {
+    fn from(name: String) -> Self {
+        ConditionType::from(name.as_str())
+    }
+}
+
+impl From<ConditionType> for String {
+    fn from(condition: ConditionType) -> Self {
+        condition.as_str().to_string()
+    }
+}
+
+impl fmt::Display for ConditionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,153 @@
+use crate::Severity;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Applies to every rule when a directive names no rules.
+const ALL_RULES: &str = "*";
+
+/// Line ranges where rules have been disabled with `html-linter-disable` comments, and
+/// severities overridden with `html-linter-configure` comments.
+#[derive(Debug, Default)]
+pub(crate) struct DisabledRegions {
+    regions: HashMap<String, Vec<(usize, usize)>>,
+    severities: HashMap<String, Vec<(usize, Severity)>>, // From the directive's line onwards
+}
+
+impl DisabledRegions {
+    /// Scans `source` for `<!-- html-linter-disable [rules] -->`,
+    /// `<!-- html-linter-enable [rules] -->`, `<!-- html-linter-disable-next-line [rules] -->`
+    /// and `<!-- html-linter-configure [rules] severity=warning -->`.
+    /// Rule names are separated by commas or whitespace; omitting them targets every rule.
+    pub(crate) fn parse(source: &str) -> Self {
+        let directive = Regex::new(
+            r"<!--\s*html-linter-(disable-next-line|disable|enable|configure)\b(.*?)-->",
+        )
+        .unwrap();
+
+        let mut regions: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+        let mut severities: HashMap<String, Vec<(usize, Severity)>> = HashMap::new();
+        let mut open: HashMap<String, usize> = HashMap::new();
+        let mut line = 1;
+        let mut scanned = 0;
+
+        for caps in directive.captures_iter(source) {
+            let start = caps.get(0).unwrap().start();
+            line += source[scanned..start].matches('\n').count();
+            scanned = start;
+
+            let (settings, mut names): (Vec<String>, Vec<String>) = caps[2]
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .partition(|token| token.contains('='));
+            if names.is_empty() {
+                names.push(ALL_RULES.to_string());
+            }
+
+            match &caps[1] {
+                "disable-next-line" => {
+                    // The comment may span lines, so count from where it ends
+                    let next_line = line + caps[0].matches('\n').count() + 1;
+                    for name in names {
+                        regions
+                            .entry(name)
+                            .or_default()
+                            .push((next_line, next_line));
+                    }
+                }
+                "disable" => {
+                    for name in names {
+                        open.entry(name).or_insert(line);
+                    }
+                }
+                "configure" => {
+                    let severity =
+                        settings
+                            .iter()
+                            .find_map(|setting| match setting.split_once('=')? {
+                                ("severity", value) => parse_severity(value),
+                                _ => None,
+                            });
+                    if let Some(severity) = severity {
+                        for name in names {
+                            severities
+                                .entry(name)
+                                .or_default()
+                                .push((line, severity.clone()));
+                        }
+                    }
+                }
+                _ => {
+                    let closing: Vec<String> = if names == [ALL_RULES] {
+                        open.keys().cloned().collect()
+                    } else {
+                        names
+                    };
+                    for name in closing {
+                        if let Some(start_line) = open.remove(&name) {
+                            regions.entry(name).or_default().push((start_line, line));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Regions that are never re-enabled run to the end of the document
+        for (name, start_line) in open {
+            regions
+                .entry(name)
+                .or_default()
+                .push((start_line, usize::MAX));
+        }
+
+        Self {
+            regions,
+            severities,
+        }
+    }
+
+    pub(crate) fn is_disabled(&self, rule_name: &str, line: usize) -> bool {
+        // Results without a known line can't be matched to a region
+        if line == 0 {
+            return false;
+        }
+
+        [rule_name, ALL_RULES].iter().any(|name| {
+            self.regions.get(*name).is_some_and(|ranges| {
+                ranges
+                    .iter()
+                    .any(|&(start, end)| (start..=end).contains(&line))
+            })
+        })
+    }
+
+    /// The severity set by the last `html-linter-configure` directive at or before `line`,
+    /// preferring directives that name the rule over ones that apply to every rule.
+    pub(crate) fn configured_severity(&self, rule_name: &str, line: usize) -> Option<Severity> {
+        if line == 0 {
+            return None;
+        }
+
+        [rule_name, ALL_RULES].iter().find_map(|name| {
+            self.severities
+                .get(*name)?
+                .iter()
+                .rev()
+                .find(|(start, _)| *start <= line)
+                .map(|(_, severity)| severity.clone())
+        })
+    }
+}
+
+fn parse_severity(value: &str) -> Option<Severity> {
+    match value
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "error" => Some(Severity::Error),
+        "warning" | "warn" => Some(Severity::Warning),
+        "info" => Some(Severity::Info),
+        _ => None,
+    }
+}
@@ -1,11 +1,24 @@
+use html5ever::driver::ParseOpts;
+use html5ever::tendril::TendrilSink;
+use html5ever::{namespace_url, ns, parse_fragment, LocalName, QualName};
+use markup5ever_rcdom::RcDom;
 use parking_lot::RwLock;
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::iter::Peekable;
 use string_interner::DefaultSymbol;
 use string_interner::StringInterner;
 
-use super::select::{AttributeSelector, SelectorEngine};
-use crate::dom::{IndexedAttribute, IndexedNode, QuotesType, SourceInfo, SourceMap};
-// Optimized arena with pre-allocated capacity
+use super::interner::FrozenStringInterner;
+use super::select::{
+    AttributeSelector, Combinator, PseudoClass, SelectorEngine, SelectorOptions, SelectorPart,
+};
+use super::tags::TagLocator;
+use super::utils::detect_quote_char;
+use crate::dom::{IndexedAttribute, IndexedNode, NodeKind, SourceInfo, SourceMap};
+use crate::ColumnUnit;
+// Optimized arena with pre-allocated capacity. Nodes are only added while the index is
+// being built; afterwards the arena is read-only, which is what lets rules share it across threads.
 pub struct NodeArena {
     nodes: Vec<IndexedNode>,
 }
@@ -33,6 +46,30 @@ impl NodeArena {
     pub fn get_mut(&mut self, index: usize) -> Option<&mut IndexedNode> {
         self.nodes.get_mut(index)
     }
+
+    /// Estimated bytes held by the nodes, counting their attribute, class and child lists.
+    pub fn memory_usage_bytes(&self) -> usize {
+        self.nodes
+            .iter()
+            .map(|node| {
+                size_of::<IndexedNode>()
+                    + node.attributes.len() * size_of::<IndexedAttribute>()
+                    + node.classes.len() * size_of::<DefaultSymbol>()
+                    + node.children.len() * size_of::<usize>()
+            })
+            .sum()
+    }
+}
+
+/// Size and shape of an indexed document.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DomStats {
+    pub node_count: usize,
+    pub element_count: usize,
+    pub text_node_count: usize,
+    pub interned_string_count: usize,
+    pub interned_bytes: usize,
+    pub estimated_memory_bytes: usize, // Arena only, see `NodeArena::memory_usage_bytes`
 }
 
 pub struct DOMIndex {
@@ -40,206 +77,449 @@ pub struct DOMIndex {
     elements: HashMap<DefaultSymbol, Vec<usize>>,
     ids: HashMap<DefaultSymbol, usize>,
     classes: HashMap<DefaultSymbol, Vec<usize>>,
-    interner: RwLock<StringInterner>,
+    // Built lock-free while indexing, then frozen for lock-free reads by the rules
+    interner: FrozenStringInterner,
     selector_engine: SelectorEngine,
     source_map: SourceMap,
     source: String,
+    // The index is never mutated after construction, so cached entries stay valid for its lifetime
+    descendant_cache: RwLock<HashMap<usize, Vec<usize>>>,
+    fragment: bool,
 }
 
+// Rules are processed in parallel against a shared index
+const _: () = {
+    fn assert_send_sync<T: Send + Sync>() {}
+    let _ = assert_send_sync::<DOMIndex>;
+};
+
 impl DOMIndex {
-    pub fn new(dom: &markup5ever_rcdom::RcDom, source: &str) -> Self {
-        let interner = StringInterner::with_capacity(1024);
-        let mut index = Self {
+    pub fn new(dom: &markup5ever_rcdom::RcDom, source: &str, column_unit: ColumnUnit) -> Self {
+        let mut interner = StringInterner::with_capacity(1024);
+        let mut index = Self::empty(source, column_unit);
+        let mut locator = TagLocator::new(source);
+
+        index.build_from_node(&dom.document, &mut interner, &mut locator);
+        index.interner = FrozenStringInterner::freeze(interner);
+        index
+    }
+
+    /// Parses `html` as the content of a `context_element` (usually `"body"`) and indexes it.
+    ///
+    /// Node 0 is still a document node, but the fragment's top-level nodes have no parent:
+    /// the parser's wrapper element and any `html`, `head` or `body` it inserted without a
+    /// source tag are left out.
+    pub fn from_fragment(html: &str, context_element: &str, column_unit: ColumnUnit) -> Self {
+        let dom = parse_fragment(
+            RcDom::default(),
+            ParseOpts::default(),
+            QualName::new(None, ns!(html), LocalName::from(context_element)),
+            Vec::new(),
+        )
+        .one(html);
+
+        let mut interner = StringInterner::with_capacity(1024);
+        let mut index = Self::empty(html, column_unit);
+        let mut locator = TagLocator::new(html);
+        index.fragment = true;
+        index.arena.allocate().kind = NodeKind::Document;
+
+        let mut roots = Vec::new();
+        let mut direct_text = String::new();
+        for wrapper in dom.document.children.borrow().iter() {
+            for child in wrapper.children.borrow().iter() {
+                let root_idx = index.build_from_node(child, &mut interner, &mut locator);
+                if let Some(root) = index.arena.get(root_idx) {
+                    if root.kind == NodeKind::Text {
+                        direct_text.push_str(&root.direct_text);
+                    }
+                }
+                roots.push(root_idx);
+            }
+        }
+        if let Some(document) = index.arena.get_mut(0) {
+            document.children = roots;
+            document.direct_text = direct_text;
+        }
+
+        index.interner = FrozenStringInterner::freeze(interner);
+        index.remove_synthesized_elements();
+        index
+    }
+
+    fn empty(source: &str, column_unit: ColumnUnit) -> Self {
+        Self {
             arena: NodeArena::new(),
             elements: HashMap::with_capacity(256),
             ids: HashMap::with_capacity(256),
             classes: HashMap::with_capacity(256),
-            interner: RwLock::new(interner.clone()),
-            selector_engine: SelectorEngine::new(interner),
-            source_map: SourceMap::new(source),
+            interner: FrozenStringInterner::default(),
+            selector_engine: SelectorEngine::new(StringInterner::new()),
+            source_map: SourceMap::new(source, column_unit),
             source: source.to_string(),
-        };
+            descendant_cache: RwLock::new(HashMap::new()),
+            fragment: false,
+        }
+    }
 
-        index.build_from_node(&dom.document);
-        index
+    /// Sets how selectors passed to `query` are parsed.
+    pub fn with_selector_options(mut self, options: SelectorOptions) -> Self {
+        self.selector_engine.set_options(options);
+        self
+    }
+
+    /// Warnings from the selectors queried so far, see `SelectorEngine::warnings`.
+    pub fn selector_warnings(&self) -> Vec<String> {
+        self.selector_engine.warnings()
+    }
+
+    /// Whether the index was built by `from_fragment` rather than from a whole document.
+    pub fn is_fragment(&self) -> bool {
+        self.fragment
+    }
+
+    /// Drops `html`, `head` and `body` elements that the parser inserted without a source tag.
+    pub fn remove_synthesized_elements(&mut self) {
+        for tag in ["html", "head", "body"] {
+            let Some(symbol) = self.interner.get(tag) else {
+                continue;
+            };
+            if let Some(nodes) = self.elements.get_mut(&symbol) {
+                let arena = &self.arena;
+                nodes.retain(|&idx| {
+                    arena
+                        .get(idx)
+                        .is_some_and(|node| !node.source_info.source.is_empty())
+                });
+            }
+        }
     }
 
     pub fn query(&self, selector: &str) -> Vec<usize> {
+        self.query_iter(selector).collect()
+    }
+
+    /// Lazily yields the nodes matching `selector` in document order, without collecting
+    /// them first. Prefer this over `query` when only a count or the first few are needed.
+    pub fn query_iter<'a>(&'a self, selector: &str) -> impl Iterator<Item = usize> + 'a {
         let selector = self
             .selector_engine
             .get_or_parse_selector(selector, &self.interner);
 
-        // Collect matches from all alternatives
-        let mut results = Vec::new();
-        for alt in &selector.alternatives {
-            // Optimize query path selection based on selector specificity
-            let initial_set = if let Some(first_part) = alt.first() {
-                if first_part.element.is_none()
-                    && first_part.id.is_none()
-                    && first_part.classes.is_empty()
-                    && first_part.attributes.is_empty()
-                {
-                    // Handle universal "*" selector - match all elements
-                    (0..self.arena.nodes.len()).collect()
-                } else if let Some(id) = first_part.id {
-                    self.ids.get(&id).map(|&idx| vec![idx]).unwrap_or_default()
-                } else if let Some(element) = first_part.element {
-                    self.elements.get(&element).cloned().unwrap_or_default()
-                } else if !first_part.classes.is_empty() {
-                    first_part
-                        .classes
-                        .iter()
-                        .filter_map(|class| self.classes.get(class))
-                        .min_by_key(|v| v.len())
-                        .cloned()
-                        .unwrap_or_default()
+        let mut alternatives: Vec<Peekable<Box<dyn Iterator<Item = usize> + 'a>>> = selector
+            .alternatives
+            .into_iter()
+            .filter_map(|mut sequence| {
+                let subject = sequence.pop()?;
+                let matches: Box<dyn Iterator<Item = usize> + 'a> =
+                    Box::new(self.candidates(&subject).filter(move |&idx| {
+                        self.matches_part(idx, &subject) && self.matches_context(idx, &sequence)
+                    }));
+                Some(matches.peekable())
+            })
+            .collect();
+
+        // Each alternative yields ascending indices; merge them, skipping nodes matched twice
+        std::iter::from_fn(move || {
+            let next = alternatives
+                .iter_mut()
+                .filter_map(|matches| matches.peek().copied())
+                .min()?;
+            for matches in &mut alternatives {
+                matches.next_if_eq(&next);
+            }
+            Some(next)
+        })
+    }
+
+    /// The first node matching `selector` in document order.
+    pub fn query_first(&self, selector: &str) -> Option<usize> {
+        self.query_iter(selector).next()
+    }
+
+    // Nodes that can match `part`, narrowed by its id, tag or rarest class when it has one
+    fn candidates<'a>(&'a self, part: &SelectorPart) -> Box<dyn Iterator<Item = usize> + 'a> {
+        if let Some(id) = part.id {
+            Box::new(self.ids.get(&id).copied().into_iter())
+        } else if let Some(element) = part.element {
+            Box::new(self.elements.get(&element).into_iter().flatten().copied())
+        } else if !part.classes.is_empty() {
+            let rarest = part
+                .classes
+                .iter()
+                .filter_map(|class| self.classes.get(class))
+                .min_by_key(|nodes| nodes.len());
+            Box::new(rarest.into_iter().flatten().copied())
+        } else {
+            Box::new(0..self.arena.nodes.len())
+        }
+    }
+
+    // Whether the compound selectors before the subject of a complex selector match, walking
+    // from the node at `idx` through each combinator. Every related element is tried, so
+    // `main > div p` finds a `div` child of `main` among all of the paragraph's ancestors.
+    fn matches_context(&self, idx: usize, context: &[SelectorPart]) -> bool {
+        let Some((part, rest)) = context.split_last() else {
+            return true;
+        };
+        let is_element = |idx: &usize| {
+            self.get_node(*idx)
+                .is_some_and(|node| node.kind == NodeKind::Element)
+        };
+        let parent = self.get_node(idx).and_then(|node| node.parent);
+        let previous_siblings = || {
+            let siblings = parent
+                .and_then(|parent| self.get_node(parent))
+                .map_or(&[][..], |parent| parent.children.as_slice());
+            let position = siblings.iter().position(|&sibling| sibling == idx);
+            siblings[..position.unwrap_or(0)]
+                .iter()
+                .rev()
+                .copied()
+                .filter(is_element)
+        };
+
+        let related: Vec<usize> = match part.combinator.as_ref() {
+            Some(Combinator::Child) => parent.into_iter().filter(is_element).collect(),
+            Some(Combinator::Adjacent) => previous_siblings().take(1).collect(),
+            Some(Combinator::GeneralSibling) => previous_siblings().collect(),
+            Some(Combinator::Descendant) | None => {
+                std::iter::successors(parent, |&ancestor| self.get_node(ancestor)?.parent)
+                    .filter(is_element)
+                    .collect()
+            }
+        };
+        related
+            .into_iter()
+            .any(|related| self.matches_part(related, part) && self.matches_context(related, rest))
+    }
+
+    // Whether the node at `idx` matches a single compound selector
+    fn matches_part(&self, idx: usize, part: &SelectorPart) -> bool {
+        let Some(node) = self.get_node(idx) else {
+            return false;
+        };
+
+        // Query candidates are already narrowed by tag or id, but `:is()` parts are not
+        let element_match = part.element.is_none_or(|element| node.tag_name == element);
+        let id_match = part.id.is_none_or(|id| self.ids.get(&id) == Some(&idx));
+
+        // Check classes
+        let classes_match = part
+            .classes
+            .iter()
+            .all(|class| node.classes.contains(class));
+
+        // Check attributes
+        let attrs_match = part.attributes.iter().all(|attr_sel| match attr_sel {
+            AttributeSelector::Exists(attr_name) => {
+                node.attributes.iter().any(|a| a.name == *attr_name)
+            }
+            AttributeSelector::Equals(attr_name, value) => node
+                .attributes
+                .iter()
+                .any(|a| a.name == *attr_name && a.value == *value),
+            AttributeSelector::StartsWith(attr_name, value) => node.attributes.iter().any(|a| {
+                if a.name == *attr_name {
+                    let attr_str = self.interner.resolve(a.value).unwrap();
+                    let value_str = self.interner.resolve(*value).unwrap();
+                    attr_str.starts_with(&*value_str)
                 } else {
-                    (0..self.arena.nodes.len()).collect()
+                    false
                 }
-            } else {
-                Vec::new()
-            };
+            }),
+            AttributeSelector::EndsWith(attr_name, value) => node.attributes.iter().any(|a| {
+                if a.name == *attr_name {
+                    let attr_str = self.interner.resolve(a.value).unwrap();
+                    let value_str = self.interner.resolve(*value).unwrap();
+                    attr_str.ends_with(&*value_str)
+                } else {
+                    false
+                }
+            }),
+            AttributeSelector::Contains(attr_name, value) => node.attributes.iter().any(|a| {
+                if a.name == *attr_name {
+                    let attr_str = self.interner.resolve(a.value).unwrap();
+                    let value_str = self.interner.resolve(*value).unwrap();
+                    attr_str.contains(&*value_str)
+                } else {
+                    false
+                }
+            }),
+            AttributeSelector::ListContains(attr_name, value) => node.attributes.iter().any(|a| {
+                if a.name == *attr_name {
+                    let attr_str = self.interner.resolve(a.value).unwrap();
+                    let value_str = self.interner.resolve(*value).unwrap();
+                    attr_str
+                        .split_ascii_whitespace()
+                        .any(|part| part == value_str)
+                } else {
+                    false
+                }
+            }),
+            AttributeSelector::DashMatch(attr_name, value) => node.attributes.iter().any(|a| {
+                if a.name == *attr_name {
+                    let attr_str = self.interner.resolve(a.value).unwrap();
+                    let value_str = self.interner.resolve(*value).unwrap();
+                    attr_str == value_str || attr_str.starts_with(&format!("{}-", value_str))
+                } else {
+                    false
+                }
+            }),
+        });
 
-            // Apply remaining filters
-            let matches: Vec<usize> = initial_set
-                .into_iter()
-                .filter(|&idx| {
-                    let node = unsafe { self.arena.nodes.get_unchecked(idx) };
-
-                    // Check classes
-                    let classes_match = if let Some(first_part) = alt.first() {
-                        first_part
-                            .classes
-                            .iter()
-                            .all(|class| node.classes.contains(class))
-                    } else {
-                        true
-                    };
+        // Check pseudo-classes
+        let pseudo_match = part
+            .pseudo_classes
+            .iter()
+            .all(|pseudo| self.matches_pseudo_class(idx, pseudo));
 
-                    // Check attributes
-                    let attrs_match = if let Some(first_part) = alt.first() {
-                        first_part.attributes.iter().all(|attr_sel| match attr_sel {
-                            AttributeSelector::Exists(attr_name) => {
-                                node.attributes.iter().any(|a| a.name == *attr_name)
-                            }
-                            AttributeSelector::Equals(attr_name, value) => node
-                                .attributes
-                                .iter()
-                                .any(|a| a.name == *attr_name && a.value == *value),
-                            AttributeSelector::StartsWith(attr_name, value) => {
-                                node.attributes.iter().any(|a| {
-                                    if a.name == *attr_name {
-                                        let interner = self.interner.read();
-                                        let attr_str = interner.resolve(a.value).unwrap();
-                                        let value_str = interner.resolve(*value).unwrap();
-                                        attr_str.starts_with(value_str)
-                                    } else {
-                                        false
-                                    }
-                                })
-                            }
-                            AttributeSelector::EndsWith(attr_name, value) => {
-                                node.attributes.iter().any(|a| {
-                                    if a.name == *attr_name {
-                                        let interner = self.interner.read();
-                                        let attr_str = interner.resolve(a.value).unwrap();
-                                        let value_str = interner.resolve(*value).unwrap();
-                                        attr_str.ends_with(value_str)
-                                    } else {
-                                        false
-                                    }
-                                })
-                            }
-                            AttributeSelector::Contains(attr_name, value) => {
-                                node.attributes.iter().any(|a| {
-                                    if a.name == *attr_name {
-                                        let interner = self.interner.read();
-                                        let attr_str = interner.resolve(a.value).unwrap();
-                                        let value_str = interner.resolve(*value).unwrap();
-                                        attr_str.contains(value_str)
-                                    } else {
-                                        false
-                                    }
-                                })
-                            }
-                            AttributeSelector::ListContains(attr_name, value) => {
-                                node.attributes.iter().any(|a| {
-                                    if a.name == *attr_name {
-                                        let interner = self.interner.read();
-                                        let attr_str = interner.resolve(a.value).unwrap();
-                                        let value_str = interner.resolve(*value).unwrap();
-                                        attr_str.split_whitespace().any(|part| part == value_str)
-                                    } else {
-                                        false
-                                    }
-                                })
-                            }
-                            AttributeSelector::DashMatch(attr_name, value) => {
-                                node.attributes.iter().any(|a| {
-                                    if a.name == *attr_name {
-                                        let interner = self.interner.read();
-                                        let attr_str = interner.resolve(a.value).unwrap();
-                                        let value_str = interner.resolve(*value).unwrap();
-                                        attr_str == value_str
-                                            || attr_str.starts_with(&format!("{}-", value_str))
-                                    } else {
-                                        false
-                                    }
-                                })
-                            }
-                        })
-                    } else {
-                        true
-                    };
+        element_match && id_match && classes_match && attrs_match && pseudo_match
+    }
+
+    fn matches_pseudo_class(&self, idx: usize, pseudo: &PseudoClass) -> bool {
+        let Some(node) = self.get_node(idx) else {
+            return false;
+        };
+        if node.kind != NodeKind::Element {
+            return false;
+        }
+
+        // Positions are counted among element siblings only
+        let siblings: Vec<usize> = node
+            .parent
+            .and_then(|parent| self.get_node(parent))
+            .map(|parent| {
+                parent
+                    .children
+                    .iter()
+                    .copied()
+                    .filter(|&child| {
+                        self.get_node(child)
+                            .is_some_and(|child| child.kind == NodeKind::Element)
+                    })
+                    .collect()
+            })
+            .unwrap_or_else(|| vec![idx]);
+        let same_type: Vec<usize> = siblings
+            .iter()
+            .copied()
+            .filter(|&sibling| {
+                self.get_node(sibling)
+                    .is_some_and(|sibling| sibling.tag_name == node.tag_name)
+            })
+            .collect();
+        let position = |list: &[usize]| {
+            list.iter()
+                .position(|&sibling| sibling == idx)
+                .map_or(0, |pos| pos as i32 + 1)
+        };
 
-                    classes_match && attrs_match
+        match pseudo {
+            PseudoClass::Root => node.parent == Some(0),
+            PseudoClass::FirstChild => siblings.first() == Some(&idx),
+            PseudoClass::LastChild => siblings.last() == Some(&idx),
+            PseudoClass::OnlyChild => siblings.len() == 1,
+            PseudoClass::NthChild(a, b) => nth_matches(*a, *b, position(&siblings)),
+            PseudoClass::NthLastChild(a, b) => {
+                nth_matches(*a, *b, siblings.len() as i32 + 1 - position(&siblings))
+            }
+            PseudoClass::FirstOfType => same_type.first() == Some(&idx),
+            PseudoClass::LastOfType => same_type.last() == Some(&idx),
+            PseudoClass::OnlyOfType => same_type.len() == 1,
+            PseudoClass::NthOfType(a, b) => nth_matches(*a, *b, position(&same_type)),
+            PseudoClass::Empty => node.children.iter().all(|&child| {
+                self.get_node(child).map_or(true, |child| match child.kind {
+                    NodeKind::Element => false,
+                    NodeKind::Text => child.direct_text.is_empty(),
+                    _ => true,
                 })
-                .collect();
+            }),
+            PseudoClass::Not(parts) => !parts.iter().any(|part| self.matches_part(idx, part)),
+            PseudoClass::Is(parts) => parts.iter().any(|part| self.matches_part(idx, part)),
+            PseudoClass::Unsupported => false,
+        }
+    }
+
+    /// Like `query`, but only returns nodes that are descendants of `root_idx`.
+    pub fn query_within(&self, root_idx: usize, selector: &str) -> Vec<usize> {
+        let matches = self.query(selector);
 
-            results.extend(matches);
+        if let Some(descendants) = self.descendant_cache.read().get(&root_idx) {
+            return Self::intersect(matches, descendants);
         }
 
-        // Remove duplicates that might occur from multiple matching alternatives
-        results.sort_unstable();
-        results.dedup();
-        results
+        let descendants = self.collect_descendants(root_idx);
+        let result = Self::intersect(matches, &descendants);
+        self.descendant_cache.write().insert(root_idx, descendants);
+        result
     }
 
-    fn build_from_node(&mut self, handle: &markup5ever_rcdom::Handle) -> usize {
+    fn collect_descendants(&self, root_idx: usize) -> Vec<usize> {
+        let mut descendants = Vec::new();
+        let mut stack: Vec<usize> = self
+            .get_node(root_idx)
+            .map(|node| node.children.clone())
+            .unwrap_or_default();
+
+        while let Some(idx) = stack.pop() {
+            descendants.push(idx);
+            if let Some(node) = self.get_node(idx) {
+                stack.extend(&node.children);
+            }
+        }
+
+        descendants.sort_unstable();
+        descendants
+    }
+
+    // Both inputs are sorted, as `query` results always are
+    fn intersect(matches: Vec<usize>, descendants: &[usize]) -> Vec<usize> {
+        matches
+            .into_iter()
+            .filter(|idx| descendants.binary_search(idx).is_ok())
+            .collect()
+    }
+
+    fn build_from_node(
+        &mut self,
+        handle: &markup5ever_rcdom::Handle,
+        interner: &mut StringInterner,
+        locator: &mut TagLocator,
+    ) -> usize {
         let idx = self.arena.nodes.len();
         let node = self.arena.allocate();
-        node.handle = Some(handle.clone());
 
         match &handle.data {
+            markup5ever_rcdom::NodeData::Document => node.kind = NodeKind::Document,
+            markup5ever_rcdom::NodeData::Doctype { .. } => node.kind = NodeKind::Doctype,
             markup5ever_rcdom::NodeData::Element { name, attrs, .. } => {
-                // Extract source info from the node
-                let source_text = Self::extract_node_source(handle);
-                let tag = self.interner.write().get_or_intern(&name.local);
+                node.kind = NodeKind::Element;
+                let tag = interner.get_or_intern(&name.local);
                 node.tag_name = tag;
                 self.elements.entry(tag).or_default().push(idx);
 
-                if let Some(source_text) = source_text {
-                    if let Some(offset) = self.source.find(&source_text) {
-                        let (line, column) = self.source_map.get_position(offset);
-                        node.source_info = SourceInfo {
-                            line,
-                            column,
-                            source: source_text,
-                        };
-                    }
+                if let Some(span) = locator.locate(&name.local) {
+                    let (line, column) = self.source_map.get_position(span.start);
+                    node.source_info = SourceInfo {
+                        line,
+                        column,
+                        source: self.source[span].to_string(),
+                    };
                 }
 
                 for attr in attrs.borrow().iter() {
-                    let name = self.interner.write().get_or_intern(&attr.name.local);
-                    let value = self.interner.write().get_or_intern(&attr.value);
+                    let name = interner.get_or_intern(&attr.name.local);
+                    let value = interner.get_or_intern(&attr.value);
 
                     match &*attr.name.local {
                         "id" => {
-                            self.ids.insert(value, idx);
+                            // Like `getElementById`, the first element with a duplicated id wins
+                            self.ids.entry(value).or_insert(idx);
                         }
                         "class" => {
                             for class in attr.value.split_whitespace() {
-                                let class_sym = self.interner.write().get_or_intern(class);
+                                let class_sym = interner.get_or_intern(class);
                                 node.classes.push(class_sym);
                                 self.classes.entry(class_sym).or_default().push(idx);
                             }
@@ -250,93 +530,346 @@ impl DOMIndex {
                     node.attributes.push(IndexedAttribute {
                         name,
                         value,
-                        quotes_type: if attr.value.contains('\'') {
-                            QuotesType::Single
-                        } else {
-                            QuotesType::Double
-                        },
+                        // Read from the start tag as written, so unknown when it wasn't located
+                        quotes_type: detect_quote_char(&node.source_info.source, &attr.name.local),
                     });
                 }
             }
             markup5ever_rcdom::NodeData::Text { contents } => {
                 let text = contents.borrow();
+                node.kind = NodeKind::Text;
+                node.direct_text = text.to_string();
                 if !text.trim().is_empty() {
-                    node.text_content =
-                        Some(self.interner.write().get_or_intern(&text.to_string()));
+                    node.text_content = Some(interner.get_or_intern(&text.to_string()));
                 }
             }
             _ => {}
         }
 
+        let mut children = Vec::new();
+        let mut direct_text = String::new();
         for child in handle.children.borrow().iter() {
-            let child_idx = self.build_from_node(child);
+            let child_idx = self.build_from_node(child, interner, locator);
             if let Some(child_node) = self.arena.get_mut(child_idx) {
                 child_node.parent = Some(idx);
+                if child_node.kind == NodeKind::Text {
+                    direct_text.push_str(&child_node.direct_text);
+                }
             }
+            children.push(child_idx);
+        }
+        if let Some(node) = self.arena.get_mut(idx) {
+            node.children = children;
+            node.direct_text.push_str(&direct_text);
         }
 
         idx
     }
 
-    fn extract_node_source(handle: &markup5ever_rcdom::Handle) -> Option<String> {
-        match &handle.data {
-            markup5ever_rcdom::NodeData::Element { name, attrs, .. } => {
-                let mut source = String::new();
-                source.push('<');
-                source.push_str(&name.local);
+    pub fn get_node(&self, index: usize) -> Option<&IndexedNode> {
+        self.arena.get(index)
+    }
 
-                for attr in attrs.borrow().iter() {
-                    source.push(' ');
-                    source.push_str(&attr.name.local);
-                    source.push('=');
-                    match attr.value.contains('\'') {
-                        true => {
-                            source.push('"');
-                            source.push_str(&attr.value);
-                            source.push('"');
-                        }
-                        false => {
-                            source.push('\'');
-                            source.push_str(&attr.value);
-                            source.push('\'');
-                        }
-                    }
-                }
-                source.push('>');
-                Some(source)
+    pub fn get_nodes(&self) -> &[IndexedNode] {
+        &self.arena.nodes
+    }
+
+    /// Node indices in document order: a depth-first pre-order walk from the document node.
+    pub fn topological_order(&self) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.arena.nodes.len());
+        let mut stack = vec![0];
+        while let Some(idx) = stack.pop() {
+            let Some(node) = self.get_node(idx) else {
+                continue;
+            };
+            order.push(idx);
+            stack.extend(node.children.iter().rev());
+        }
+        order
+    }
+
+    /// Arena index of a node borrowed from this index, found through its parent's children.
+    pub fn node_index_of(&self, node: &IndexedNode) -> Option<usize> {
+        match node.parent {
+            Some(parent_idx) => {
+                self.get_node(parent_idx)?
+                    .children
+                    .iter()
+                    .copied()
+                    .find(|&child_idx| {
+                        self.get_node(child_idx)
+                            .is_some_and(|child| std::ptr::eq(child, node))
+                    })
             }
-            markup5ever_rcdom::NodeData::Text { contents } => Some(contents.borrow().to_string()),
-            _ => None,
+            // The document itself, or one of a fragment's top-level nodes
+            None => std::iter::once(0)
+                .chain(self.get_node(0)?.children.iter().copied())
+                .find(|&idx| {
+                    self.get_node(idx)
+                        .is_some_and(|root| std::ptr::eq(root, node))
+                }),
         }
     }
 
-    pub fn get_node(&self, index: usize) -> Option<&IndexedNode> {
-        self.arena.get(index)
+    /// Looks `id` up in the id map without going through the selector engine.
+    pub(crate) fn get_element_by_id(&self, id: &str) -> Option<&IndexedNode> {
+        let symbol = self.interner.get(id)?;
+        self.ids.get(&symbol).and_then(|&idx| self.get_node(idx))
     }
 
-    pub fn get_nodes(&self) -> &[IndexedNode] {
-        &self.arena.nodes
+    /// Indices of the elements named `tag`, in document order.
+    pub(crate) fn get_elements_by_tag(&self, tag: &str) -> &[usize] {
+        self.interner
+            .get(tag)
+            .and_then(|symbol| self.elements.get(&symbol))
+            .map_or(&[], Vec::as_slice)
     }
 
     pub fn resolve_symbol(&self, symbol: DefaultSymbol) -> Option<String> {
-        self.interner.read().resolve(symbol).map(|s| s.to_string())
+        self.interner.resolve(symbol).map(Cow::into_owned)
     }
 
     pub fn get_source_map(&self) -> &SourceMap {
         &self.source_map
     }
 
+    pub fn get_source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn stats(&self) -> DomStats {
+        let nodes = self.get_nodes();
+
+        DomStats {
+            node_count: nodes.len(),
+            element_count: nodes
+                .iter()
+                .filter(|node| node.kind == NodeKind::Element)
+                .count(),
+            text_node_count: nodes
+                .iter()
+                .filter(|node| node.kind == NodeKind::Text)
+                .count(),
+            interned_string_count: self.interner.len(),
+            interned_bytes: self.interner.byte_len(),
+            estimated_memory_bytes: self.arena.memory_usage_bytes(),
+        }
+    }
+
     pub fn has_doctype(&self) -> bool {
+        if self.fragment {
+            return false;
+        }
         // Check if any direct child of the document is a DOCTYPE declaration
-        if let Some(document) = self.get_node(0) {
-            if let Some(handle) = &document.handle {
-                for child in handle.children.borrow().iter() {
-                    if let markup5ever_rcdom::NodeData::Doctype { .. } = child.data {
-                        return true;
-                    }
-                }
-            }
+        self.get_node(0).is_some_and(|document| {
+            document.children.iter().any(|&child_idx| {
+                self.get_node(child_idx)
+                    .is_some_and(|child| child.kind == NodeKind::Doctype)
+            })
+        })
+    }
+}
+
+/// Whether the 1-based `position` is selected by `an+b` for some n >= 0.
+fn nth_matches(a: i32, b: i32, position: i32) -> bool {
+    if a == 0 {
+        return position == b;
+    }
+    let offset = position - b;
+    offset % a == 0 && offset / a >= 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use html5ever::driver::ParseOpts;
+    use html5ever::parse_document;
+    use html5ever::tendril::TendrilSink;
+    use markup5ever_rcdom::RcDom;
+
+    fn index(html: &str) -> DOMIndex {
+        let dom = parse_document(RcDom::default(), ParseOpts::default())
+            .from_utf8()
+            .read_from(&mut html.as_bytes())
+            .unwrap();
+        DOMIndex::new(&dom, html, ColumnUnit::default())
+    }
+
+    #[test]
+    fn test_get_element_by_id() {
+        let index =
+            index("<body><p id='intro'>One</p><div id='dup'></div><span id='dup'></span></body>");
+
+        let intro = index.get_element_by_id("intro").unwrap();
+        assert_eq!(index.resolve_symbol(intro.tag_name).unwrap(), "p");
+        let dup = index.get_element_by_id("dup").unwrap();
+        assert_eq!(index.resolve_symbol(dup.tag_name).unwrap(), "div");
+        assert!(index.get_element_by_id("missing").is_none());
+    }
+
+    #[test]
+    fn test_get_elements_by_tag() {
+        let index = index("<body><p>One</p><div><p>Two</p></div></body>");
+
+        assert_eq!(index.get_elements_by_tag("p"), index.query("p").as_slice());
+        assert_eq!(index.get_elements_by_tag("p").len(), 2);
+        assert!(index.get_elements_by_tag("table").is_empty());
+    }
+
+    #[test]
+    fn test_query_iter() {
+        let index = index(
+            "<body><h2 class='title'>A</h2><p id='intro'>B</p><h1>C</h1><p class='title'>D</p></body>",
+        );
+
+        let titles: Vec<usize> = index.query_iter(".title").collect();
+        assert_eq!(titles, index.query(".title"));
+        assert_eq!(titles.len(), 2);
+        assert!(titles[0] < titles[1]);
+
+        assert_eq!(index.query_iter("p").count(), 2);
+        assert_eq!(
+            index.query_first("p"),
+            index.query("#intro").first().copied()
+        );
+        assert_eq!(index.query_first("table"), None);
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let index = index("<body><div><p>One</p><ul><li>Two</li></ul></div><h1>Three</h1></body>");
+        let tags: Vec<String> = index
+            .topological_order()
+            .into_iter()
+            .filter_map(|idx| index.get_node(idx))
+            .filter(|node| node.kind == NodeKind::Element)
+            .map(|node| index.resolve_symbol(node.tag_name).unwrap())
+            .collect();
+
+        assert_eq!(
+            tags,
+            vec!["html", "head", "body", "div", "p", "ul", "li", "h1"]
+        );
+        assert_eq!(index.topological_order().len(), index.get_nodes().len());
+    }
+
+    #[test]
+    fn test_stats() {
+        let index =
+            index("<html><head></head><body><p class='a b'>One</p><p>Two</p></body></html>");
+        let stats = index.stats();
+
+        // Document, html, head, body, two paragraphs and their text nodes
+        assert_eq!(stats.node_count, 8);
+        assert_eq!(stats.element_count, 5);
+        assert_eq!(stats.text_node_count, 2);
+        assert!(stats.interned_string_count > 0);
+        assert!(stats.interned_bytes >= "htmlheadbodypclassa b".len());
+        assert_eq!(
+            stats.estimated_memory_bytes,
+            index.arena.memory_usage_bytes()
+        );
+        assert!(stats.estimated_memory_bytes > 8 * size_of::<IndexedNode>());
+    }
+
+    #[test]
+    fn test_uppercase_element_selectors() {
+        let html = "<body><IMG src='a.png'><Div>Text</Div></body>";
+        let exact = index(html);
+        assert!(exact.query("IMG").is_empty());
+        assert!(exact.query("Div").is_empty());
+        exact.query("IMG");
+        assert_eq!(
+            exact.selector_warnings(),
+            vec![
+                "Element selector `IMG` contains uppercase letters and won't match parsed HTML elements; use `img` or enable case_insensitive_elements",
+                "Element selector `Div` contains uppercase letters and won't match parsed HTML elements; use `div` or enable case_insensitive_elements",
+            ]
+        );
+
+        let folded = index(html).with_selector_options(SelectorOptions {
+            case_insensitive_elements: true,
+        });
+        assert_eq!(folded.query("IMG").len(), 1);
+        assert_eq!(folded.query("Div").len(), 1);
+        assert!(folded.selector_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_pseudo_class_warnings() {
+        let index = index("<body><p>Text</p></body>");
+        assert!(index.query("p:hover").is_empty());
+        assert!(index.query(":is(p,").is_empty());
+        assert_eq!(
+            index.selector_warnings(),
+            vec![
+                "Pseudo-class `:hover` is not supported and matches nothing",
+                "Pseudo-class `:is(p,` is missing its closing parenthesis and matches nothing",
+            ]
+        );
+    }
+
+    fn tags(index: &DOMIndex, nodes: &[usize]) -> Vec<String> {
+        nodes
+            .iter()
+            .filter_map(|&idx| index.get_node(idx))
+            .filter(|node| node.kind == NodeKind::Element)
+            .map(|node| index.resolve_symbol(node.tag_name).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_from_fragment_list() {
+        let index = DOMIndex::from_fragment(
+            "<ul class='nav'>\n  <li>One</li>\n  <li>Two</li>\n</ul>",
+            "body",
+            ColumnUnit::default(),
+        );
+
+        assert!(index.is_fragment());
+        assert!(!index.has_doctype());
+        for tag in ["html", "head", "body"] {
+            assert!(index.query(tag).is_empty());
         }
-        false
+
+        let roots = &index.get_node(0).unwrap().children;
+        assert_eq!(tags(&index, roots), vec!["ul"]);
+        let list = index.get_node(roots[0]).unwrap();
+        assert_eq!(list.parent, None);
+        assert_eq!(index.node_index_of(list), Some(roots[0]));
+        assert_eq!(list.source_info.line, 1);
+
+        let items = index.query("li");
+        assert_eq!(items.len(), 2);
+        assert!(items
+            .iter()
+            .all(|&idx| index.get_node(idx).unwrap().parent == Some(roots[0])));
+    }
+
+    #[test]
+    fn test_from_fragment_form() {
+        let index = DOMIndex::from_fragment(
+            "<!DOCTYPE html><label for='q'>Search</label><form action='/s'><input id='q' name='q'><input type='submit'></form>",
+            "body",
+            ColumnUnit::default(),
+        );
+
+        assert!(!index.has_doctype());
+        let roots = &index.get_node(0).unwrap().children;
+        assert_eq!(tags(&index, roots), vec!["label", "form"]);
+        assert_eq!(index.query_within(roots[1], "input").len(), 2);
+        assert!(index.get_element_by_id("q").is_some());
+    }
+
+    #[test]
+    fn test_from_fragment_context() {
+        let html = "<tr><td>Cell</td></tr>";
+        let in_body = DOMIndex::from_fragment(html, "body", ColumnUnit::default());
+        assert!(in_body.query("td").is_empty());
+
+        let in_tbody = DOMIndex::from_fragment(html, "tbody", ColumnUnit::default());
+        let roots = &in_tbody.get_node(0).unwrap().children;
+        assert_eq!(tags(&in_tbody, roots), vec!["tr"]);
+        assert_eq!(in_tbody.query("td").len(), 1);
     }
 }
@@ -0,0 +1,121 @@
+use parking_lot::RwLock;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+use string_interner::{DefaultSymbol, StringInterner, Symbol};
+
+/// The strings interned while building a `DOMIndex`, frozen once the index is built so that
+/// lookups and resolution need no lock.
+///
+/// Selectors can name strings the document doesn't contain, such as the value in
+/// `[href^=https]`. Those are interned into a small locked overflow whose symbols follow the
+/// frozen ones, so only selector parsing and matching against such values ever lock.
+#[derive(Default)]
+pub(crate) struct FrozenStringInterner {
+    strings: Arc<Vec<Arc<str>>>,
+    symbols: HashMap<Arc<str>, DefaultSymbol>,
+    overflow: RwLock<Vec<Arc<str>>>,
+}
+
+impl FrozenStringInterner {
+    pub(crate) fn freeze(interner: StringInterner) -> Self {
+        let mut strings: Vec<Arc<str>> = vec![Arc::from(""); interner.len()];
+        let mut symbols = HashMap::with_capacity(interner.len());
+        for (symbol, string) in &interner {
+            let string: Arc<str> = Arc::from(string);
+            let slot = symbol.to_usize();
+            if slot >= strings.len() {
+                strings.resize(slot + 1, Arc::from(""));
+            }
+            strings[slot] = string.clone();
+            symbols.insert(string, symbol);
+        }
+
+        Self {
+            strings: Arc::new(strings),
+            symbols,
+            overflow: RwLock::new(Vec::new()),
+        }
+    }
+
+    pub(crate) fn get(&self, string: &str) -> Option<DefaultSymbol> {
+        self.symbols.get(string).copied().or_else(|| {
+            let position = self
+                .overflow
+                .read()
+                .iter()
+                .position(|interned| &**interned == string)?;
+            DefaultSymbol::try_from_usize(self.strings.len() + position)
+        })
+    }
+
+    pub(crate) fn get_or_intern(&self, string: &str) -> DefaultSymbol {
+        if let Some(symbol) = self.get(string) {
+            return symbol;
+        }
+
+        let mut overflow = self.overflow.write();
+        // Another thread may have interned it between the lookup and taking the lock
+        let position = match overflow.iter().position(|interned| &**interned == string) {
+            Some(position) => position,
+            None => {
+                overflow.push(Arc::from(string));
+                overflow.len() - 1
+            }
+        };
+        DefaultSymbol::try_from_usize(self.strings.len() + position)
+            .expect("interner symbol space exhausted")
+    }
+
+    pub(crate) fn resolve(&self, symbol: DefaultSymbol) -> Option<Cow<'_, str>> {
+        let slot = symbol.to_usize();
+        match self.strings.get(slot) {
+            Some(string) => Some(Cow::Borrowed(string)),
+            None => self
+                .overflow
+                .read()
+                .get(slot - self.strings.len())
+                .map(|string| Cow::Owned(string.to_string())),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.strings.len() + self.overflow.read().len()
+    }
+
+    /// Total length in bytes of every interned string.
+    pub(crate) fn byte_len(&self) -> usize {
+        self.strings
+            .iter()
+            .chain(self.overflow.read().iter())
+            .map(|string| string.len())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frozen_and_overflow_symbols() {
+        let mut interner = StringInterner::new();
+        let div = interner.get_or_intern("div");
+        let class = interner.get_or_intern("class");
+        let frozen = FrozenStringInterner::freeze(interner);
+
+        assert_eq!(frozen.get("div"), Some(div));
+        assert_eq!(frozen.resolve(class).as_deref(), Some("class"));
+        assert_eq!(frozen.get("https"), None);
+
+        // Strings first seen after freezing get new symbols that resolve as well
+        let https = frozen.get_or_intern("https");
+        assert_ne!(https, div);
+        assert_ne!(https, class);
+        assert_eq!(frozen.get_or_intern("https"), https);
+        assert_eq!(frozen.get_or_intern("div"), div);
+        assert_eq!(frozen.resolve(https).as_deref(), Some("https"));
+        assert_eq!(frozen.len(), 3);
+        assert_eq!(frozen.byte_len(), "divclasshttps".len());
+    }
+}
@@ -1,14 +1,17 @@
 pub(crate) mod index;
+pub(crate) mod interner;
 pub(crate) mod select;
+pub(crate) mod tags;
 pub(crate) mod utils;
 
-use markup5ever_rcdom::Handle;
 use string_interner::Symbol;
 
+use crate::ColumnUnit;
+
 pub(crate) use self::index::*;
 
 #[derive(Debug)]
-pub(crate) struct IndexedNode {
+pub struct IndexedNode {
     pub tag_name: string_interner::DefaultSymbol,
     pub attributes: Vec<IndexedAttribute>,
     pub classes: Vec<string_interner::DefaultSymbol>,
@@ -16,7 +19,17 @@ pub(crate) struct IndexedNode {
     pub children: Vec<usize>,
     pub source_info: SourceInfo,
     pub text_content: Option<string_interner::DefaultSymbol>,
-    pub handle: Option<Handle>,
+    pub kind: NodeKind,
+    pub direct_text: String, // Own text for text nodes, concatenated text children otherwise
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NodeKind {
+    Document,
+    Doctype,
+    Element,
+    Text,
+    Other,
 }
 
 impl Default for IndexedNode {
@@ -33,51 +46,167 @@ impl Default for IndexedNode {
                 source: String::new(),
             },
             text_content: None,
-            handle: None,
+            kind: NodeKind::Other,
+            direct_text: String::new(),
         }
     }
 }
 
 #[derive(Clone, Debug)]
-pub(crate) struct IndexedAttribute {
+pub struct IndexedAttribute {
     pub name: string_interner::DefaultSymbol,
     pub value: string_interner::DefaultSymbol,
-    pub quotes_type: QuotesType,
+    pub quotes_type: Option<QuotesType>, // `None` for valueless attributes or unlocated tags
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
-pub(crate) enum QuotesType {
+pub enum QuotesType {
     Single,
     Double,
 }
 
 #[derive(Clone, Debug)]
-pub(crate) struct SourceInfo {
+pub struct SourceInfo {
     pub line: usize,
     pub column: usize,
     pub source: String,
 }
 
-pub(crate) struct SourceMap {
+const CONTEXT_LINES: usize = 3;
+
+/// The line endings used by a document. Documents without any line break count as `Lf`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LineEnding {
+    Lf,
+    CrLf,
+    Mixed,
+}
+
+pub struct SourceMap {
     pub lines: Vec<String>,
     pub line_offsets: Vec<usize>,
+    pub column_unit: ColumnUnit,
+    pub line_ending: LineEnding,
+    ends_with_newline: bool,
 }
 
 impl SourceMap {
-    pub fn new(source: &str) -> Self {
-        let lines: Vec<String> = source.lines().map(String::from).collect();
-        let mut line_offsets = Vec::with_capacity(lines.len());
-        let mut offset = 0;
-
-        for line in &lines {
-            line_offsets.push(offset);
-            offset += line.len() + 1; // +1 for newline
+    pub fn new(source: &str, column_unit: ColumnUnit) -> Self {
+        // Lines end at `\n` or `\r\n`, like `str::lines`, but offsets have to count the `\r` too
+        let bytes = source.as_bytes();
+        let mut lines = Vec::new();
+        let mut line_offsets = Vec::new();
+        let (mut has_lf, mut has_crlf) = (false, false);
+        let mut start = 0;
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            if byte != b'\n' {
+                continue;
+            }
+            let end = if i > start && bytes[i - 1] == b'\r' {
+                has_crlf = true;
+                i - 1
+            } else {
+                has_lf = true;
+                i
+            };
+            line_offsets.push(start);
+            lines.push(source[start..end].to_string());
+            start = i + 1;
+        }
+        if start < bytes.len() {
+            line_offsets.push(start);
+            lines.push(source[start..].to_string());
         }
 
+        let line_ending = match (has_lf, has_crlf) {
+            (true, true) => LineEnding::Mixed,
+            (false, true) => LineEnding::CrLf,
+            _ => LineEnding::Lf,
+        };
+
         Self {
             lines,
             line_offsets,
+            column_unit,
+            line_ending,
+            ends_with_newline: source.ends_with('\n'),
+        }
+    }
+
+    /// The source with every line ending converted to `\n`.
+    pub fn normalize_line_endings(&self) -> String {
+        let mut normalized = self.lines.join("\n");
+        if self.ends_with_newline {
+            normalized.push('\n');
+        }
+        normalized
+    }
+
+    /// The 1-based `line`, without its line ending.
+    pub fn get_line(&self, line: usize) -> Option<&str> {
+        line.checked_sub(1)
+            .and_then(|i| self.lines.get(i))
+            .map(String::as_str)
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Returns `line` with up to `context_lines` lines either side, clamped to the document.
+    /// The target line is prefixed with `> ` and the others with two spaces.
+    pub fn get_snippet(&self, line: usize, context_lines: usize) -> String {
+        if line == 0 || line > self.line_count() {
+            return String::new();
         }
+
+        let first = line.saturating_sub(context_lines).max(1);
+        let last = line.saturating_add(context_lines).min(self.line_count());
+        (first..=last)
+            .filter_map(|number| {
+                let marker = if number == line { "> " } else { "  " };
+                self.get_line(number)
+                    .map(|text| format!("{}{}", marker, text))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Renders up to `CONTEXT_LINES` lines either side of `line` in a `rustc`-like gutter,
+    /// with a caret under `column`. Returns an empty string for unknown positions.
+    pub fn context(&self, line: usize, column: usize) -> String {
+        if line == 0 || line > self.line_count() {
+            return String::new();
+        }
+
+        let first = line.saturating_sub(CONTEXT_LINES).max(1);
+        let last = (line + CONTEXT_LINES).min(self.line_count());
+        let width = last.to_string().len();
+
+        // The snippet's lines, numbered and with the target line marked
+        let snippet = self.get_snippet(line, CONTEXT_LINES);
+        let mut context = Vec::new();
+        for (number, snippet_line) in (first..).zip(snippet.split('\n')) {
+            let (marker, text) = snippet_line.split_at(2);
+            context.push(format!("{:>width$} | {}", number, text, width = width));
+
+            if marker == "> " {
+                // Pad by characters, keeping tabs so the caret lines up
+                let prefix = &text[..self.byte_index(text, column)];
+                let padding: String = prefix
+                    .chars()
+                    .map(|c| if c == '\t' { '\t' } else { ' ' })
+                    .collect();
+                context.push(format!("{:>width$} | {}^", "", padding, width = width));
+            }
+        }
+
+        context.join("\n")
     }
 
     pub fn get_position(&self, offset: usize) -> (usize, usize) {
@@ -85,11 +214,37 @@ impl SourceMap {
             Ok(line) => (line + 1, 1),
             Err(line) => {
                 let line = if line == 0 { 0 } else { line - 1 };
-                let column = offset - self.line_offsets[line] + 1;
+                let start = self.line_offsets[line];
+                let column = match self.lines.get(line) {
+                    Some(text) => self.column_of(text, offset - start),
+                    None => offset - start + 1,
+                };
                 (line + 1, column)
             }
         }
     }
+
+    /// Converts a byte offset within `text` to a 1-based column in `column_unit`.
+    pub fn column_of(&self, text: &str, byte_offset: usize) -> usize {
+        let mut end = byte_offset.min(text.len());
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.column_unit.measure(&text[..end]) + 1
+    }
+
+    /// Converts a 1-based column in `column_unit` back to a byte offset within `text`.
+    pub fn byte_index(&self, text: &str, column: usize) -> usize {
+        let target = column.saturating_sub(1);
+        let mut width = 0;
+        for (i, c) in text.char_indices() {
+            if width >= target {
+                return i;
+            }
+            width += self.column_unit.measure(c.encode_utf8(&mut [0; 4]));
+        }
+        text.len()
+    }
 }
 
 impl IndexedNode {
@@ -115,3 +270,103 @@ impl IndexedNode {
         return format!("#{}", tag);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source_map() -> SourceMap {
+        SourceMap::new(
+            "<html>\n<body>\n<p>One</p>\n<p>Two</p>\n</body>\n</html>",
+            ColumnUnit::Chars,
+        )
+    }
+
+    #[test]
+    fn test_get_line() {
+        let map = source_map();
+        assert_eq!(map.line_count(), 6);
+        assert!(!map.is_empty());
+        assert_eq!(map.get_line(1), Some("<html>"));
+        assert_eq!(map.get_line(6), Some("</html>"));
+        assert_eq!(map.get_line(0), None);
+        assert_eq!(map.get_line(7), None);
+    }
+
+    #[test]
+    fn test_get_snippet() {
+        let map = source_map();
+        assert_eq!(
+            map.get_snippet(3, 1),
+            "  <body>\n> <p>One</p>\n  <p>Two</p>"
+        );
+        assert_eq!(map.get_snippet(3, 0), "> <p>One</p>");
+    }
+
+    #[test]
+    fn test_crlf_positions() {
+        let source = "<html>\r\n<body>\r\n<p>One</p>\r\n</body>\r\n</html>\r\n";
+        let map = SourceMap::new(source, ColumnUnit::Chars);
+        assert_eq!(map.line_ending, LineEnding::CrLf);
+        assert_eq!(map.line_count(), 5);
+        assert_eq!(map.get_line(3), Some("<p>One</p>"));
+        assert_eq!(map.line_offsets, vec![0, 8, 16, 28, 37]);
+
+        let paragraph = source.find("<p>").unwrap();
+        assert_eq!(map.get_position(paragraph), (3, 1));
+        assert_eq!(map.get_position(source.find("One").unwrap()), (3, 4));
+        assert_eq!(map.get_position(source.find("</html>").unwrap()), (5, 1));
+        assert_eq!(
+            map.normalize_line_endings(),
+            "<html>\n<body>\n<p>One</p>\n</body>\n</html>\n"
+        );
+    }
+
+    #[test]
+    fn test_mixed_line_endings() {
+        let source = "<p>One</p>\r\n<p>Two</p>\n<p>Three</p>";
+        let map = SourceMap::new(source, ColumnUnit::Chars);
+        assert_eq!(map.line_ending, LineEnding::Mixed);
+        assert_eq!(map.get_position(source.find("Three").unwrap()), (3, 4));
+        assert_eq!(
+            map.normalize_line_endings(),
+            "<p>One</p>\n<p>Two</p>\n<p>Three</p>"
+        );
+        assert_eq!(source_map().line_ending, LineEnding::Lf);
+    }
+
+    #[test]
+    fn test_get_snippet_clamps_to_document() {
+        let map = source_map();
+        assert_eq!(map.get_snippet(1, 2), "> <html>\n  <body>\n  <p>One</p>");
+        assert_eq!(map.get_snippet(6, 2), "  <p>Two</p>\n  </body>\n> </html>");
+        assert_eq!(map.get_snippet(2, usize::MAX).lines().count(), 6);
+        assert_eq!(map.get_snippet(0, 2), "");
+        assert_eq!(map.get_snippet(7, 2), "");
+    }
+
+    #[test]
+    fn test_context_clamps_to_document() {
+        let map = source_map();
+        assert_eq!(
+            map.context(1, 2),
+            "1 | <html>\n  |  ^\n2 | <body>\n3 | <p>One</p>\n4 | <p>Two</p>"
+        );
+        assert_eq!(
+            map.context(6, 1),
+            "3 | <p>One</p>\n4 | <p>Two</p>\n5 | </body>\n6 | </html>\n  | ^"
+        );
+        assert_eq!(map.context(0, 1), "");
+        assert_eq!(map.context(7, 1), "");
+    }
+
+    #[test]
+    fn test_empty_source() {
+        let map = SourceMap::new("", ColumnUnit::Chars);
+        assert!(map.is_empty());
+        assert_eq!(map.line_count(), 0);
+        assert_eq!(map.get_snippet(1, 3), "");
+        assert_eq!(map.get_line(1), None);
+        assert_eq!(map.context(1, 1), "");
+    }
+}
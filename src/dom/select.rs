@@ -5,6 +5,8 @@ use std::collections::HashSet;
 use std::rc::Rc;
 use string_interner::{DefaultSymbol, StringInterner};
 
+use super::interner::FrozenStringInterner;
+
 // Move selector-related structs
 #[derive(Clone, Debug, PartialEq)]
 pub enum Combinator {
@@ -24,8 +26,12 @@ pub enum PseudoClass {
     LastOfType,
     OnlyChild,
     OnlyOfType,
+    NthOfType(i32, i32),
+    Root,
     Empty,
-    Not(Box<SelectorPart>),
+    Not(Vec<SelectorPart>),
+    Is(Vec<SelectorPart>), // Also `:matches()` and `:where()`
+    Unsupported,           // Unknown or malformed, so it matches nothing
 }
 
 // Expand attribute selectors
@@ -68,9 +74,17 @@ pub struct Selector {
     pub(crate) alternatives: Vec<Vec<SelectorPart>>, // Each inner Vec represents a sequence
 }
 
+/// How selectors are parsed by `DOMIndex::query` and friends.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SelectorOptions {
+    pub case_insensitive_elements: bool, // Match `IMG` against `img` elements
+}
+
 pub struct SelectorEngine {
     selector_cache: RwLock<HashMap<String, Selector>>,
     interner: RwLock<StringInterner>,
+    options: SelectorOptions,
+    warnings: RwLock<Vec<String>>,
 }
 
 impl SelectorEngine {
@@ -78,6 +92,45 @@ impl SelectorEngine {
         Self {
             selector_cache: RwLock::new(HashMap::with_capacity(64)),
             interner: RwLock::new(interner),
+            options: SelectorOptions::default(),
+            warnings: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Replaces the parsing options, dropping selectors parsed under the old ones.
+    pub fn set_options(&mut self, options: SelectorOptions) {
+        self.options = options;
+        self.selector_cache.get_mut().clear();
+    }
+
+    /// Problems noticed while parsing selectors that don't stop them from running, such as
+    /// uppercase element names or unsupported pseudo-classes that can never match.
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.read().clone()
+    }
+
+    // html5ever lowercases every HTML element name it parses, so an element token with
+    // uppercase letters only matches when it's lowercased here
+    fn element_symbol(&self, token: &str, interner: &FrozenStringInterner) -> DefaultSymbol {
+        if !token.chars().any(|c| c.is_ascii_uppercase()) {
+            return interner.get_or_intern(token);
+        }
+        if self.options.case_insensitive_elements {
+            return interner.get_or_intern(&token.to_ascii_lowercase());
+        }
+
+        self.warn(format!(
+            "Element selector `{}` contains uppercase letters and won't match parsed HTML elements; use `{}` or enable case_insensitive_elements",
+            token,
+            token.to_ascii_lowercase()
+        ));
+        interner.get_or_intern(token)
+    }
+
+    fn warn(&self, warning: String) {
+        let mut warnings = self.warnings.write();
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
         }
     }
 
@@ -106,28 +159,103 @@ impl SelectorEngine {
         }
     }
 
+    // Pseudo-classes that can't be evaluated against a static document, such as `:hover`, or
+    // that are malformed are kept as `Unsupported` so the compound selector matches nothing
     fn parse_pseudo_class(
         &self,
         name: &str,
-        _chars: &mut std::iter::Peekable<std::str::Chars>,
-    ) -> Option<PseudoClass> {
-        match name {
-            "first-child" => Some(PseudoClass::FirstChild),
-            "last-child" => Some(PseudoClass::LastChild),
-            "nth-child" => {
-                // Parse an+b pattern
-                // Implementation needed
-                Some(PseudoClass::NthChild(1, 0))
-            }
-            // Add other pseudo-class parsing...
+        chars: &mut std::iter::Peekable<std::str::Chars>,
+        interner: &FrozenStringInterner,
+    ) -> PseudoClass {
+        // Functional pseudo-classes carry their argument in parentheses, which may nest
+        let mut argument = None;
+        if chars.peek() == Some(&'(') {
+            chars.next();
+            let mut text = String::new();
+            let mut depth = 0;
+            let mut closed = false;
+            for c in chars.by_ref() {
+                match c {
+                    '(' => depth += 1,
+                    ')' if depth == 0 => {
+                        closed = true;
+                        break;
+                    }
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                text.push(c);
+            }
+            if !closed {
+                self.warn(format!(
+                    "Pseudo-class `:{}({}` is missing its closing parenthesis and matches nothing",
+                    name, text
+                ));
+                return PseudoClass::Unsupported;
+            }
+            argument = Some(text);
+        }
+
+        let pseudo = match (name, argument.as_deref()) {
+            ("root", None) => Some(PseudoClass::Root),
+            ("first-child", None) => Some(PseudoClass::FirstChild),
+            ("last-child", None) => Some(PseudoClass::LastChild),
+            ("only-child", None) => Some(PseudoClass::OnlyChild),
+            ("first-of-type", None) => Some(PseudoClass::FirstOfType),
+            ("last-of-type", None) => Some(PseudoClass::LastOfType),
+            ("only-of-type", None) => Some(PseudoClass::OnlyOfType),
+            ("empty", None) => Some(PseudoClass::Empty),
+            ("nth-child", Some(arg)) => parse_nth(arg).map(|(a, b)| PseudoClass::NthChild(a, b)),
+            ("nth-last-child", Some(arg)) => {
+                parse_nth(arg).map(|(a, b)| PseudoClass::NthLastChild(a, b))
+            }
+            ("nth-of-type", Some(arg)) => parse_nth(arg).map(|(a, b)| PseudoClass::NthOfType(a, b)),
+            ("is" | "matches" | "where", Some(arg)) => self
+                .parse_selector_argument(arg, interner)
+                .map(PseudoClass::Is),
+            ("not", Some(arg)) => self
+                .parse_selector_argument(arg, interner)
+                .map(PseudoClass::Not),
             _ => None,
+        };
+
+        pseudo.unwrap_or_else(|| {
+            let selector = match &argument {
+                Some(argument) => format!(":{}({})", name, argument),
+                None => format!(":{}", name),
+            };
+            self.warn(format!(
+                "Pseudo-class `{}` is not supported and matches nothing",
+                selector
+            ));
+            PseudoClass::Unsupported
+        })
+    }
+
+    // The selector list inside `:is()` or `:not()`, which must name at least one selector.
+    // Only compound selectors are supported there; combinators go outside, as in `:is(h1, h2) + p`
+    fn parse_selector_argument(
+        &self,
+        argument: &str,
+        interner: &FrozenStringInterner,
+    ) -> Option<Vec<SelectorPart>> {
+        let parts = split_selector_list(argument);
+        if parts.is_empty() {
+            return None;
         }
+        parts
+            .into_iter()
+            .map(|part| match split_complex_selector(part)?.as_slice() {
+                [(compound, None)] => Some(self.parse_compound_selector(compound, interner)),
+                _ => None,
+            })
+            .collect()
     }
 
     fn parse_attribute_selector(
         &self,
         chars: &mut std::iter::Peekable<std::str::Chars>,
-        interner: &RwLock<StringInterner>,
+        interner: &FrozenStringInterner,
     ) -> Option<AttributeSelector> {
         let mut token = String::with_capacity(32);
 
@@ -138,7 +266,7 @@ impl SelectorEngine {
             }
             token.push(chars.next().unwrap());
         }
-        let attr_name = interner.write().get_or_intern(&token.trim());
+        let attr_name = interner.get_or_intern(token.trim());
         token.clear();
 
         // Parse operator and value if present
@@ -148,7 +276,7 @@ impl SelectorEngine {
                 let value = self.parse_attribute_value(chars);
                 Some(AttributeSelector::Equals(
                     attr_name,
-                    interner.write().get_or_intern(&value),
+                    interner.get_or_intern(&value),
                 ))
             }
             Some(c) => match c {
@@ -158,7 +286,7 @@ impl SelectorEngine {
                     }
 
                     let value = self.parse_attribute_value(chars);
-                    let value_symbol = interner.write().get_or_intern(&value);
+                    let value_symbol = interner.get_or_intern(&value);
 
                     match c {
                         '^' => Some(AttributeSelector::StartsWith(attr_name, value_symbol)),
@@ -212,101 +340,123 @@ impl SelectorEngine {
         value
     }
 
-    pub fn parse_selector(&self, selector: &str, interner: &RwLock<StringInterner>) -> Selector {
-        // Handle universal selector "*" explicitly
-        if selector == "*" {
-            return Selector {
-                alternatives: vec![vec![SelectorPart {
-                    element: None,
-                    classes: Vec::new(),
-                    id: None,
-                    attributes: Vec::new(),
-                    pseudo_classes: Vec::new(),
-                    combinator: None,
-                    specificity: (0, 0, 0),
-                }]],
-            };
-        }
-
-        let mut alternatives = Vec::new();
-        let mut current_sequence = Vec::new();
-
-        // Split by commas and handle each part
-        for part in selector.split(',') {
-            let part = part.trim(); // Handle potential spaces after commas
-            if part.is_empty() {
-                continue;
-            }
-
-            let mut element = None;
-            let mut classes = Vec::with_capacity(4);
-            let mut id = None;
-            let mut attributes = Vec::new();
-            let mut token = String::with_capacity(32);
-            let mut chars = part.chars().peekable();
+    // A single compound selector such as `p.intro[lang]:first-child`
+    fn parse_compound_selector(&self, part: &str, interner: &FrozenStringInterner) -> SelectorPart {
+        let mut element = None;
+        let mut classes = Vec::with_capacity(4);
+        let mut id = None;
+        let mut attributes = Vec::new();
+        let mut pseudo_classes = Vec::new();
+        let mut token = String::with_capacity(32);
+        // The universal selector `*` places no constraint on the element
+        let mut chars = part.strip_prefix('*').unwrap_or(part).chars().peekable();
 
-            while let Some(c) = chars.next() {
-                match c {
-                    '[' => {
-                        if !token.is_empty() {
-                            element = Some(interner.write().get_or_intern(&token));
-                            token.clear();
-                        }
+        while let Some(c) = chars.next() {
+            match c {
+                '[' => {
+                    if !token.is_empty() {
+                        element = Some(self.element_symbol(&token, interner));
+                        token.clear();
+                    }
 
-                        if let Some(attr_selector) =
-                            self.parse_attribute_selector(&mut chars, interner)
-                        {
-                            attributes.push(attr_selector);
-                        }
+                    if let Some(attr_selector) = self.parse_attribute_selector(&mut chars, interner)
+                    {
+                        attributes.push(attr_selector);
                     }
-                    '#' => {
-                        if !token.is_empty() {
-                            element = Some(interner.write().get_or_intern(&token));
-                            token.clear();
-                        }
-                        while let Some(&c) = chars.peek() {
-                            if c == '.' || c == '#' {
-                                break;
-                            }
-                            token.push(chars.next().unwrap());
-                        }
-                        id = Some(interner.write().get_or_intern(&token));
+                }
+                '#' => {
+                    if !token.is_empty() {
+                        element = Some(self.element_symbol(&token, interner));
                         token.clear();
                     }
-                    '.' => {
-                        if !token.is_empty() {
-                            element = Some(interner.write().get_or_intern(&token));
-                            token.clear();
+                    while let Some(&c) = chars.peek() {
+                        if c == '.' || c == '#' || c == ':' {
+                            break;
                         }
-                        while let Some(&c) = chars.peek() {
-                            if c == '.' || c == '#' {
-                                break;
-                            }
-                            token.push(chars.next().unwrap());
+                        token.push(chars.next().unwrap());
+                    }
+                    id = Some(interner.get_or_intern(&token));
+                    token.clear();
+                }
+                '.' => {
+                    if !token.is_empty() {
+                        element = Some(self.element_symbol(&token, interner));
+                        token.clear();
+                    }
+                    while let Some(&c) = chars.peek() {
+                        if c == '.' || c == '#' || c == ':' {
+                            break;
                         }
-                        classes.push(interner.write().get_or_intern(&token));
+                        token.push(chars.next().unwrap());
+                    }
+                    classes.push(interner.get_or_intern(&token));
+                    token.clear();
+                }
+                ':' => {
+                    if !token.is_empty() {
+                        element = Some(self.element_symbol(&token, interner));
                         token.clear();
                     }
-                    _ => token.push(c),
+                    while let Some(&c) = chars.peek() {
+                        if !(c.is_ascii_alphanumeric() || c == '-') {
+                            break;
+                        }
+                        token.push(chars.next().unwrap());
+                    }
+                    pseudo_classes.push(self.parse_pseudo_class(
+                        &token.to_ascii_lowercase(),
+                        &mut chars,
+                        interner,
+                    ));
+                    token.clear();
                 }
+                _ => token.push(c),
             }
+        }
 
-            if !token.is_empty() {
-                element = Some(interner.write().get_or_intern(&token));
-            }
+        if !token.is_empty() {
+            element = Some(self.element_symbol(&token, interner));
+        }
 
-            current_sequence.push(SelectorPart {
-                element,
-                classes,
-                id,
-                attributes,
-                pseudo_classes: Vec::new(),
-                combinator: None,
-                specificity: (0, 0, 0),
-            });
+        SelectorPart {
+            element,
+            classes,
+            id,
+            attributes,
+            pseudo_classes,
+            combinator: None,
+            specificity: (0, 0, 0),
         }
+    }
 
-        alternatives.push(current_sequence);
+    /// Parses a selector list. Element names are matched exactly, and since html5ever
+    /// lowercases the elements it parses, uppercase names only match with
+    /// `SelectorOptions::case_insensitive_elements`; otherwise they are recorded in `warnings`.
+    pub fn parse_selector(&self, selector: &str, interner: &FrozenStringInterner) -> Selector {
+        // Each comma-separated alternative is a sequence of compound selectors joined by
+        // combinators, e.g. `body > main p`
+        let alternatives = split_selector_list(selector)
+            .into_iter()
+            .map(|alternative| match split_complex_selector(alternative) {
+                Some(compounds) => compounds
+                    .into_iter()
+                    .map(|(compound, combinator)| SelectorPart {
+                        combinator,
+                        ..self.parse_compound_selector(compound, interner)
+                    })
+                    .collect(),
+                None => {
+                    self.warn(format!(
+                        "Selector `{}` has a combinator without a selector on both sides and matches nothing",
+                        alternative
+                    ));
+                    vec![SelectorPart {
+                        pseudo_classes: vec![PseudoClass::Unsupported],
+                        ..self.parse_compound_selector("", interner)
+                    }]
+                }
+            })
+            .collect();
 
         Selector { alternatives }
     }
@@ -314,7 +464,7 @@ impl SelectorEngine {
     pub fn get_or_parse_selector(
         &self,
         selector: &str,
-        interner: &RwLock<StringInterner>,
+        interner: &FrozenStringInterner,
     ) -> Selector {
         // Fast path: check cache first with read lock
         let cache = self.selector_cache.read();
@@ -398,7 +548,9 @@ impl SelectorEngine {
                 )
             }
             PseudoClass::Empty => element.children.is_empty() && element.text.is_empty(),
-            PseudoClass::Not(selector_part) => !self.matches_part(element, &*selector_part),
+            PseudoClass::Not(parts) => !parts.iter().any(|part| self.matches_part(element, part)),
+            // Only `DOMIndex` evaluates the newer pseudo-classes
+            _ => false,
         }
     }
 
@@ -648,7 +800,9 @@ impl SelectorEngine {
                     let interner = self.interner.read();
                     let attr_str = interner.resolve(attr).unwrap();
                     let value_str = interner.resolve(*value).unwrap();
-                    attr_str.contains(value_str)
+                    attr_str
+                        .split_ascii_whitespace()
+                        .any(|part| part == value_str)
                 })
             }
             AttributeSelector::DashMatch(name, value) => {
@@ -656,13 +810,137 @@ impl SelectorEngine {
                     let interner = self.interner.read();
                     let attr_str = interner.resolve(attr).unwrap();
                     let value_str = interner.resolve(*value).unwrap();
-                    attr_str == value_str || attr_str.starts_with(&format!("{:?}-", value_str))
+                    attr_str == value_str || attr_str.starts_with(&format!("{}-", value_str))
                 })
             }
         }
     }
 }
 
+/// Splits a selector list on the commas that are not inside parentheses.
+fn split_selector_list(selector: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in selector.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&selector[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&selector[start..]);
+
+    parts
+        .into_iter()
+        .map(str::trim) // Handle potential spaces after commas
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+// Splits a complex selector such as `main > p + ul li` into its compound selectors, each paired
+// with the combinator that joins it to the next. None when a combinator is missing a side.
+fn split_complex_selector(selector: &str) -> Option<Vec<(&str, Option<Combinator>)>> {
+    let mut compounds = Vec::new();
+    let mut pending: Option<Combinator> = None;
+    let mut start = None;
+    let mut depth = 0;
+    let mut quote = None;
+
+    for (i, c) in selector.char_indices() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        let combinator = match c {
+            '"' | '\'' => {
+                quote = Some(c);
+                None
+            }
+            '(' | '[' => {
+                depth += 1;
+                None
+            }
+            ')' | ']' => {
+                depth -= 1;
+                None
+            }
+            '>' if depth == 0 => Some(Combinator::Child),
+            '+' if depth == 0 => Some(Combinator::Adjacent),
+            '~' if depth == 0 => Some(Combinator::GeneralSibling),
+            c if c.is_whitespace() && depth == 0 => Some(Combinator::Descendant),
+            _ => None,
+        };
+
+        match combinator {
+            Some(combinator) => {
+                if let Some(begin) = start.take() {
+                    compounds.push((&selector[begin..i], None));
+                }
+                // Whitespace around an explicit combinator doesn't make it a descendant one
+                pending = match (pending, combinator) {
+                    (Some(Combinator::Descendant) | None, combinator) => Some(combinator),
+                    (Some(explicit), Combinator::Descendant) => Some(explicit),
+                    (Some(_), _) => return None,
+                };
+            }
+            None if start.is_none() => {
+                match (compounds.last_mut(), pending.take()) {
+                    (Some((_, joined)), Some(combinator)) => *joined = Some(combinator),
+                    (None, Some(Combinator::Descendant)) | (None, None) => {}
+                    _ => return None,
+                }
+                start = Some(i);
+            }
+            None => {}
+        }
+    }
+
+    match start {
+        Some(begin) => compounds.push((&selector[begin..], None)),
+        None if matches!(pending, Some(Combinator::Descendant) | None) => {}
+        None => return None,
+    }
+    (!compounds.is_empty()).then_some(compounds)
+}
+
+/// Parses an `an+b` argument such as `odd`, `3`, `2n+1` or `-n+3` into `(a, b)`.
+pub(crate) fn parse_nth(argument: &str) -> Option<(i32, i32)> {
+    let argument: String = argument
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    match argument.as_str() {
+        "odd" => return Some((2, 1)),
+        "even" => return Some((2, 0)),
+        _ => {}
+    }
+
+    match argument.split_once('n') {
+        Some((a, b)) => {
+            let a = match a {
+                "" | "+" => 1,
+                "-" => -1,
+                a => a.parse().ok()?,
+            };
+            let b = if b.is_empty() {
+                0
+            } else {
+                b.strip_prefix('+').unwrap_or(b).parse().ok()?
+            };
+            Some((a, b))
+        }
+        None => argument.parse().ok().map(|b| (0, b)),
+    }
+}
+
 // First, let's add a helper trait for node traversal
 pub(crate) trait NodeTraversal {
     fn parent(&self) -> Option<Rc<RefCell<NodeData>>>;
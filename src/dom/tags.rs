@@ -0,0 +1,412 @@
+use super::utils::VOID_ELEMENTS;
+use std::ops::Range;
+
+// Elements whose content is text up to their closing tag rather than markup
+const RAW_TEXT_ELEMENTS: &[&str] = &[
+    "script", "style", "textarea", "title", "xmp", "iframe", "noembed", "noframes",
+];
+
+// Elements the parser inserts without a start tag, e.g. the `<tbody>` around a table's rows
+const IMPLIED_ELEMENTS: &[&str] = &["html", "head", "body", "tbody", "tr", "colgroup"];
+
+// Start tags that end an open element of the given kinds, when one is open inside the nearest
+// boundary element: a `<td>` ends the previous cell of its row, but not a cell of an outer table
+const IMPLIED_END_TAGS: &[(&[&str], &[&str], &[&str])] = &[
+    (
+        &["td", "th"],
+        &["td", "th", "caption", "colgroup"],
+        &["tr", "table"],
+    ),
+    (
+        &["tr"],
+        &["tr", "caption", "colgroup"],
+        &["thead", "tbody", "tfoot", "table"],
+    ),
+    (
+        &["thead", "tbody", "tfoot"],
+        &["thead", "tbody", "tfoot", "caption", "colgroup"],
+        &["table"],
+    ),
+    (&["li"], &["li"], &["ul", "ol", "menu"]),
+    (&["dt", "dd"], &["dt", "dd"], &["dl"]),
+];
+
+// Raw text elements whose content is still escapable text, i.e. decodes character references
+const ESCAPABLE_RAW_TEXT_ELEMENTS: &[&str] = &["title"];
+
+/// A start or end tag as written in the source.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SourceTag {
+    pub name: String, // Lowercased
+    pub span: Range<usize>,
+    pub closing: bool,
+}
+
+/// The tags of a document in source order, along with the ranges of text between them.
+/// Comments, doctypes and the content of raw text elements such as `<script>` are neither.
+#[derive(Debug, Default)]
+pub(crate) struct SourceTags {
+    pub tags: Vec<SourceTag>,
+    pub text: Vec<Range<usize>>,
+}
+
+impl SourceTags {
+    /// Tokenizes `source` the way a browser splits markup from text, without building a tree.
+    pub fn scan(source: &str) -> Self {
+        let bytes = source.as_bytes();
+        let mut scanned = Self::default();
+        let mut text_start = 0;
+        let mut pos = 0;
+
+        while let Some(found) = source[pos..].find('<') {
+            let at = pos + found;
+            let rest = &bytes[at + 1..];
+            let (end, tag) = if rest.starts_with(b"!--") {
+                let end = source[at + 4..]
+                    .find("-->")
+                    .map_or(bytes.len(), |end| at + 4 + end + 3);
+                (end, None)
+            } else if rest.first().is_some_and(|&b| b == b'!' || b == b'?') {
+                let end = source[at..]
+                    .find('>')
+                    .map_or(bytes.len(), |end| at + end + 1);
+                (end, None)
+            } else if let Some(tag) = Self::tag_at(source, at) {
+                (tag.span.end, Some(tag))
+            } else {
+                pos = at + 1;
+                continue;
+            };
+
+            scanned.push_text(text_start..at);
+            pos = end;
+            text_start = end;
+
+            let Some(tag) = tag else {
+                continue;
+            };
+            let raw_text = !tag.closing && RAW_TEXT_ELEMENTS.contains(&tag.name.as_str());
+            let escapable = ESCAPABLE_RAW_TEXT_ELEMENTS.contains(&tag.name.as_str());
+            let name = tag.name.clone();
+            scanned.tags.push(tag);
+
+            if raw_text {
+                // Everything up to the matching end tag is content, however tag-like it looks
+                let close = format!("</{}", name);
+                let content_end = source[pos..]
+                    .to_ascii_lowercase()
+                    .find(&close)
+                    .map_or(bytes.len(), |end| pos + end);
+                if escapable {
+                    scanned.push_text(pos..content_end);
+                }
+                pos = content_end;
+                text_start = content_end;
+            }
+        }
+
+        scanned.push_text(text_start..bytes.len());
+        scanned
+    }
+
+    /// Pairs each start tag with the element it was written inside, before the parser has had a
+    /// chance to repair the markup: in `<div><td>`, the `<td>` is inside the `<div>` even though
+    /// a browser drops it.
+    ///
+    /// End tags close the latest open element of their name, and optional end tags are implied
+    /// where a new cell, row, table section or list item starts.
+    pub fn nesting(&self, source: &str) -> Vec<NestedTag<'_>> {
+        let mut open: Vec<&str> = Vec::new();
+        let mut nested = Vec::new();
+
+        for tag in &self.tags {
+            let name = tag.name.as_str();
+            if tag.closing {
+                if let Some(at) = open.iter().rposition(|&open| open == name) {
+                    open.truncate(at);
+                }
+                continue;
+            }
+
+            if let Some((_, ended, boundaries)) = IMPLIED_END_TAGS
+                .iter()
+                .find(|(starts, _, _)| starts.contains(&name))
+            {
+                let ended = open
+                    .iter()
+                    .rposition(|open| ended.contains(open) || boundaries.contains(open))
+                    .filter(|&at| ended.contains(&open[at]));
+                if let Some(at) = ended {
+                    open.truncate(at);
+                }
+            }
+
+            let foreign = open.iter().any(|&open| open == "svg" || open == "math");
+            nested.push(NestedTag {
+                tag,
+                parent: open.last().copied(),
+                foreign,
+            });
+
+            // Only foreign elements may close themselves with `/>`
+            let self_closing = foreign && source[tag.span.clone()].ends_with("/>");
+            if !VOID_ELEMENTS.contains(&name) && !self_closing {
+                open.push(name);
+            }
+        }
+
+        nested
+    }
+
+    fn push_text(&mut self, range: Range<usize>) {
+        if !range.is_empty() {
+            self.text.push(range);
+        }
+    }
+
+    // The start or end tag opening at `at`, or `None` when the `<` there is literal text
+    fn tag_at(source: &str, at: usize) -> Option<SourceTag> {
+        let bytes = source.as_bytes();
+        let closing = bytes.get(at + 1) == Some(&b'/');
+        let name_start = at + 1 + usize::from(closing);
+        if !bytes.get(name_start).is_some_and(u8::is_ascii_alphabetic) {
+            return None;
+        }
+        let name_end = bytes[name_start..]
+            .iter()
+            .position(|&b| b.is_ascii_whitespace() || b == b'/' || b == b'>')
+            .map_or(bytes.len(), |end| name_start + end);
+
+        // Quoted attribute values may contain `>`
+        let mut quote = None;
+        let mut end = bytes.len();
+        for (i, &b) in bytes.iter().enumerate().skip(name_end) {
+            match quote {
+                Some(q) if b == q => quote = None,
+                Some(_) => {}
+                None if b == b'"' || b == b'\'' => quote = Some(b),
+                None if b == b'>' => {
+                    end = i + 1;
+                    break;
+                }
+                None => {}
+            }
+        }
+
+        Some(SourceTag {
+            name: source[name_start..name_end].to_ascii_lowercase(),
+            span: at..end,
+            closing,
+        })
+    }
+}
+
+/// A start tag and the start tag of the element it was written inside.
+#[derive(Debug)]
+pub(crate) struct NestedTag<'a> {
+    pub tag: &'a SourceTag,
+    pub parent: Option<&'a str>, // `None` at the top level, including an implied `<body>`
+    pub foreign: bool,           // Inside `<svg>` or `<math>`
+}
+
+/// An attribute as written in a start tag, as byte ranges into the tag's source.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SourceAttribute {
+    pub name: Range<usize>,
+    pub value: Option<Range<usize>>, // Without its quotes; `None` for a bare `checked`
+    pub quote: Option<char>,
+}
+
+/// The name and attributes of `tag`, a start tag as written such as `<img src="a.png" alt>`.
+pub(crate) fn parse_start_tag(tag: &str) -> (Range<usize>, Vec<SourceAttribute>) {
+    let bytes = tag.as_bytes();
+    let is_delimiter = |b: u8| b.is_ascii_whitespace() || b == b'/' || b == b'>';
+
+    let name_start = usize::from(bytes.first() == Some(&b'<'));
+    let name = name_start..skip_while(bytes, name_start, |b| !is_delimiter(b));
+    let mut attributes = Vec::new();
+    let mut pos = name.end;
+    loop {
+        pos = skip_while(bytes, pos, |b| b.is_ascii_whitespace() || b == b'/');
+        if pos >= bytes.len() || bytes[pos] == b'>' {
+            break;
+        }
+        // Like browsers, read a stray `=` as the start of a name
+        let name = pos..skip_while(bytes, pos + 1, |b| !is_delimiter(b) && b != b'=');
+        pos = skip_while(bytes, name.end, |b| b.is_ascii_whitespace());
+
+        let (mut value, mut quote) = (None, None);
+        if bytes.get(pos) == Some(&b'=') {
+            pos = skip_while(bytes, pos + 1, |b| b.is_ascii_whitespace());
+            match bytes.get(pos) {
+                Some(&q @ (b'"' | b'\'')) => {
+                    let end = skip_while(bytes, pos + 1, |b| b != q);
+                    value = Some(pos + 1..end);
+                    quote = Some(char::from(q));
+                    pos = end + 1;
+                }
+                _ => {
+                    let end = skip_while(bytes, pos, |b| !b.is_ascii_whitespace() && b != b'>');
+                    value = Some(pos..end);
+                    pos = end;
+                }
+            }
+        }
+        attributes.push(SourceAttribute { name, value, quote });
+    }
+
+    (name, attributes)
+}
+
+fn skip_while(bytes: &[u8], mut pos: usize, skipped: impl Fn(u8) -> bool) -> usize {
+    while bytes.get(pos).is_some_and(|&b| skipped(b)) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Pairs parsed elements, visited in document order, with the start tags they were parsed from.
+pub(crate) struct TagLocator {
+    tags: Vec<SourceTag>,
+    next: usize,
+}
+
+impl TagLocator {
+    pub fn new(source: &str) -> Self {
+        Self {
+            tags: SourceTags::scan(source).tags,
+            next: 0,
+        }
+    }
+
+    /// The span of the start tag an element named `name` was parsed from, or `None` for
+    /// elements the parser implied or moved.
+    ///
+    /// Tags the parser dropped, such as a `<td>` outside any table, are skipped over, but an
+    /// element that can be implied only takes the next tag when that tag names it.
+    pub fn locate(&mut self, name: &str) -> Option<Range<usize>> {
+        let name = name.to_ascii_lowercase();
+        let tags = &self.tags;
+        let mut start_tags = (self.next..tags.len()).filter(|&i| !tags[i].closing);
+        let found = if IMPLIED_ELEMENTS.contains(&name.as_str()) {
+            start_tags.next().filter(|&i| tags[i].name == name)
+        } else {
+            start_tags.find(|&i| tags[i].name == name)
+        }?;
+
+        self.next = found + 1;
+        if name == "template" {
+            // Template contents aren't children in the parsed tree
+            self.skip_template_contents();
+        }
+        Some(self.tags[found].span.clone())
+    }
+
+    fn skip_template_contents(&mut self) {
+        let mut depth = 1;
+        while let Some(tag) = self.tags.get(self.next) {
+            self.next += 1;
+            if tag.name == "template" {
+                depth = if tag.closing { depth - 1 } else { depth + 1 };
+                if depth == 0 {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_skips_comments_and_raw_text() {
+        let source = "<p class=\"a>b\">One &amp; two</p><!-- <b> --><script>if (a<b) {}</script>\
+            <title>T&eacute;</title>3 < 4";
+        let scanned = SourceTags::scan(source);
+
+        let names: Vec<(&str, bool)> = scanned
+            .tags
+            .iter()
+            .map(|tag| (tag.name.as_str(), tag.closing))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("p", false),
+                ("p", true),
+                ("script", false),
+                ("script", true),
+                ("title", false),
+                ("title", true),
+            ]
+        );
+        assert_eq!(&source[scanned.tags[0].span.clone()], "<p class=\"a>b\">");
+
+        let text: Vec<&str> = scanned
+            .text
+            .iter()
+            .map(|range| &source[range.clone()])
+            .collect();
+        assert_eq!(text, vec!["One &amp; two", "T&eacute;", "3 < 4"]);
+    }
+
+    #[test]
+    fn test_nesting_follows_the_source() {
+        let source = "<div><td>Dropped</td></div><table><tr><td>A<p>B<td>C<tr><th>D\
+            <table><td>Inner</table></table><ul><li>One<li>Two</ul><svg><rect/><td/></svg>";
+        let scanned = SourceTags::scan(source);
+        let nested: Vec<(&str, Option<&str>, bool)> = scanned
+            .nesting(source)
+            .iter()
+            .map(|nested| (nested.tag.name.as_str(), nested.parent, nested.foreign))
+            .collect();
+        assert_eq!(
+            nested,
+            vec![
+                ("div", None, false),
+                ("td", Some("div"), false),
+                ("table", None, false),
+                ("tr", Some("table"), false),
+                ("td", Some("tr"), false),
+                ("p", Some("td"), false),
+                ("td", Some("tr"), false),
+                ("tr", Some("table"), false),
+                ("th", Some("tr"), false),
+                ("table", Some("th"), false),
+                ("td", Some("table"), false),
+                ("ul", None, false),
+                ("li", Some("ul"), false),
+                ("li", Some("ul"), false),
+                ("svg", None, false),
+                ("rect", Some("svg"), true),
+                ("td", Some("svg"), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_start_tag() {
+        let tag = "<INPUT type=checkbox checked Value = 'a \"b\"' data-x=\"it's\"/>";
+        let (name, attributes) = parse_start_tag(tag);
+        assert_eq!(&tag[name], "INPUT");
+
+        let parsed: Vec<(&str, Option<&str>, Option<char>)> = attributes
+            .iter()
+            .map(|attr| {
+                let value = attr.value.clone().map(|value| &tag[value]);
+                (&tag[attr.name.clone()], value, attr.quote)
+            })
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![
+                ("type", Some("checkbox"), None),
+                ("checked", None, None),
+                ("Value", Some("a \"b\""), Some('\'')),
+                ("data-x", Some("it's"), Some('"')),
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use super::tags::parse_start_tag;
 use super::*;
 use markup5ever_rcdom::{Handle, NodeData};
 
@@ -30,21 +31,17 @@ impl _NodeExt for Handle {
 
     fn get_text_content(&self) -> String {
         let mut content = String::new();
-        extract_text(self, &mut content);
-        content
-    }
-}
-
-pub(crate) fn extract_text(handle: &Handle, output: &mut String) {
-    if let NodeData::Text { ref contents } = handle.data {
-        output.push_str(&contents.borrow());
-    }
+        if let NodeData::Text { ref contents } = self.data {
+            content.push_str(&contents.borrow());
+        }
 
-    // Only get direct text nodes, skip recursing into elements
-    for child in handle.children.borrow().iter() {
-        if let NodeData::Text { .. } = child.data {
-            extract_text(child, output);
+        // Only get direct text nodes, skip recursing into elements
+        for child in self.children.borrow().iter() {
+            if let NodeData::Text { ref contents } = child.data {
+                content.push_str(&contents.borrow());
+            }
         }
+        content
     }
 }
 
@@ -70,3 +67,298 @@ fn collect_node_text(node_idx: usize, index: &DOMIndex, output: &mut String) {
         collect_node_text(child_idx, index, output);
     }
 }
+
+/// Walks the parent chain of `node_idx` looking for any node in the sorted `candidates` list.
+pub(crate) fn has_ancestor_in(node_idx: usize, candidates: &[usize], index: &DOMIndex) -> bool {
+    let mut current = index.get_node(node_idx).and_then(|node| node.parent);
+    while let Some(parent_idx) = current {
+        if candidates.binary_search(&parent_idx).is_ok() {
+            return true;
+        }
+        current = index.get_node(parent_idx).and_then(|node| node.parent);
+    }
+    false
+}
+
+/// Splits an IDREF list (`for`, `aria-labelledby`, `headers`, ...) and returns the
+/// tokens that no element carries as its `reference_attribute`.
+pub(crate) fn missing_references(
+    value: &str,
+    reference_attribute: &str,
+    index: &DOMIndex,
+) -> Vec<String> {
+    value
+        .split_ascii_whitespace()
+        .filter(|token| {
+            if reference_attribute == "id" {
+                return index.get_element_by_id(token).is_none();
+            }
+            let selector = format!("[{}=\"{}\"]", reference_attribute, token);
+            index.query_first(&selector).is_none()
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// CSS-style path from the outermost element down to `node_idx`, e.g.
+/// `html > body > main > article:nth-child(2) > p`. Elements with an id are written as
+/// `tag#id`; otherwise `:nth-child(n)` is added when a sibling shares the tag name.
+pub(crate) fn build_element_path(node_idx: usize, index: &DOMIndex) -> String {
+    let mut segments = Vec::new();
+    let mut current = Some(node_idx);
+    while let Some(idx) = current {
+        let Some(node) = index.get_node(idx) else {
+            break;
+        };
+        if !is_element(node) {
+            break;
+        }
+        let tag_name = index.resolve_symbol(node.tag_name).unwrap_or_default();
+        let id = node
+            .attributes
+            .iter()
+            .find(|attr| index.resolve_symbol(attr.name).unwrap_or_default() == "id")
+            .map(|attr| index.resolve_symbol(attr.value).unwrap_or_default())
+            .filter(|id| !id.is_empty());
+
+        let segment = match id {
+            Some(id) => format!("{}#{}", tag_name, id),
+            None => {
+                let siblings: Vec<usize> = node
+                    .parent
+                    .and_then(|parent_idx| index.get_node(parent_idx))
+                    .map(|parent| {
+                        parent
+                            .children
+                            .iter()
+                            .copied()
+                            .filter(|&child_idx| index.get_node(child_idx).is_some_and(is_element))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let shares_tag = siblings.iter().any(|&sibling_idx| {
+                    sibling_idx != idx
+                        && index.get_node(sibling_idx).is_some_and(|sibling| {
+                            index.resolve_symbol(sibling.tag_name).unwrap_or_default() == tag_name
+                        })
+                });
+                match siblings.iter().position(|&sibling_idx| sibling_idx == idx) {
+                    Some(position) if shares_tag => {
+                        format!("{}:nth-child({})", tag_name, position + 1)
+                    }
+                    _ => tag_name,
+                }
+            }
+        };
+        segments.push(segment);
+        current = node.parent;
+    }
+    segments.reverse();
+    segments.join(" > ")
+}
+
+/// The value of `node`'s attribute `name`. The parser lowercases HTML attribute names, so
+/// `name` should be lowercase.
+pub(crate) fn get_attribute(node: &IndexedNode, name: &str, index: &DOMIndex) -> Option<String> {
+    node.attributes
+        .iter()
+        .find(|attr| index.resolve_symbol(attr.name).unwrap_or_default() == name)
+        .and_then(|attr| index.resolve_symbol(attr.value))
+}
+
+/// Nearest ancestor of `node_idx` whose tag is `tag_name`.
+pub(crate) fn closest_ancestor_of_type(
+    node_idx: usize,
+    tag_name: &str,
+    index: &DOMIndex,
+) -> Option<usize> {
+    let mut current = index.get_node(node_idx).and_then(|node| node.parent);
+    while let Some(parent_idx) = current {
+        let parent = index.get_node(parent_idx)?;
+        if is_element(parent)
+            && index.resolve_symbol(parent.tag_name).unwrap_or_default() == tag_name
+        {
+            return Some(parent_idx);
+        }
+        current = parent.parent;
+    }
+    None
+}
+
+/// Quote character used around `attr_name`'s value in `tag`, a start tag as written. Unquoted
+/// values count as double-quoted. Returns `None` when the tag doesn't give the attribute a
+/// value, as for a bare `checked`.
+pub(crate) fn detect_quote_char(tag: &str, attr_name: &str) -> Option<QuotesType> {
+    let (_, attributes) = parse_start_tag(tag);
+    // Like the parser, the first of duplicated attributes wins. Foreign attributes such as
+    // `xlink:href` are named without their prefix.
+    let attr = attributes.iter().find(|attr| {
+        let name = &tag[attr.name.clone()];
+        name.eq_ignore_ascii_case(attr_name)
+            || name
+                .rsplit_once(':')
+                .is_some_and(|(_, local)| local.eq_ignore_ascii_case(attr_name))
+    })?;
+    attr.value.as_ref()?;
+    Some(match attr.quote {
+        Some('\'') => QuotesType::Single,
+        _ => QuotesType::Double,
+    })
+}
+
+/// The lowercased scheme of an absolute URL, or `None` for relative URLs. Like browsers,
+/// this ignores leading whitespace and control characters and tabs or newlines inside.
+pub(crate) fn url_scheme(url: &str) -> Option<String> {
+    let url: String = url
+        .trim_start_matches(|c: char| c.is_ascii_whitespace() || c.is_ascii_control())
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect();
+    let (scheme, _) = url.split_once(':')?;
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then(|| scheme.to_ascii_lowercase())
+}
+
+/// How custom elements are categorised, from the `custom_*_elements` linter options.
+/// Tags listed here extend the built-in HTML lists.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ElementContext<'a> {
+    pub void_elements: &'a [String],
+    pub block_elements: &'a [String],
+    pub inline_elements: &'a [String],
+}
+
+/// Number of ancestors between `node_idx` and the document root; `<html>` has depth 1.
+///
+/// The parser nests whatever follows a custom void element such as `<my-icon />` inside it,
+/// so those elements don't count towards the depth of what they appear to contain.
+pub(crate) fn get_node_depth(node_idx: usize, index: &DOMIndex, context: ElementContext) -> usize {
+    let mut depth = 0;
+    let mut current = index.get_node(node_idx).and_then(|node| node.parent);
+    while let Some(parent) = current.and_then(|parent_idx| index.get_node(parent_idx)) {
+        let tag_name = index.resolve_symbol(parent.tag_name).unwrap_or_default();
+        if !(is_element(parent) && is_void_element(&tag_name, context)) {
+            depth += 1;
+        }
+        current = parent.parent;
+    }
+    depth
+}
+
+pub(crate) fn is_element(node: &IndexedNode) -> bool {
+    node.kind == NodeKind::Element
+}
+
+pub(crate) const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+pub(crate) fn is_void_element(tag_name: &str, context: ElementContext) -> bool {
+    VOID_ELEMENTS.contains(&tag_name) || context.void_elements.iter().any(|tag| tag == tag_name)
+}
+
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "dialog",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+pub(crate) fn is_block_element(tag_name: &str, context: ElementContext) -> bool {
+    BLOCK_ELEMENTS.contains(&tag_name) || context.block_elements.iter().any(|tag| tag == tag_name)
+}
+
+const INLINE_ELEMENTS: &[&str] = &[
+    "a", "abbr", "b", "bdi", "bdo", "cite", "code", "em", "i", "kbd", "mark", "q", "s", "samp",
+    "small", "span", "strong", "sub", "sup", "time", "u", "var",
+];
+
+pub(crate) fn is_inline_element(tag_name: &str, context: ElementContext) -> bool {
+    INLINE_ELEMENTS.contains(&tag_name) || context.inline_elements.iter().any(|tag| tag == tag_name)
+}
+
+/// First and last source lines spanned by an element, found by matching its closing tag.
+/// Elements whose closing tag is implied span only their opening line.
+pub(crate) fn get_node_line_range(
+    node_idx: usize,
+    index: &DOMIndex,
+    context: ElementContext,
+) -> Option<(usize, usize)> {
+    let node = index.get_node(node_idx)?;
+    let line = node.source_info.line;
+    if !is_element(node) || line == 0 {
+        return None;
+    }
+
+    let tag_name = index.resolve_symbol(node.tag_name)?;
+    if is_void_element(&tag_name, context) {
+        return Some((line, line));
+    }
+
+    let source_map = index.get_source_map();
+    let start = source_map.line_offsets[line - 1]
+        + source_map.byte_index(&source_map.lines[line - 1], node.source_info.column);
+    let source = index.get_source().get(start..)?.to_ascii_lowercase();
+    let open = format!("<{}", tag_name);
+    let close = format!("</{}", tag_name);
+
+    // Track nested elements with the same name so we stop at the matching closing tag
+    let mut depth = 0;
+    let mut pos = 0;
+    while let Some(found) = source[pos..].find('<') {
+        let at = pos + found;
+        let rest = &source[at..];
+        let is_boundary = |tag: &str| {
+            rest.starts_with(tag)
+                && rest[tag.len()..]
+                    .chars()
+                    .next()
+                    .is_none_or(|c| c == '>' || c == '/' || c.is_whitespace())
+        };
+
+        if is_boundary(&close) {
+            depth -= 1;
+            if depth == 0 {
+                let (end_line, _) = source_map.get_position(start + at);
+                return Some((line, end_line));
+            }
+        } else if is_boundary(&open) {
+            depth += 1;
+        }
+        pos = at + 1;
+    }
+
+    Some((line, line))
+}
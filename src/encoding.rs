@@ -0,0 +1,46 @@
+use crate::LinterError;
+use encoding_rs::Encoding;
+
+// Encodings are sniffed from the same prefix browsers scan for a `<meta charset>`
+const SNIFF_LIMIT: usize = 1024;
+
+/// The encoding named by a byte order mark, or else by a `charset` declaration in the first
+/// 1024 bytes (`<meta charset>` or an `http-equiv` content type).
+pub(crate) fn sniff(bytes: &[u8]) -> Option<&'static Encoding> {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return Some(encoding);
+    }
+
+    let prefix = String::from_utf8_lossy(&bytes[..bytes.len().min(SNIFF_LIMIT)]).to_lowercase();
+    prefix.match_indices("charset").find_map(|(start, name)| {
+        let rest = prefix[start + name.len()..].trim_start();
+        let value = rest.strip_prefix('=')?.trim_start();
+        let value = value.trim_start_matches(['"', '\'']);
+        let end = value
+            .find(|c: char| c.is_ascii_whitespace() || matches!(c, '"' | '\'' | ';' | '>' | '/'))
+            .unwrap_or(value.len());
+        // A UTF-16 document couldn't have been read this far as ASCII, so like browsers
+        // treat such a declaration as UTF-8
+        Encoding::for_label(&value.as_bytes()[..end]).map(Encoding::output_encoding)
+    })
+}
+
+/// Looks up an encoding by any of its WHATWG labels, e.g. `latin1` or `shift_jis`.
+pub(crate) fn for_label(label: &str) -> Result<&'static Encoding, LinterError> {
+    Encoding::for_label(label.trim().as_bytes())
+        .ok_or_else(|| LinterError::ParseError(format!("Unsupported encoding: {}", label)))
+}
+
+/// Transcodes `bytes` to UTF-8. A byte order mark overrides `encoding`, as it does in
+/// browsers, and is removed.
+pub(crate) fn decode(bytes: &[u8], encoding: &'static Encoding) -> Result<String, LinterError> {
+    let encoding = Encoding::for_bom(bytes).map_or(encoding, |(encoding, _)| encoding);
+    let (text, had_errors) = encoding.decode_with_bom_removal(bytes);
+    if had_errors {
+        return Err(LinterError::ParseError(format!(
+            "Invalid {} input",
+            encoding.name()
+        )));
+    }
+    Ok(text.into_owned())
+}
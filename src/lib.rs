@@ -2,15 +2,40 @@ use html5ever::driver::ParseOpts;
 use html5ever::parse_document;
 use html5ever::tendril::TendrilSink;
 use markup5ever_rcdom::RcDom;
+use rayon::prelude::*;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+mod builder;
 mod checks;
+mod collector;
+mod condition;
+mod directives;
 mod dom;
+mod encoding;
+pub mod output;
+mod plugin;
+pub mod report;
+pub mod sarif;
+mod stats;
 
-use dom::{DOMIndex, IndexedNode};
+pub use builder::HtmlLinterBuilder;
+use collector::{DryRunAccumulator, Reported, ResultCollector, RuleResults};
+pub use condition::ConditionType;
+use directives::DisabledRegions;
+pub use dom::index::{DOMIndex, DomStats};
+use dom::interner::FrozenStringInterner;
+use dom::select::SelectorEngine;
+pub use dom::select::SelectorOptions;
+use dom::utils::{build_element_path, ElementContext};
+pub use dom::IndexedNode;
+use dom::SourceMap;
+use output::LintResultWriter;
+pub use plugin::LinterPlugin;
+pub use stats::LintStats;
 
 #[derive(Error, Debug)]
 pub enum LinterError {
@@ -22,10 +47,13 @@ pub enum LinterError {
     SelectorError(String),
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
+    #[error("TOML error: {0}")]
+    TomlError(String),
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub enum RuleType {
+    #[default]
     ElementPresence,
     AttributePresence,
     AttributeValue,
@@ -41,49 +69,264 @@ pub enum RuleType {
     ElementCount,
     ElementCase,
     AttributeQuotes,
+    AriaRole,
+    JsonLd,
+    FormValidation,
+    SecurityPattern,
+    Encoding,
+    OpenGraph,
+    Twitter,
+    Microdata,
+    Manifest,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// A rule to run. Literals can end with `..Default::default()` to leave the optional
+/// `options`, `tags` and `fix_hint` empty.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Rule {
     pub name: String,
     pub rule_type: RuleType,
     pub severity: Severity,
-    pub selector: String,  // CSS-like selector
-    pub condition: String, // Rule-specific condition
-    pub message: String,   // Error message
+    pub selector: String,         // CSS-like selector
+    pub condition: ConditionType, // Rule-specific condition
+    pub message: String,          // Error message
     #[serde(default)]
     pub options: HashMap<String, String>, // Additional rule options
+    #[serde(default)]
+    pub tags: Vec<String>, // Groups for LinterOptions::only_tags / skip_tags
+    #[serde(default)]
+    pub fix_hint: String, // How to resolve a violation, copied into each LintResult
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub enum Severity {
+    #[default]
     Error,
     Warning,
     Info,
 }
 
-#[derive(Debug, Clone)]
+impl Severity {
+    /// Process exit code conventionally used for this severity (Error=2, Warning=1, Info=0).
+    pub fn to_exit_code(&self) -> i32 {
+        match self {
+            Severity::Error => 2,
+            Severity::Warning => 1,
+            Severity::Info => 0,
+        }
+    }
+}
+
+/// A violation found by a rule. Literals can end with `..Default::default()` to leave the
+/// optional fields empty.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct LintResult {
     pub rule: String,
     pub severity: Severity,
     pub message: String,
     pub location: Location,
     pub source: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub context: String, // Surrounding source lines with a caret under the violation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix_suggestion: Option<FixSuggestion>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub element_path: String, // Empty when the violation isn't tied to an element
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub fix_hint: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool, // Last result kept before `max_errors_per_rule` dropped the rest
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FixSuggestion {
+    pub description: String,
+    pub replacement: Option<String>, // Corrected text for `LintResult::source`
+    pub is_safe: bool,               // Whether the fix can be applied without review
+}
+
+/// Documentation for a rule, as returned by `HtmlLinter::rule_docs` and `explain_rule`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RuleDoc {
+    pub name: String,
+    pub rule_type: String,
+    pub severity: Severity,
+    pub description: String, // The rule's message unless it is a built-in rule
+    #[serde(default)]
+    pub examples: Vec<RuleExample>,
+    pub message: String,
+    pub fix_hint: String,
+    pub tags: Vec<String>,
+}
+
+/// A snippet of markup that the documented rule accepts (`valid`) or reports.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RuleExample {
+    pub valid: bool,
+    pub html: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Location {
     pub line: usize,
     pub column: usize,
     pub element: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>, // Set by `HtmlLinter::lint_file`
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+/// How `Location::column` is counted within a line.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnUnit {
+    Bytes,
+    #[default]
+    Chars,
+    Utf16Units, // What LSP clients such as VS Code expect
+}
+
+impl ColumnUnit {
+    pub(crate) fn measure(self, text: &str) -> usize {
+        match self {
+            ColumnUnit::Bytes => text.len(),
+            ColumnUnit::Chars => text.chars().count(),
+            ColumnUnit::Utf16Units => text.encode_utf16().count(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LinterOptions {
+    #[serde(default)]
     pub ignore_files: Vec<String>,
+    #[serde(default)]
     pub custom_selectors: HashMap<String, String>,
     pub max_line_length: Option<usize>,
+    #[serde(default)]
     pub allow_inline_styles: bool,
+    pub max_nesting_depth: Option<usize>,
+    #[serde(default)]
+    pub only_tags: Vec<String>, // Run only rules carrying one of these tags
+    #[serde(default)]
+    pub skip_tags: Vec<String>, // Skip rules carrying any of these tags
+    #[serde(default)]
+    pub column_unit: ColumnUnit,
+    #[serde(default)]
+    pub treat_warnings_as_errors: bool,
+    #[serde(default)]
+    pub treat_info_as_warnings: bool,
+    #[serde(default)]
+    pub max_errors: Option<usize>, // Stop linting once this many errors are found
+    #[serde(default)]
+    pub max_errors_per_rule: Option<usize>, // Stops a rule once it has reported this many errors
+    #[serde(default)]
+    pub max_count_includes_warnings: bool, // Count warnings towards the limits above
+    #[serde(default)]
+    pub ignore_selectors: Vec<String>, // Elements (and their contents) that are never reported
+    #[serde(default)]
+    pub ignore_data_attribute: Option<String>, // e.g. "data-no-lint"
+    #[serde(default)]
+    pub severity_map: HashMap<String, Severity>, // Per-rule-name severity overrides
+    #[serde(default)]
+    pub downgrade_all_to: Option<Severity>, // Caps every result at this severity
+    #[serde(default = "default_enable_comment_directives")]
+    pub enable_comment_directives: bool, // Honour `<!-- html-linter-... -->` comments
+    #[serde(default)]
+    pub custom_void_elements: Vec<String>, // Custom elements written self-closing, e.g. "my-icon"
+    #[serde(default)]
+    pub custom_block_elements: Vec<String>,
+    #[serde(default)]
+    pub custom_inline_elements: Vec<String>,
+    #[serde(default)]
+    pub encoding: Option<String>, // Encoding of files read by `lint_file`, e.g. "windows-1252"
+    #[serde(default)]
+    pub encoding_detection: bool, // Sniff file encodings from a BOM or `<meta charset>`
+    #[serde(default)]
+    pub case_insensitive_elements: bool, // Let rule selectors such as `IMG` match `img`
+}
+
+fn default_enable_comment_directives() -> bool {
+    true
+}
+
+impl Default for LinterOptions {
+    fn default() -> Self {
+        Self {
+            ignore_files: Vec::new(),
+            custom_selectors: HashMap::new(),
+            max_line_length: None,
+            allow_inline_styles: false,
+            max_nesting_depth: None,
+            only_tags: Vec::new(),
+            skip_tags: Vec::new(),
+            column_unit: ColumnUnit::default(),
+            treat_warnings_as_errors: false,
+            treat_info_as_warnings: false,
+            max_errors: None,
+            max_errors_per_rule: None,
+            max_count_includes_warnings: false,
+            ignore_selectors: Vec::new(),
+            ignore_data_attribute: None,
+            severity_map: HashMap::new(),
+            downgrade_all_to: None,
+            enable_comment_directives: default_enable_comment_directives(),
+            custom_void_elements: Vec::new(),
+            custom_block_elements: Vec::new(),
+            custom_inline_elements: Vec::new(),
+            encoding: None,
+            encoding_detection: false,
+            case_insensitive_elements: false,
+        }
+    }
+}
+
+impl LinterOptions {
+    /// Layers `other` on top of these options. List settings are combined, `other`'s
+    /// selectors and `Some` values take precedence, and plain flags come from `other`.
+    pub fn merge(self, other: LinterOptions) -> LinterOptions {
+        fn union(mut base: Vec<String>, extra: Vec<String>) -> Vec<String> {
+            for item in extra {
+                if !base.contains(&item) {
+                    base.push(item);
+                }
+            }
+            base
+        }
+
+        let mut custom_selectors = self.custom_selectors;
+        custom_selectors.extend(other.custom_selectors);
+        let mut severity_map = self.severity_map;
+        severity_map.extend(other.severity_map);
+
+        LinterOptions {
+            ignore_files: union(self.ignore_files, other.ignore_files),
+            custom_selectors,
+            max_line_length: other.max_line_length.or(self.max_line_length),
+            allow_inline_styles: other.allow_inline_styles,
+            max_nesting_depth: other.max_nesting_depth.or(self.max_nesting_depth),
+            only_tags: union(self.only_tags, other.only_tags),
+            skip_tags: union(self.skip_tags, other.skip_tags),
+            column_unit: other.column_unit,
+            treat_warnings_as_errors: other.treat_warnings_as_errors,
+            treat_info_as_warnings: other.treat_info_as_warnings,
+            max_errors: other.max_errors.or(self.max_errors),
+            max_errors_per_rule: other.max_errors_per_rule.or(self.max_errors_per_rule),
+            max_count_includes_warnings: other.max_count_includes_warnings,
+            ignore_selectors: union(self.ignore_selectors, other.ignore_selectors),
+            ignore_data_attribute: other.ignore_data_attribute.or(self.ignore_data_attribute),
+            severity_map,
+            downgrade_all_to: other.downgrade_all_to.or(self.downgrade_all_to),
+            enable_comment_directives: other.enable_comment_directives,
+            custom_void_elements: union(self.custom_void_elements, other.custom_void_elements),
+            custom_block_elements: union(self.custom_block_elements, other.custom_block_elements),
+            custom_inline_elements: union(
+                self.custom_inline_elements,
+                other.custom_inline_elements,
+            ),
+            encoding: other.encoding.or(self.encoding),
+            encoding_detection: other.encoding_detection,
+            case_insensitive_elements: other.case_insensitive_elements,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -130,6 +373,7 @@ pub enum CompoundCondition {
 pub struct HtmlLinter {
     pub(crate) rules: Vec<Rule>,
     options: LinterOptions,
+    plugins: HashMap<String, Box<dyn LinterPlugin>>, // Keyed by `RuleType::Custom` validator name
 }
 
 impl HtmlLinter {
@@ -137,32 +381,253 @@ impl HtmlLinter {
         Self {
             rules,
             options: options.unwrap_or_default(),
+            plugins: HashMap::new(),
         }
     }
 
+    /// Like `new`, but fails on rules whose condition their rule type doesn't support,
+    /// which would otherwise never report anything. Rules loaded from config files are
+    /// checked this way.
+    pub fn try_new(rules: Vec<Rule>, options: Option<LinterOptions>) -> Result<Self, LinterError> {
+        if let Some(rule) = rules
+            .iter()
+            .find(|rule| !rule.condition.is_compatible_with(&rule.rule_type))
+        {
+            return Err(LinterError::RuleError(format!(
+                "Condition '{}' is not supported by {:?} rule '{}'",
+                rule.condition, rule.rule_type, rule.name
+            )));
+        }
+        Ok(Self::new(rules, options))
+    }
+
+    /// Registers `plugin` to run `RuleType::Custom(name)` rules, taking precedence over any
+    /// built-in validator of the same name.
+    pub fn with_plugin(mut self, name: &str, plugin: Box<dyn LinterPlugin>) -> Self {
+        self.plugins.insert(name.to_string(), plugin);
+        self
+    }
+
     pub fn lint(&self, html: &str) -> Result<Vec<LintResult>, LinterError> {
+        self.lint_document(html, self.promotion())
+    }
+
+    /// Like `lint`, but reports warnings as errors and info results as warnings.
+    pub fn lint_strict(&self, html: &str) -> Result<Vec<LintResult>, LinterError> {
+        let promotion = Promotion {
+            warnings_as_errors: true,
+            info_as_warnings: true,
+        };
+        self.lint_document(html, promotion)
+    }
+
+    /// Lints `(document_id, html)` pairs in parallel. Results come back in input order,
+    /// paired with the document id; a failure in one document doesn't affect the others.
+    pub fn lint_batch<'a>(
+        &self,
+        documents: &[(&'a str, &str)],
+    ) -> Vec<(&'a str, Result<Vec<LintResult>, LinterError>)> {
+        documents
+            .par_iter()
+            .map(|&(id, html)| (id, self.lint(html)))
+            .collect()
+    }
+
+    /// Reads and lints the file at `path`, recording the path in each result's location.
+    pub fn lint_file(&self, path: impl AsRef<Path>) -> Result<Vec<LintResult>, LinterError> {
+        let path = path.as_ref();
+        let html = self.read_html(path)?;
+        let mut results = self.lint(&html)?;
+        for result in &mut results {
+            result.location.file = Some(path.display().to_string());
+        }
+        Ok(results)
+    }
+
+    // Decodes a file using the sniffed encoding when detection is on, falling back to
+    // `LinterOptions::encoding` and then UTF-8
+    fn read_html(&self, path: &Path) -> Result<String, LinterError> {
+        let bytes = std::fs::read(path)?;
+        let sniffed = if self.options.encoding_detection {
+            encoding::sniff(&bytes)
+        } else {
+            None
+        };
+        let encoding = match (sniffed, &self.options.encoding) {
+            (Some(encoding), _) => encoding,
+            (None, Some(label)) => encoding::for_label(label)?,
+            (None, None) => encoding_rs::UTF_8,
+        };
+        encoding::decode(&bytes, encoding)
+    }
+
+    /// Reads and lints each file in parallel, returning results in input order.
+    pub fn lint_batch_files(
+        &self,
+        paths: &[&Path],
+    ) -> Vec<(PathBuf, Result<Vec<LintResult>, LinterError>)> {
+        paths
+            .par_iter()
+            .map(|path| (path.to_path_buf(), self.lint_file(path)))
+            .collect()
+    }
+
+    /// Runs every rule over `html` and returns only the stats `compute_stats` would give for
+    /// the results of `lint`. Only the rule and severity of each result are recorded, and no
+    /// result is ever built, which suits CI gates on large documents where only pass/fail
+    /// matters.
+    pub fn dry_run(&self, html: &str) -> Result<LintStats, LinterError> {
+        let counts: DryRunAccumulator = self.lint_document(html, self.promotion())?;
+        Ok(counts.into_stats())
+    }
+
+    fn lint_document<C: ResultCollector>(
+        &self,
+        html: &str,
+        promotion: Promotion,
+    ) -> Result<C, LinterError> {
         let dom = parse_document(RcDom::default(), ParseOpts::default())
             .from_utf8()
             .read_from(&mut html.as_bytes())
             .map_err(|e| LinterError::ParseError(e.to_string()))?;
 
-        let index = DOMIndex::new(&dom, html);
-        let mut results = Vec::new();
+        let index = DOMIndex::new(&dom, html, self.options.column_unit)
+            .with_selector_options(self.selector_options());
+        self.run_rules(html, &index, promotion)
+    }
+
+    /// Lints a partial document, such as a template component, as content of a `body` element.
+    pub fn lint_fragment(&self, html: &str) -> Result<Vec<LintResult>, LinterError> {
+        self.lint_fragment_in(html, "body")
+    }
+
+    /// Lints a partial document as the content of a `context` element.
+    ///
+    /// Elements the parser inserts around the fragment (`html`, `head`, `body`) are left out
+    /// of the index, so rules targeting them only match when they appear in the source.
+    pub fn lint_fragment_in(
+        &self,
+        html: &str,
+        context: &str,
+    ) -> Result<Vec<LintResult>, LinterError> {
+        let index = DOMIndex::from_fragment(html, context, self.options.column_unit)
+            .with_selector_options(self.selector_options());
+        self.run_rules(html, &index, self.promotion())
+    }
 
-        // Process rules in parallel using rayon
-        for rule in &self.rules {
-            if !self.should_ignore_rule(&rule.name) {
-                results.extend(self.process_rule(rule, &index)?);
+    // Severities are promoted before results count toward `max_errors` or
+    // `max_errors_per_rule`, so the limits apply to the severities that are reported
+    fn run_rules<C: ResultCollector>(
+        &self,
+        html: &str,
+        index: &DOMIndex,
+        promotion: Promotion,
+    ) -> Result<C, LinterError> {
+        // Linted content can't switch off its own checks unless directives are trusted
+        let filters = LintFilters {
+            disabled: if self.options.enable_comment_directives {
+                DisabledRegions::parse(html)
+            } else {
+                DisabledRegions::default()
+            },
+            ignored: self.ignored_positions(index),
+            promotion,
+        };
+        let option_rules = self.option_rules();
+
+        if let Some(max_errors) = self.options.max_errors {
+            // Rules run one at a time so linting can stop as soon as the limit is reached
+            let mut results = C::default();
+            let mut budget = max_errors;
+            if max_errors == 0 {
+                return Ok(results);
             }
+
+            let rules = self.rules.iter().chain(option_rules.iter());
+            for rule in rules.filter(|rule| self.should_run_rule(rule)) {
+                let mut reported = self.process_rule(rule, index, &filters, C::KEEPS_RESULTS)?;
+                let exhausted =
+                    reported.truncate(&mut budget, |severity| self.counts_toward_limit(severity));
+                results.collect(&rule.name, reported);
+                if exhausted {
+                    return Ok(results);
+                }
+            }
+            return Ok(results);
         }
 
+        // Process rules in parallel using rayon; collecting keeps results in rule order
+        let collectors = self
+            .rules
+            .par_iter()
+            .chain(option_rules.par_iter())
+            .filter(|rule| self.should_run_rule(rule))
+            .map(|rule| {
+                let mut collector = C::default();
+                self.process_rule(rule, index, &filters, C::KEEPS_RESULTS)
+                    .map(|reported| {
+                        collector.collect(&rule.name, reported);
+                        collector
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut results = C::default();
+        for collector in collectors {
+            results.append(collector);
+        }
         Ok(results)
     }
 
+    /// Source positions of elements excluded by `ignore_selectors` or `ignore_data_attribute`,
+    /// including everything nested inside them. Results are matched to elements by position.
+    fn ignored_positions(&self, index: &DOMIndex) -> HashSet<(usize, usize)> {
+        let mut selectors = self.options.ignore_selectors.clone();
+        if let Some(attribute) = &self.options.ignore_data_attribute {
+            selectors.push(format!("[{}]", attribute));
+        }
+
+        let mut positions = HashSet::new();
+        for selector in &selectors {
+            for node_idx in index.query(selector) {
+                let subtree = std::iter::once(node_idx).chain(index.query_within(node_idx, "*"));
+                for idx in subtree {
+                    if let Some(node) = index.get_node(idx).filter(|node| node.source_info.line > 0)
+                    {
+                        positions.insert((node.source_info.line, node.source_info.column));
+                    }
+                }
+            }
+        }
+        positions
+    }
+
+    fn counts_toward_limit(&self, severity: &Severity) -> bool {
+        match severity {
+            Severity::Error => true,
+            Severity::Warning => self.options.max_count_includes_warnings,
+            Severity::Info => false,
+        }
+    }
+
+    /// Summarises `results` by severity and rule; see [`LintStats::from_results`].
+    pub fn compute_stats(results: &[LintResult]) -> LintStats {
+        LintStats::from_results(results)
+    }
+
+    /// Prints `results` to stdout as GitHub Actions workflow commands.
+    pub fn print_github_annotations(results: &[LintResult]) -> std::io::Result<()> {
+        output::github::GithubAnnotations.write_all(results, &mut std::io::stdout().lock())
+    }
+
+    pub fn builder() -> HtmlLinterBuilder {
+        HtmlLinterBuilder::new()
+    }
+
     pub fn from_json(json: &str, options: Option<LinterOptions>) -> Result<Self, LinterError> {
         let rules: Vec<Rule> = serde_json::from_str(json)
             .map_err(|e| LinterError::ParseError(format!("Failed to parse rules JSON: {}", e)))?;
-        Ok(Self::new(rules, options))
+        Self::try_new(rules, options)
     }
 
     pub fn from_json_file(path: &str, options: Option<LinterOptions>) -> Result<Self, LinterError> {
@@ -170,6 +635,136 @@ impl HtmlLinter {
         Self::from_json(&content, options)
     }
 
+    /// Loads rules from a YAML sequence of rule mappings, using the same fields as the JSON format:
+    ///
+    /// ```yaml
+    /// - name: img-alt
+    ///   rule_type: AttributePresence
+    ///   severity: Error
+    ///   selector: img
+    ///   condition: alt-missing
+    ///   message: Images must have alt attributes
+    /// - name: no-empty-links
+    ///   rule_type: !Custom no-empty-links
+    ///   severity: Warning
+    ///   selector: a
+    ///   condition: no-empty-links
+    ///   message: Links must have content
+    ///   options:
+    ///     check_mode: normal
+    /// ```
+    pub fn from_yaml(yaml: &str, options: Option<LinterOptions>) -> Result<Self, LinterError> {
+        let rules: Vec<Rule> = serde_yaml::from_str(yaml)
+            .map_err(|e| LinterError::ParseError(format!("Failed to parse rules YAML: {}", e)))?;
+        Self::try_new(rules, options)
+    }
+
+    pub fn from_yaml_file(path: &str, options: Option<LinterOptions>) -> Result<Self, LinterError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_yaml(&content, options)
+    }
+
+    /// Loads rules from a TOML document with one `[[rules]]` table per rule. An optional
+    /// `[linter_options]` table is merged under `options`, which wins where both set a value:
+    ///
+    /// ```
+    /// use html_linter::HtmlLinter;
+    ///
+    /// let linter = HtmlLinter::from_toml(
+    ///     r#"
+    /// [linter_options]
+    /// max_line_length = 120
+    ///
+    /// [[rules]]
+    /// name = "img-alt"
+    /// rule_type = "AttributePresence"
+    /// severity = "Error"
+    /// selector = "img"
+    /// condition = "alt-missing"
+    /// message = "Images must have alt attributes"
+    ///
+    /// [[rules]]
+    /// name = "no-empty-links"
+    /// rule_type = { Custom = "no-empty-links" }
+    /// severity = "Warning"
+    /// selector = "a"
+    /// condition = "no-empty-links"
+    /// message = "Links must have content"
+    /// options = { check_mode = "normal" }
+    /// "#,
+    ///     None,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(linter.get_rules().len(), 2);
+    /// ```
+    pub fn from_toml(toml: &str, options: Option<LinterOptions>) -> Result<Self, LinterError> {
+        #[derive(Deserialize)]
+        struct TomlConfig {
+            #[serde(default)]
+            rules: Vec<Rule>,
+            linter_options: Option<LinterOptions>,
+        }
+
+        let config: TomlConfig = toml::from_str(toml)
+            .map_err(|e| LinterError::TomlError(format!("Failed to parse rules TOML: {}", e)))?;
+        let options = match (config.linter_options, options) {
+            (Some(file_options), Some(options)) => Some(file_options.merge(options)),
+            (file_options, options) => options.or(file_options),
+        };
+        Self::try_new(config.rules, options)
+    }
+
+    pub fn from_toml_file(path: &str, options: Option<LinterOptions>) -> Result<Self, LinterError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_toml(&content, options)
+    }
+
+    /// Loads a rule file, choosing the format from its `.json`, `.yaml`/`.yml` or `.toml` extension.
+    pub fn from_config_file(path: &str) -> Result<Self, LinterError> {
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("json") => Self::from_json_file(path, None),
+            Some("yaml") | Some("yml") => Self::from_yaml_file(path, None),
+            Some("toml") => Self::from_toml_file(path, None),
+            _ => Err(LinterError::ParseError(format!(
+                "Unsupported config file format: {}",
+                path
+            ))),
+        }
+    }
+
+    /// Rules implied by `LinterOptions` rather than configured explicitly.
+    fn option_rules(&self) -> Vec<Rule> {
+        let mut rules = Vec::new();
+
+        if let Some(max) = self.options.max_nesting_depth {
+            rules.push(Rule {
+                name: "max-nesting-depth".to_string(),
+                rule_type: RuleType::Nesting,
+                severity: Severity::Warning,
+                selector: "*".to_string(),
+                condition: ConditionType::MaxDepth,
+                message: "Element is nested too deeply".to_string(),
+                options: HashMap::from([("max".to_string(), max.to_string())]),
+                ..Default::default()
+            });
+        }
+
+        rules
+    }
+
+    fn should_run_rule(&self, rule: &Rule) -> bool {
+        let has_tag = |tags: &[String]| rule.tags.iter().any(|tag| tags.contains(tag));
+
+        !self.should_ignore_rule(&rule.name)
+            && (self.options.only_tags.is_empty() || has_tag(&self.options.only_tags))
+            && !has_tag(&self.options.skip_tags)
+    }
+
     fn should_ignore_rule(&self, rule_name: &str) -> bool {
         self.options.ignore_files.iter().any(|pattern| {
             if let Ok(regex) = Regex::new(pattern) {
@@ -180,23 +775,107 @@ impl HtmlLinter {
         })
     }
 
-    fn process_rule(&self, rule: &Rule, index: &DOMIndex) -> Result<Vec<LintResult>, LinterError> {
+    // Checks report into `RuleResults`, which drops filtered results and stops the rule once
+    // `max_errors_per_rule` is reached
+    fn process_rule(
+        &self,
+        rule: &Rule,
+        index: &DOMIndex,
+        filters: &LintFilters,
+        keep_results: bool,
+    ) -> Result<Reported, LinterError> {
+        let mut results = RuleResults::new(self, rule, index, filters, keep_results);
         match rule.rule_type {
-            RuleType::ElementPresence => self.check_element_presence(rule, index),
-            RuleType::AttributePresence => self.check_attribute_presence(rule, index),
-            RuleType::AttributeValue => self.check_attribute_value(rule, index),
-            RuleType::ElementOrder => self.check_element_order(rule, index),
-            RuleType::TextContent => self.check_text_content(rule, index),
-            RuleType::ElementContent => self.check_element_content(rule, index),
-            RuleType::WhiteSpace => self.check_whitespace(rule, index),
-            RuleType::Nesting => self.check_nesting(rule, index),
-            RuleType::Semantics => self.check_semantics(rule, index),
-            RuleType::Compound => self.check_compound(rule, index),
-            RuleType::Custom(ref validator) => self.check_custom(rule, validator, index),
-            RuleType::DocumentStructure => self.check_document_structure(rule, index),
-            RuleType::ElementCount => self.check_element_count(rule, index),
-            RuleType::ElementCase => self.check_element_case(rule, index),
-            RuleType::AttributeQuotes => self.check_attribute_quotes(rule, index),
+            RuleType::ElementPresence => self.check_element_presence(rule, index, &mut results),
+            RuleType::AttributePresence => self.check_attribute_presence(rule, index, &mut results),
+            RuleType::AttributeValue => self.check_attribute_value(rule, index, &mut results),
+            RuleType::ElementOrder => self.check_element_order(rule, index, &mut results),
+            RuleType::TextContent => self.check_text_content(rule, index, &mut results),
+            RuleType::ElementContent => self.check_element_content(rule, index, &mut results),
+            RuleType::WhiteSpace => self.check_whitespace(rule, index, &mut results),
+            RuleType::Nesting => self.check_nesting(rule, index, &mut results),
+            RuleType::Semantics => self.check_semantics(rule, index, &mut results),
+            RuleType::Compound => self.check_compound(rule, index, &mut results),
+            RuleType::Custom(ref validator) => match self.plugins.get(validator) {
+                Some(plugin) => plugin.check(rule, index).map(|reported| {
+                    for result in reported {
+                        results.push(result);
+                    }
+                }),
+                None => self.check_custom(rule, validator, index, &mut results),
+            },
+            RuleType::DocumentStructure => self.check_document_structure(rule, index, &mut results),
+            RuleType::ElementCount => self.check_element_count(rule, index, &mut results),
+            RuleType::ElementCase => self.check_element_case(rule, index, &mut results),
+            RuleType::AttributeQuotes => self.check_attribute_quotes(rule, index, &mut results),
+            RuleType::AriaRole => self.check_aria_role(rule, index, &mut results),
+            RuleType::JsonLd => self.check_json_ld(rule, index, &mut results),
+            RuleType::FormValidation => self.check_form_validation(rule, index, &mut results),
+            RuleType::SecurityPattern => self.check_security_pattern(rule, index, &mut results),
+            RuleType::Encoding => self.check_encoding(rule, index, &mut results),
+            RuleType::OpenGraph => self.check_open_graph(rule, index, &mut results),
+            RuleType::Twitter => self.check_twitter_card(rule, index, &mut results),
+            RuleType::Microdata => self.check_microdata(rule, index, &mut results),
+            RuleType::Manifest => self.check_manifest(rule, index, &mut results),
+        }?;
+        Ok(results.into_reported())
+    }
+
+    // The severity a result is reported with, or `None` when the rule is disabled on its
+    // line or its element is ignored
+    fn reported_severity(
+        &self,
+        rule: &Rule,
+        filters: &LintFilters,
+        line: usize,
+        column: usize,
+        severity: &Severity,
+    ) -> Option<Severity> {
+        if filters.disabled.is_disabled(&rule.name, line)
+            || filters.ignored.contains(&(line, column))
+        {
+            return None;
+        }
+        let severity = filters
+            .disabled
+            .configured_severity(&rule.name, line)
+            .unwrap_or_else(|| severity.clone());
+        Some(
+            filters
+                .promotion
+                .apply(self.effective_severity(&rule.name, severity)),
+        )
+    }
+
+    // A rule's entry in `severity_map` takes precedence over the `downgrade_all_to` cap
+    fn effective_severity(&self, rule_name: &str, severity: Severity) -> Severity {
+        if let Some(severity) = self.options.severity_map.get(rule_name) {
+            return severity.clone();
+        }
+        match &self.options.downgrade_all_to {
+            Some(cap) if severity.to_exit_code() > cap.to_exit_code() => cap.clone(),
+            _ => severity,
+        }
+    }
+
+    fn promotion(&self) -> Promotion {
+        Promotion {
+            warnings_as_errors: self.options.treat_warnings_as_errors,
+            info_as_warnings: self.options.treat_info_as_warnings,
+        }
+    }
+
+    fn selector_options(&self) -> SelectorOptions {
+        SelectorOptions {
+            case_insensitive_elements: self.options.case_insensitive_elements,
+        }
+    }
+
+    fn element_context(&self) -> ElementContext<'_> {
+        ElementContext {
+            void_elements: &self.options.custom_void_elements,
+            block_elements: &self.options.custom_block_elements,
+            inline_elements: &self.options.custom_inline_elements,
         }
     }
 
@@ -212,14 +891,180 @@ impl HtmlLinter {
                     .resolve_symbol(node.tag_name)
                     .unwrap_or_default()
                     .to_string(),
+                file: None,
             },
             source: node.source_info.source.clone(),
+            context: index
+                .get_source_map()
+                .context(node.source_info.line, node.source_info.column),
+            fix_suggestion: None,
+            element_path: index
+                .node_index_of(node)
+                .map(|node_idx| build_element_path(node_idx, index))
+                .unwrap_or_default(),
+            fix_hint: rule.fix_hint.clone(),
+            ..Default::default()
         }
     }
 
+    /// Applies every safe fix suggestion to `html`.
+    ///
+    /// A fix replaces the result's `source` text, which has to start at the reported line and
+    /// column (counted in this linter's `column_unit`); fixes whose text isn't there are
+    /// skipped. Fixes are applied from the end of the document backwards so earlier offsets
+    /// stay valid, and overlapping fixes are skipped.
+    pub fn apply_fixes(&self, html: &str, results: &[LintResult]) -> String {
+        let source_map = SourceMap::new(html, self.options.column_unit);
+
+        let mut edits: Vec<(usize, usize, &str)> = results
+            .iter()
+            .filter_map(|result| {
+                let fix = result.fix_suggestion.as_ref()?;
+                let replacement = fix.replacement.as_deref()?;
+                if !fix.is_safe || result.source.is_empty() {
+                    return None;
+                }
+
+                let line = source_map.get_line(result.location.line)?;
+                let start = source_map.line_offsets[result.location.line - 1]
+                    + source_map.byte_index(line, result.location.column);
+                html[start..].starts_with(&result.source).then_some((
+                    start,
+                    start + result.source.len(),
+                    replacement,
+                ))
+            })
+            .collect();
+
+        edits.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+
+        let mut fixed = html.to_string();
+        let mut applied_from = usize::MAX;
+        for (start, end, replacement) in edits {
+            if end > applied_from {
+                continue;
+            }
+            fixed.replace_range(start..end, replacement);
+            applied_from = start;
+        }
+
+        fixed
+    }
+
+    /// Combines two linters. Rules from `other` replace same-named rules from `self` in
+    /// place; the rest are appended. Options are combined with [`LinterOptions::merge`], and
+    /// `other`'s plugins replace same-named ones.
+    pub fn merge(mut self, other: HtmlLinter) -> HtmlLinter {
+        self.extend_rules(other.rules);
+        self.options = self.options.merge(other.options);
+        self.plugins.extend(other.plugins);
+        self
+    }
+
+    /// Adds each rule with the same replacement semantics as `add_rule`.
+    pub fn extend_rules(&mut self, rules: Vec<Rule>) -> &mut Self {
+        for rule in rules {
+            self.add_rule(rule);
+        }
+        self
+    }
+
     pub fn get_rules(&self) -> Vec<Rule> {
         self.rules.clone()
     }
+
+    /// Adds `rule`, replacing any existing rule with the same name in place.
+    pub fn add_rule(&mut self, rule: Rule) {
+        match self
+            .rules
+            .iter_mut()
+            .find(|existing| existing.name == rule.name)
+        {
+            Some(existing) => *existing = rule,
+            None => self.rules.push(rule),
+        }
+    }
+
+    /// Removes the rule named `rule_name`, returning whether it existed.
+    pub fn remove_rule(&mut self, rule_name: &str) -> bool {
+        let len = self.rules.len();
+        self.rules.retain(|rule| rule.name != rule_name);
+        self.rules.len() != len
+    }
+
+    /// Swaps the rule named `rule_name` for `new_rule`, returning whether it existed.
+    pub fn replace_rule(&mut self, rule_name: &str, new_rule: Rule) -> bool {
+        match self.rules.iter_mut().find(|rule| rule.name == rule_name) {
+            Some(rule) => {
+                *rule = new_rule;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get_rules_by_tag(&self, tag: &str) -> Vec<&Rule> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.tags.iter().any(|t| t == tag))
+            .collect()
+    }
+
+    pub fn rule_docs(&self) -> Vec<RuleDoc> {
+        self.rules.iter().map(Self::rule_doc).collect()
+    }
+
+    pub fn has_rule(&self, rule_name: &str) -> bool {
+        self.rules.iter().any(|rule| rule.name == rule_name)
+    }
+
+    pub fn rule_count(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Problems with rule selectors that don't stop them from running but mean they can
+    /// never match, such as uppercase element names or unsupported pseudo-classes. Each
+    /// warning names the rule whose selector caused it.
+    pub fn selector_warnings(&self) -> Vec<String> {
+        let interner = FrozenStringInterner::default();
+        self.rules
+            .iter()
+            .flat_map(|rule| {
+                let mut engine = SelectorEngine::new(Default::default());
+                engine.set_options(self.selector_options());
+                engine.parse_selector(&rule.selector, &interner);
+                engine
+                    .warnings()
+                    .into_iter()
+                    .map(move |warning| format!("Rule '{}': {}", rule.name, warning))
+            })
+            .collect()
+    }
+}
+
+/// What decides which results a rule reports, and with which severity.
+struct LintFilters {
+    disabled: DisabledRegions,
+    ignored: HashSet<(usize, usize)>,
+    promotion: Promotion,
+}
+
+/// Severity promotion from `treat_warnings_as_errors` and `treat_info_as_warnings`.
+#[derive(Clone, Copy)]
+struct Promotion {
+    warnings_as_errors: bool,
+    info_as_warnings: bool,
+}
+
+impl Promotion {
+    // Each result is promoted at most one level, so info never jumps straight to error
+    fn apply(self, severity: Severity) -> Severity {
+        match severity {
+            Severity::Warning if self.warnings_as_errors => Severity::Error,
+            Severity::Info if self.info_as_warnings => Severity::Warning,
+            severity => severity,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -233,9 +1078,10 @@ mod tests {
             rule_type: RuleType::AttributePresence,
             severity: Severity::Error,
             selector: "img".to_string(),
-            condition: "alt-missing".to_string(),
+            condition: ConditionType::AltMissing,
             message: "Image must have alt attribute".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         }];
 
         let linter = HtmlLinter::new(rules, None);
@@ -245,6 +1091,53 @@ mod tests {
         assert_eq!(results[0].severity, Severity::Error);
     }
 
+    #[test]
+    fn test_rule_results_fill_at_limit() {
+        let rule = Rule {
+            name: "img-alt".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Error,
+            selector: "img".to_string(),
+            condition: ConditionType::AltMissing,
+            message: "Image must have alt attribute".to_string(),
+            ..Default::default()
+        };
+        let linter = HtmlLinter::new(
+            vec![rule.clone()],
+            Some(LinterOptions {
+                max_errors_per_rule: Some(2),
+                ..Default::default()
+            }),
+        );
+        let html = "<img src=a.png><img src=b.png><img src=c.png>";
+        let dom = parse_document(RcDom::default(), ParseOpts::default())
+            .from_utf8()
+            .read_from(&mut html.as_bytes())
+            .unwrap();
+        let index = DOMIndex::new(&dom, html, ColumnUnit::default());
+        let filters = LintFilters {
+            disabled: DisabledRegions::default(),
+            ignored: HashSet::new(),
+            promotion: linter.promotion(),
+        };
+
+        // Checks stop once a result arrives past the limit
+        let mut results = RuleResults::new(&linter, &rule, &index, &filters, true);
+        let images = index.query("img");
+        for &node_idx in &images[..2] {
+            results.report(index.get_node(node_idx).unwrap(), |_| {});
+        }
+        assert!(!results.is_full());
+        results.report(index.get_node(images[2]).unwrap(), |_| {});
+        assert!(results.is_full());
+
+        let Reported::Results(kept) = results.into_reported() else {
+            panic!("expected results");
+        };
+        assert_eq!(kept.len(), 2);
+        assert!(kept[1].truncated);
+    }
+
     #[test]
     fn test_compound_rule() {
         // Add more comprehensive tests
@@ -0,0 +1,58 @@
+use super::LintResultWriter;
+use crate::{LintResult, Severity};
+use std::io::{self, Write};
+
+/// Writes one GitHub Actions workflow command per result, so findings show up as
+/// annotations on the pull request diff.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GithubAnnotations;
+
+impl LintResultWriter for GithubAnnotations {
+    fn write_all(&self, results: &[LintResult], writer: &mut dyn Write) -> io::Result<()> {
+        for result in results {
+            writeln!(writer, "{}", to_github_annotation(result))?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats a result as a workflow command, e.g.
+/// `::error file=index.html,line=3,col=5,title=img-alt::Images must have alt text`.
+pub fn to_github_annotation(result: &LintResult) -> String {
+    let command = match result.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "notice",
+    };
+
+    let mut properties = Vec::new();
+    if let Some(file) = &result.location.file {
+        properties.push(format!("file={}", escape_property(file)));
+    }
+    // Document-level results have no position to point at
+    if result.location.line > 0 {
+        properties.push(format!("line={}", result.location.line));
+        if result.location.column > 0 {
+            properties.push(format!("col={}", result.location.column));
+        }
+    }
+    properties.push(format!("title={}", escape_property(&result.rule)));
+
+    format!(
+        "::{} {}::{}",
+        command,
+        properties.join(","),
+        escape_data(&result.message)
+    )
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
@@ -0,0 +1,23 @@
+use crate::report::HtmlReporter;
+use crate::sarif::SarifWriter;
+use crate::LintResult;
+use std::io::{self, Write};
+
+pub mod github;
+
+/// Serializes a set of lint results in some output format.
+pub trait LintResultWriter {
+    fn write_all(&self, results: &[LintResult], writer: &mut dyn Write) -> io::Result<()>;
+}
+
+impl LintResultWriter for HtmlReporter {
+    fn write_all(&self, results: &[LintResult], writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_all(self.render(results).as_bytes())
+    }
+}
+
+impl LintResultWriter for SarifWriter {
+    fn write_all(&self, results: &[LintResult], writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_all(self.to_sarif(results).to_string().as_bytes())
+    }
+}
@@ -0,0 +1,7 @@
+use crate::{DOMIndex, LintResult, LinterError, Rule};
+
+/// A check implemented outside this crate, run for `RuleType::Custom` rules whose validator
+/// name it was registered under with [`crate::HtmlLinter::with_plugin`].
+pub trait LinterPlugin: Send + Sync {
+    fn check(&self, rule: &Rule, index: &DOMIndex) -> Result<Vec<LintResult>, LinterError>;
+}
@@ -0,0 +1,212 @@
+use crate::dom::SourceMap;
+use crate::{ColumnUnit, LintResult, Severity};
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+
+const DEFAULT_TITLE: &str = "HTML Lint Report";
+
+// Lines shown either side of a violation when the report has the document's source
+const SNIPPET_LINES: usize = 2;
+
+const STYLESHEET: &str = r#"
+body { font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Helvetica, Arial, sans-serif; margin: 2rem auto; max-width: 60rem; color: #1f2328; }
+h1 { font-size: 1.6rem; }
+table.summary { border-collapse: collapse; margin-bottom: 2rem; }
+table.summary th, table.summary td { border: 1px solid #d0d7de; padding: 0.4rem 0.8rem; text-align: left; }
+table.summary td.count { text-align: right; }
+section { margin-bottom: 2rem; }
+.violation { border-left: 4px solid #d0d7de; padding: 0.5rem 1rem; margin: 0.75rem 0; background: #f6f8fa; }
+.violation.error { border-color: #cf222e; }
+.violation.warning { border-color: #bf8700; }
+.violation.info { border-color: #0969da; }
+.violation .rule { font-weight: 600; }
+.violation .location { color: #57606a; font-size: 0.9rem; }
+.violation pre.context { color: #57606a; }
+.violation .fix-hint { font-style: italic; }
+pre { background: #ffffff; border: 1px solid #d0d7de; padding: 0.5rem; overflow-x: auto; }
+"#;
+
+/// Renders lint results as a self-contained HTML page.
+pub struct HtmlReporter {
+    title: String,
+    source: Option<SourceMap>,
+}
+
+impl Default for HtmlReporter {
+    fn default() -> Self {
+        Self {
+            title: DEFAULT_TITLE.to_string(),
+            source: None,
+        }
+    }
+}
+
+impl HtmlReporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_title(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Shows each violation's lines from `source`, the document the results came from, with
+    /// the violating line marked by `> `, instead of the results' own `context`.
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.source = Some(SourceMap::new(source, ColumnUnit::default()));
+        self
+    }
+
+    pub fn render(&self, results: &[LintResult]) -> String {
+        let groups = [
+            (Severity::Error, "error", "Errors"),
+            (Severity::Warning, "warning", "Warnings"),
+            (Severity::Info, "info", "Info"),
+        ];
+
+        let mut html = String::with_capacity(4096 + results.len() * 512);
+        let title = escape_html(&self.title);
+
+        html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        html.push_str("<meta charset=\"utf-8\">\n");
+        let _ = writeln!(html, "<title>{}</title>", title);
+        let _ = writeln!(html, "<style>{}</style>", STYLESHEET);
+        html.push_str("</head>\n<body>\n");
+        let _ = writeln!(html, "<h1>{}</h1>", title);
+
+        // Summary table
+        html.push_str("<table class=\"summary\">\n");
+        html.push_str("<thead><tr><th>Severity</th><th>Count</th></tr></thead>\n<tbody>\n");
+        for (severity, class, label) in &groups {
+            let count = results.iter().filter(|r| r.severity == *severity).count();
+            // Only severities with results get a section to link to
+            let label = if count > 0 {
+                format!("<a href=\"#{}\">{}</a>", class, label)
+            } else {
+                label.to_string()
+            };
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td class=\"count\" id=\"{}-count\">{}</td></tr>",
+                label, class, count
+            );
+        }
+        let _ = writeln!(
+            html,
+            "<tr><th>Total</th><td class=\"count\" id=\"total-count\">{}</td></tr>",
+            results.len()
+        );
+        html.push_str("</tbody>\n</table>\n");
+
+        // One section per severity
+        let mut violation_id = 0;
+        for (severity, class, label) in &groups {
+            let grouped: Vec<&LintResult> =
+                results.iter().filter(|r| r.severity == *severity).collect();
+            if grouped.is_empty() {
+                continue;
+            }
+
+            let _ = writeln!(html, "<section id=\"{}\">", class);
+            let _ = writeln!(html, "<h2>{} ({})</h2>", label, grouped.len());
+
+            for result in grouped {
+                violation_id += 1;
+                let _ = writeln!(
+                    html,
+                    "<div class=\"violation {}\" id=\"violation-{}\">",
+                    class, violation_id
+                );
+                let _ = writeln!(
+                    html,
+                    "<div class=\"rule\">{}</div>",
+                    escape_html(&result.rule)
+                );
+                let _ = writeln!(
+                    html,
+                    "<div class=\"message\">{}</div>",
+                    escape_html(&result.message)
+                );
+                // Results from `lint_file` link to the file they were found in
+                let file = match &result.location.file {
+                    Some(file) => format!(
+                        "<a href=\"{}\">{}</a>: ",
+                        escape_html(file),
+                        escape_html(file)
+                    ),
+                    None => String::new(),
+                };
+                let _ = writeln!(
+                    html,
+                    "<div class=\"location\">{}Line {}, column {}{}</div>",
+                    file,
+                    result.location.line,
+                    result.location.column,
+                    if result.location.element.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" &lt;{}&gt;", escape_html(&result.location.element))
+                    }
+                );
+                if !result.source.is_empty() {
+                    let _ = writeln!(
+                        html,
+                        "<pre><code>{}</code></pre>",
+                        escape_html(&result.source)
+                    );
+                }
+                let context = match &self.source {
+                    Some(source) => source.get_snippet(result.location.line, SNIPPET_LINES),
+                    None => result.context.clone(),
+                };
+                if !context.is_empty() {
+                    let _ = writeln!(
+                        html,
+                        "<pre class=\"context\"><code>{}</code></pre>",
+                        escape_html(&context)
+                    );
+                }
+                if !result.fix_hint.is_empty() {
+                    let _ = writeln!(
+                        html,
+                        "<div class=\"fix-hint\">{}</div>",
+                        escape_html(&result.fix_hint)
+                    );
+                }
+                html.push_str("</div>\n");
+            }
+
+            html.push_str("</section>\n");
+        }
+
+        if results.is_empty() {
+            html.push_str("<p>No problems found.</p>\n");
+        }
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    pub fn render_to_file(&self, results: &[LintResult], path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.render(results))
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,151 @@
+use crate::{LintResult, Severity};
+use serde_json::{json, Value};
+
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// Writes lint results as a SARIF 2.1.0 log through `LintResultWriter`.
+#[derive(Debug, Clone)]
+pub struct SarifWriter {
+    tool_name: String,
+    tool_version: String,
+}
+
+impl SarifWriter {
+    pub fn new(tool_name: &str, tool_version: &str) -> Self {
+        Self {
+            tool_name: tool_name.to_string(),
+            tool_version: tool_version.to_string(),
+        }
+    }
+
+    pub fn to_sarif(&self, results: &[LintResult]) -> Value {
+        to_sarif(results, &self.tool_name, &self.tool_version)
+    }
+}
+
+/// Converts lint results into a SARIF 2.1.0 log with a single run.
+///
+/// Columns are read as Unicode code points, the linter's default `ColumnUnit::Chars`.
+pub fn to_sarif(results: &[LintResult], tool_name: &str, tool_version: &str) -> Value {
+    // Rule descriptors are listed once per rule id, in order of first appearance
+    let mut rule_ids: Vec<&str> = Vec::new();
+    for result in results {
+        if !rule_ids.contains(&result.rule.as_str()) {
+            rule_ids.push(&result.rule);
+        }
+    }
+
+    // A rule's fix hint is the same for all of its results, so it's the rule's help text. It
+    // isn't a fix: SARIF fixes need `artifactChanges`, and a hint doesn't edit anything
+    let rules: Vec<Value> = rule_ids
+        .iter()
+        .map(|&id| {
+            let mut rule = json!({ "id": id });
+            let hint = results
+                .iter()
+                .find(|result| result.rule == id && !result.fix_hint.is_empty());
+            if let Some(result) = hint {
+                rule["help"] = json!({ "text": result.fix_hint });
+            }
+            rule
+        })
+        .collect();
+    let sarif_results: Vec<Value> = results.iter().map(sarif_result).collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": tool_name,
+                    "version": tool_version,
+                    "rules": rules,
+                }
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": sarif_results,
+        }]
+    })
+}
+
+fn sarif_level(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+fn sarif_result(result: &LintResult) -> Value {
+    let mut region = serde_json::Map::new();
+    // SARIF line and column numbers are 1-based; 0 means the location is unknown
+    if result.location.line > 0 {
+        region.insert("startLine".to_string(), json!(result.location.line));
+    }
+    if result.location.column > 0 {
+        region.insert("startColumn".to_string(), json!(result.location.column));
+    }
+    if !result.source.is_empty() {
+        region.insert("snippet".to_string(), json!({ "text": result.source }));
+    }
+
+    let mut sarif = json!({
+        "ruleId": result.rule,
+        "level": sarif_level(&result.severity),
+        "message": { "text": result.message },
+    });
+
+    let mut artifact = serde_json::Map::new();
+    if let Some(file) = &result.location.file {
+        artifact.insert("uri".to_string(), json!(file));
+    }
+
+    if !region.is_empty() {
+        let mut physical = json!({ "region": region });
+        if !artifact.is_empty() {
+            physical["artifactLocation"] = json!(artifact);
+        }
+        sarif["locations"] = json!([{ "physicalLocation": physical }]);
+    }
+
+    // A fix replaces the result's source, which starts at its location
+    let replacement = result
+        .fix_suggestion
+        .as_ref()
+        .and_then(|fix| Some((fix, fix.replacement.as_ref()?)))
+        .filter(|_| result.location.line > 0 && result.location.column > 0);
+    if let Some((fix, replacement)) = replacement {
+        sarif["fixes"] = json!([{
+            "description": { "text": fix.description },
+            "artifactChanges": [{
+                "artifactLocation": artifact,
+                "replacements": [{
+                    "deletedRegion": source_region(result),
+                    "insertedContent": { "text": replacement },
+                }],
+            }],
+        }]);
+    }
+
+    sarif
+}
+
+// The region covered by a result's source text
+fn source_region(result: &LintResult) -> Value {
+    let (line, column) = (result.location.line, result.location.column);
+    let (end_line, end_column) = match result.source.rsplit_once('\n') {
+        Some((before, last)) => (
+            line + before.matches('\n').count() + 1,
+            last.chars().count() + 1,
+        ),
+        None => (line, column + result.source.chars().count()),
+    };
+    json!({
+        "startLine": line,
+        "startColumn": column,
+        "endLine": end_line,
+        "endColumn": end_column,
+    })
+}
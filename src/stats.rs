@@ -0,0 +1,63 @@
+use crate::{LintResult, Severity};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Aggregate counts over a set of lint results.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LintStats {
+    pub error_count: usize,
+    pub warning_count: usize,
+    pub info_count: usize,
+    pub total_count: usize,
+    pub rules_triggered: HashMap<String, usize>, // Results per rule name
+    pub most_common_rule: Option<String>,        // Ties go to the alphabetically first rule
+}
+
+impl LintStats {
+    pub fn from_results(results: &[LintResult]) -> Self {
+        let mut stats = Self {
+            total_count: results.len(),
+            ..Default::default()
+        };
+
+        for result in results {
+            match result.severity {
+                Severity::Error => stats.error_count += 1,
+                Severity::Warning => stats.warning_count += 1,
+                Severity::Info => stats.info_count += 1,
+            }
+            *stats
+                .rules_triggered
+                .entry(result.rule.clone())
+                .or_default() += 1;
+        }
+
+        stats.most_common_rule = most_common_rule(&stats.rules_triggered);
+        stats
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.error_count > 0
+    }
+
+    pub fn worst_severity(&self) -> Option<Severity> {
+        if self.error_count > 0 {
+            Some(Severity::Error)
+        } else if self.warning_count > 0 {
+            Some(Severity::Warning)
+        } else if self.info_count > 0 {
+            Some(Severity::Info)
+        } else {
+            None
+        }
+    }
+}
+
+pub(crate) fn most_common_rule(rules_triggered: &HashMap<String, usize>) -> Option<String> {
+    rules_triggered
+        .iter()
+        .max_by(|(a_rule, a_count), (b_rule, b_count)| {
+            a_count.cmp(b_count).then_with(|| b_rule.cmp(a_rule))
+        })
+        .map(|(rule, _)| rule.clone())
+}
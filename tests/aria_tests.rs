@@ -0,0 +1,192 @@
+mod common;
+
+use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn aria_rule(options: HashMap<String, String>) -> Rule {
+    Rule {
+        name: "aria-role".to_string(),
+        options,
+        ..common::rule(
+            RuleType::AriaRole,
+            Severity::Error,
+            "[role]",
+            "valid-role",
+            "Invalid ARIA role usage",
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_builtin_required_attributes() {
+    let linter = HtmlLinter::new(vec![aria_rule(HashMap::new())], None);
+
+    let results = linter
+        .lint(r#"<div role="checkbox" aria-checked="false">Subscribe</div>"#)
+        .unwrap();
+    assert_eq!(results.len(), 0);
+
+    let results = linter
+        .lint(r#"<div role="checkbox">Subscribe</div><h2 role="heading" aria-level="">Title</h2>"#)
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].message.contains("aria-checked"));
+    assert!(results[1].message.contains("aria-level"));
+}
+
+#[test]
+fn test_allowed_roles() {
+    let mut options = HashMap::new();
+    options.insert(
+        "allowed_roles".to_string(),
+        r#"["button", "navigation"]"#.to_string(),
+    );
+    let linter = HtmlLinter::new(vec![aria_rule(options)], None);
+
+    let results = linter
+        .lint(
+            r#"<nav role="navigation"></nav><div role="button">Go</div><div role="banana">?</div>"#,
+        )
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("banana"));
+}
+
+#[test]
+fn test_custom_required_attributes() {
+    let mut options = HashMap::new();
+    options.insert(
+        "required_attributes".to_string(),
+        r#"{"tab": ["aria-controls", "aria-selected"]}"#.to_string(),
+    );
+    let linter = HtmlLinter::new(vec![aria_rule(options)], None);
+
+    let results = linter
+        .lint(r#"<button role="tab" aria-selected="true">One</button>"#)
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("aria-controls"));
+    assert!(!results[0].message.contains("aria-selected"));
+}
+
+#[test]
+fn test_invalid_options() {
+    let mut options = HashMap::new();
+    options.insert("allowed_roles".to_string(), "button".to_string());
+    let linter = HtmlLinter::new(vec![aria_rule(options)], None);
+
+    assert!(linter.lint(r#"<div role="button"></div>"#).is_err());
+}
+
+fn live_region_results(html: &str) -> Vec<html_linter::LintResult> {
+    let rule = Rule {
+        name: "aria-live-regions".to_string(),
+        rule_type: RuleType::Semantics,
+        severity: Severity::Warning,
+        selector: "*".to_string(),
+        condition: "aria-live-regions".into(),
+        message: "Live region misuse".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    };
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+#[test]
+fn test_valid_live_regions() {
+    let html = "<html><body>
+        <div aria-live='polite'>Saved</div>
+        <div role='status'>3 results</div>
+        <div role='status' aria-live='polite'>Loading</div>
+        <div role='alert'>Session expired</div>
+        <div role='alert' aria-live='assertive'>Payment failed</div>
+        <div aria-live='off'><div aria-live='polite'>Ticker</div></div>
+    </body></html>";
+
+    assert!(live_region_results(html).is_empty());
+}
+
+#[test]
+fn test_invalid_live_regions() {
+    let html = "<html><body>
+        <section aria-live='polite'><p aria-live='polite'>Nested</p></section>
+        <div role='alert' aria-live='polite'>Too quiet</div>
+        <div role='status' aria-live='assertive'>Too loud</div>
+        <div aria-live='loud'>Unknown</div>
+    </body></html>";
+
+    let results = live_region_results(html);
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(results.len(), 5, "{:?}", messages);
+    assert!(messages
+        .iter()
+        .any(|m| m.contains("nested inside another polite live region")));
+    assert!(messages
+        .iter()
+        .any(|m| m.contains(r#"role="alert" implies aria-live="assertive", not "polite""#)));
+    assert!(messages
+        .iter()
+        .any(|m| m.contains(r#"role="status" implies aria-live="polite", not "assertive""#)));
+    assert!(messages
+        .iter()
+        .any(|m| m.contains(r#"invalid aria-live value "loud""#)));
+    // The assertive status region also gets the sparing-use reminder
+    assert_eq!(
+        results
+            .iter()
+            .filter(|r| r.severity == Severity::Info)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_assertive_live_region_is_info() {
+    let results = live_region_results("<div aria-live='assertive'>Breaking news</div>");
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].severity, Severity::Info);
+    assert!(results[0].message.contains("prefer \"polite\""));
+}
+
+fn role_required_messages(html: &str) -> Vec<String> {
+    let rule = Rule {
+        name: "role-required-attributes".to_string(),
+        rule_type: RuleType::Semantics,
+        severity: Severity::Error,
+        selector: "[role]".to_string(),
+        condition: "role-required-attributes".into(),
+        message: "Role is missing a required attribute".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    };
+    HtmlLinter::new(vec![rule], None)
+        .lint(html)
+        .unwrap()
+        .into_iter()
+        .map(|result| result.message)
+        .collect()
+}
+
+#[test]
+fn test_role_required_attributes() {
+    let html = r#"<div role="slider" aria-valuenow="5" aria-valuemin="0" aria-valuemax="10"></div><div role="combobox" aria-expanded="false"></div><nav role="navigation"></nav>"#;
+    assert!(role_required_messages(html).is_empty());
+
+    // Each missing attribute is its own result
+    let html = r#"<div role="slider" aria-valuenow="5"></div>"#;
+    assert_eq!(
+        role_required_messages(html),
+        vec![
+            "Role is missing a required attribute (role=\"slider\" requires aria-valuemin)",
+            "Role is missing a required attribute (role=\"slider\" requires aria-valuemax)",
+        ]
+    );
+
+    let html = r#"<div role="checkbox switch" aria-checked="">Subscribe</div><div role="scrollbar" aria-controls="main" aria-valuenow="0" aria-valuemin="0" aria-valuemax="100"></div>"#;
+    assert_eq!(
+        role_required_messages(html),
+        vec!["Role is missing a required attribute (role=\"checkbox\" requires aria-checked)"]
+    );
+}
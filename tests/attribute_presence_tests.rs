@@ -0,0 +1,160 @@
+mod common;
+
+use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+
+fn mutually_exclusive_rule(selector: &str, groups: &str) -> Rule {
+    common::rule(
+        RuleType::AttributePresence,
+        Severity::Warning,
+        selector,
+        "mutually-exclusive",
+        "Conflicting attributes",
+        &[("attribute_groups", groups)],
+    )
+}
+
+fn messages(rule: Rule, html: &str) -> Vec<String> {
+    HtmlLinter::new(vec![rule], None)
+        .lint(html)
+        .unwrap()
+        .into_iter()
+        .map(|result| result.message)
+        .collect()
+}
+
+#[test]
+fn test_mutually_exclusive_attributes() {
+    let rule = mutually_exclusive_rule("script", r#"[["async", "defer"]]"#);
+    let html = "<script src='a.js' async defer></script>
+        <script src='b.js' async></script>
+        <script src='c.js' defer></script>";
+    assert_eq!(
+        messages(rule, html),
+        vec!["Conflicting attributes (async and defer cannot be used together)"]
+    );
+}
+
+#[test]
+fn test_mutually_exclusive_attribute_values() {
+    let rule = mutually_exclusive_rule(
+        "*",
+        r#"[["aria-hidden=true", "tabindex=0"], ["hidden", "open"]]"#,
+    );
+    let html = "<body>
+        <a href='#' aria-hidden='true' tabindex='0'>One</a>
+        <a href='#' aria-hidden='true' tabindex='-1'>Two</a>
+        <a href='#' aria-hidden='false' tabindex='0'>Three</a>
+        <details hidden open aria-hidden='true' tabindex='0'></details>
+    </body>";
+    assert_eq!(
+        messages(rule, html),
+        vec![
+            "Conflicting attributes (aria-hidden=true and tabindex=0 cannot be used together)",
+            "Conflicting attributes (aria-hidden=true and tabindex=0 cannot be used together)",
+            "Conflicting attributes (hidden and open cannot be used together)",
+        ]
+    );
+}
+
+#[test]
+fn test_mutually_exclusive_invalid_options() {
+    let mut rule = mutually_exclusive_rule("script", r#"["async", "defer"]"#);
+    assert!(HtmlLinter::new(vec![rule.clone()], None)
+        .lint("<script async></script>")
+        .is_err());
+
+    rule.options.clear();
+    assert!(HtmlLinter::new(vec![rule], None)
+        .lint("<script async></script>")
+        .is_err());
+}
+
+fn required_when_parent_rule(selector: &str, options: &[(&str, &str)]) -> Rule {
+    common::rule(
+        RuleType::AttributePresence,
+        Severity::Warning,
+        selector,
+        "required-when-parent",
+        "Missing required attribute",
+        options,
+    )
+}
+
+#[test]
+fn test_required_when_parent() {
+    let rule = required_when_parent_rule(
+        "input",
+        &[
+            ("attribute", "required"),
+            ("parent_selector", "form[novalidate]"),
+        ],
+    );
+    let html = "<form novalidate><div><input name='a'><input name='b' required></div></form>
+        <form><input name='c'></form>";
+    let results = HtmlLinter::new(vec![rule], None).lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Missing required attribute (missing required attribute)"
+    );
+    assert!(results[0].source.contains("'a'"));
+}
+
+#[test]
+fn test_required_when_parent_depth() {
+    // table > tbody > tr > td puts the table three levels above each cell
+    let html = "<table class='data'><tr><td class='outer'>\
+        <table class='layout'><tr><td class='inner'>Nested</td></tr></table>\
+        </td><td headers='h1' class='ok'>Value</td></tr></table>";
+
+    let rule = required_when_parent_rule(
+        "td",
+        &[
+            ("attribute", "headers"),
+            ("parent_selector", "table.data"),
+            ("require_depth", "3"),
+        ],
+    );
+    let results = HtmlLinter::new(vec![rule], None).lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].source.contains("outer"));
+
+    // Without a depth the nested cell counts as inside the data table too
+    let rule = required_when_parent_rule(
+        "td",
+        &[("attribute", "headers"), ("parent_selector", "table.data")],
+    );
+    assert_eq!(
+        HtmlLinter::new(vec![rule], None).lint(html).unwrap().len(),
+        2
+    );
+}
+
+fn not_empty_rule(selector: &str, options: &[(&str, &str)]) -> Rule {
+    Rule {
+        name: "attribute-not-empty".to_string(),
+        condition: "attribute-not-empty".into(),
+        message: "Empty attribute".to_string(),
+        ..required_when_parent_rule(selector, options)
+    }
+}
+
+#[test]
+fn test_attribute_not_empty() {
+    let html = "<img src='a.png' alt=''><img src='b.png' alt='Logo'><img src='c.png'>";
+    let rule = not_empty_rule("img", &[("attribute", "alt")]);
+    // The image without an alt is left to alt-missing
+    assert_eq!(messages(rule, html), vec!["Empty attribute (alt is empty)"]);
+
+    let html = "<html lang=' '><body><p lang='fr'>Bonjour</p></body></html>";
+    let rule = not_empty_rule("[lang]", &[("attribute", "lang")]);
+    assert_eq!(messages(rule, html).len(), 1);
+    let rule = not_empty_rule(
+        "[lang]",
+        &[("attribute", "lang"), ("allow_whitespace_only", "true")],
+    );
+    assert!(messages(rule, html).is_empty());
+
+    let linter = HtmlLinter::new(vec![not_empty_rule("img", &[])], None);
+    assert!(linter.lint("<img alt=''>").is_err());
+}
@@ -0,0 +1,325 @@
+mod common;
+
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+
+fn attribute_rule(selector: &str, condition: &str, options: &[(&str, &str)]) -> Rule {
+    common::rule(
+        RuleType::AttributeValue,
+        Severity::Warning,
+        selector,
+        condition,
+        "Invalid attribute value",
+        options,
+    )
+}
+
+fn lint(rule: Rule, html: &str) -> Vec<LintResult> {
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+#[test]
+fn test_numeric_range_inclusive() {
+    let rule = attribute_rule(
+        "img",
+        "numeric-range",
+        &[("attribute", "width"), ("min", "1"), ("max", "4000")],
+    );
+    let html = "<img src='a.png' width='1'><img src='b.png' width='4000'><img src='c.png' width='4001'><img src='d.png' width='0'>";
+    let results = lint(rule, html);
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].message,
+        "Invalid attribute value (width=\"4001\" is outside [1, 4000])"
+    );
+    assert!(results[1].source.contains("d.png"));
+}
+
+#[test]
+fn test_numeric_range_exclusive_and_open_ended() {
+    let rule = attribute_rule(
+        "td",
+        "numeric-range",
+        &[
+            ("attribute", "colspan"),
+            ("max", "20"),
+            ("inclusive", "false"),
+        ],
+    );
+    let html = "<table><tr><td colspan='19'>A</td><td colspan='20'>B</td><td colspan='-5'>C</td></tr></table>";
+    let results = lint(rule, html);
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Invalid attribute value (colspan=\"20\" is outside (-∞, 20))"
+    );
+}
+
+#[test]
+fn test_numeric_range_ignores_missing_and_non_numeric() {
+    let rule = attribute_rule(
+        "img",
+        "numeric-range",
+        &[("attribute", "width"), ("min", "1"), ("max", "10")],
+    );
+    let html = "<img src='a.png'><img src='b.png' width='100%'><img src='c.png' width='auto'>";
+    assert!(lint(rule, html).is_empty());
+}
+
+#[test]
+fn test_numeric_range_invalid_options() {
+    let linter = HtmlLinter::new(
+        vec![attribute_rule("img", "numeric-range", &[("min", "1")])],
+        None,
+    );
+    assert!(linter.lint("<img src='a.png' width='5'>").is_err());
+
+    let linter = HtmlLinter::new(
+        vec![attribute_rule(
+            "img",
+            "numeric-range",
+            &[("attribute", "width"), ("max", "big")],
+        )],
+        None,
+    );
+    assert!(linter.lint("<img src='a.png' width='5'>").is_err());
+}
+
+#[test]
+fn test_integer_only() {
+    let rule = attribute_rule("td", "integer-only", &[("attribute", "rowspan")]);
+    let html = "<table><tr><td rowspan='2'>A</td><td rowspan='1.5'>B</td><td rowspan='two'>C</td></tr></table>";
+    let results = lint(rule, html);
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Invalid attribute value (rowspan=\"1.5\" is not an integer)"
+    );
+}
+
+#[test]
+fn test_reference_exists_for_label() {
+    let rule = attribute_rule("label", "reference-exists", &[("attribute", "for")]);
+    let html = "<label for='name'>Name</label><input id='name'><label for='email'>Email</label><label>Inline <input></label>";
+    let results = lint(rule, html);
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Invalid attribute value (no element with id=\"email\")"
+    );
+}
+
+#[test]
+fn test_reference_exists_id_list() {
+    let rule = attribute_rule(
+        "input",
+        "reference-exists",
+        &[("attribute", "aria-describedby")],
+    );
+    let html = "<p id='hint'>Hint</p><input name='a' aria-describedby='hint'><input name='b' aria-describedby='hint error other'>";
+    let results = lint(rule, html);
+    assert_eq!(results.len(), 1);
+    assert!(results[0].source.contains("error other"));
+    assert_eq!(
+        results[0].message,
+        "Invalid attribute value (no element with id=\"error other\")"
+    );
+}
+
+#[test]
+fn test_reference_exists_disallow_missing() {
+    let rule = attribute_rule(
+        "label",
+        "reference-exists",
+        &[("attribute", "for"), ("allow_missing", "false")],
+    );
+    let html = "<label for='name'>Name</label><input id='name'><label>Email</label>";
+    let results = lint(rule, html);
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Invalid attribute value (missing for attribute)"
+    );
+}
+
+#[test]
+fn test_reference_exists_custom_reference_attribute() {
+    let rule = attribute_rule(
+        "a",
+        "reference-exists",
+        &[
+            ("attribute", "data-target"),
+            ("reference_attribute", "name"),
+        ],
+    );
+    let html = "<a href='#' data-target='top'>Top</a><a href='#x' data-target='bottom'>Bottom</a><div name='top'></div>";
+    let results = lint(rule, html);
+    assert_eq!(results.len(), 1);
+    assert!(results[0].source.contains("bottom"));
+}
+
+#[test]
+fn test_url_scheme_absolute_urls() {
+    let rule = attribute_rule("a", "url-scheme", &[]);
+    let html = "<a href='https://example.com'>One</a>
+        <a href='mailto:team@example.com'>Two</a>
+        <a href='javascript:void(0)'>Three</a>
+        <a href='ftp://legacy.example.com'>Four</a>
+        <a href=' JavaScript:alert(1)'>Five</a>";
+    let messages: Vec<String> = lint(rule, html).into_iter().map(|r| r.message).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Invalid attribute value (the javascript: scheme is denied)",
+            "Invalid attribute value (the ftp: scheme is not allowed)",
+            "Invalid attribute value (the javascript: scheme is denied)",
+        ]
+    );
+}
+
+#[test]
+fn test_url_scheme_relative_urls_are_allowed() {
+    let rule = attribute_rule("a", "url-scheme", &[]);
+    let html = "<a href='/about'>About</a>
+        <a href='page.html?next=http://x'>Page</a>
+        <a href='#top'>Top</a>
+        <a href='//cdn.example.com/lib.js'>CDN</a>
+        <a>No href</a>";
+    assert!(lint(rule, html).is_empty());
+}
+
+#[test]
+fn test_url_scheme_custom_lists() {
+    let rule = attribute_rule(
+        "iframe",
+        "url-scheme",
+        &[
+            ("attribute", "src"),
+            ("allowed_schemes", r#"["https", "ftp", "data"]"#),
+            ("deny_schemes", r#"["data"]"#),
+        ],
+    );
+    let html = "<iframe src='data:text/html,<p>hi</p>'></iframe>
+        <iframe src='ftp://legacy.example.com'></iframe>
+        <iframe src='http://example.com'></iframe>";
+    let messages: Vec<String> = lint(rule, html).into_iter().map(|r| r.message).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Invalid attribute value (the data: scheme is denied)",
+            "Invalid attribute value (the http: scheme is not allowed)",
+        ]
+    );
+
+    let rule = attribute_rule("a", "url-scheme", &[("allowed_schemes", "https")]);
+    let result = HtmlLinter::new(vec![rule], None).lint("<a href='https://x'>x</a>");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_boolean_attribute_correct_usage() {
+    let html = "<form novalidate><input name='a' disabled><input name='b' disabled=''>\
+        <input name='c' disabled='disabled'><input name='d' type='checkbox' checked='Checked'>\
+        <select name='e' multiple required><option>One</option></select></form>";
+    assert!(lint(attribute_rule("*", "boolean-attribute", &[]), html).is_empty());
+}
+
+#[test]
+fn test_boolean_attribute_false_values() {
+    let html =
+        "<form><input name='a' disabled='false'><input name='b' type='checkbox' checked='0'>\
+        <input name='c' required='no'><textarea name='d' readonly='off'></textarea></form>";
+    let results = lint(attribute_rule("*", "boolean-attribute", &[]), html);
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            r#"Invalid attribute value (disabled="false" still enables disabled; remove the attribute to turn it off)"#,
+            r#"Invalid attribute value (checked="0" still enables checked; remove the attribute to turn it off)"#,
+            r#"Invalid attribute value (required="no" still enables required; remove the attribute to turn it off)"#,
+            r#"Invalid attribute value (readonly="off" still enables readonly; remove the attribute to turn it off)"#,
+        ]
+    );
+}
+
+#[test]
+fn test_boolean_attribute_other_values() {
+    let html = "<input name='a' autofocus='true'><select name='b' multiple='yes'></select>";
+    let results = lint(attribute_rule("*", "boolean-attribute", &[]), html);
+    assert_eq!(results.len(), 2);
+    assert!(results[0]
+        .message
+        .contains(r#"autofocus="true" is not a boolean value; use autofocus or autofocus="""#));
+    assert!(results[1].message.contains(r#"multiple="yes""#));
+}
+
+#[test]
+fn test_boolean_attribute_option() {
+    let html = "<input name='a' disabled='false' required='no'><video muted='false'></video>";
+    let rule = attribute_rule(
+        "*",
+        "boolean-attribute",
+        &[("attribute", "muted, disabled")],
+    );
+    let results = lint(rule, html);
+    assert_eq!(results.len(), 2);
+    assert!(results[0].message.contains("disabled=\"false\""));
+    assert!(results[1].message.contains("muted=\"false\""));
+}
+
+#[test]
+fn test_class_order_alphabetical() {
+    let html = "<div class='card active'>A</div><div class='active card'>B</div>";
+    let results = lint(attribute_rule("[class]", "class-order", &[]), html);
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        r#"Invalid attribute value (class names are not in alphabetical order; expected "active card")"#
+    );
+    let fix = results[0].fix_suggestion.as_ref().unwrap();
+    assert_eq!(
+        fix.replacement.as_deref(),
+        Some("<div class='active card'>")
+    );
+    assert!(fix.is_safe);
+}
+
+#[test]
+fn test_class_order_prefix_groups() {
+    let rule = attribute_rule(
+        "[class]",
+        "class-order",
+        &[
+            ("order", "custom"),
+            ("prefix_groups", r#"["sm:", "md:", "hover:"]"#),
+        ],
+    );
+    let html = r#"<p class="p-4 text-lg sm:p-2 md:p-1 hover:underline">A</p><p class="md:p-1 p-4 hover:underline sm:p-2">B</p>"#;
+    let results = lint(rule.clone(), html);
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.ends_with(
+        r#"(class names are not in custom order; expected "p-4 sm:p-2 md:p-1 hover:underline")"#
+    ));
+
+    // The fix rewrites the tag as written, double quotes included
+    let linter = HtmlLinter::new(vec![rule], None);
+    let fixed = linter.apply_fixes(html, &results);
+    assert!(fixed.ends_with(r#"<p class="p-4 sm:p-2 md:p-1 hover:underline">B</p>"#));
+    assert!(linter.lint(&fixed).unwrap().is_empty());
+
+    // Class names written with character references can't be reordered verbatim
+    let html = "<p class='hover:underline p-&#52;'>A</p>";
+    let results = linter.lint(html).unwrap();
+    let fix = results[0].fix_suggestion.as_ref().unwrap();
+    assert!(!fix.is_safe);
+    assert_eq!(fix.replacement, None);
+
+    let linter = HtmlLinter::new(
+        vec![attribute_rule(
+            "[class]",
+            "class-order",
+            &[("order", "custom")],
+        )],
+        None,
+    );
+    assert!(linter.lint("<p class='a'></p>").is_err());
+}
@@ -0,0 +1,64 @@
+use html_linter::{HtmlLinter, LinterError, Rule, RuleType, Severity};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+
+fn img_alt_linter() -> HtmlLinter {
+    let rule = Rule {
+        name: "img-alt".to_string(),
+        rule_type: RuleType::AttributePresence,
+        severity: Severity::Error,
+        selector: "img".to_string(),
+        condition: "alt-missing".into(),
+        message: "Images must have alt attributes".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    };
+    HtmlLinter::new(vec![rule], None)
+}
+
+#[test]
+fn test_lint_batch_preserves_order() {
+    let pages: Vec<(String, String)> = (0..50)
+        .map(|i| {
+            let images = "<img src='a.png'>".repeat(i % 4);
+            (format!("page-{}", i), format!("<body>{}</body>", images))
+        })
+        .collect();
+    let documents: Vec<(&str, &str)> = pages
+        .iter()
+        .map(|(id, html)| (id.as_str(), html.as_str()))
+        .collect();
+
+    let linter = img_alt_linter();
+    let results = linter.lint_batch(&documents);
+
+    assert_eq!(results.len(), documents.len());
+    for ((id, result), (expected_id, html)) in results.iter().zip(&documents) {
+        assert_eq!(id, expected_id);
+        assert_eq!(
+            result.as_ref().unwrap().len(),
+            linter.lint(html).unwrap().len()
+        );
+    }
+    assert_eq!(results[3].1.as_ref().unwrap().len(), 3);
+}
+
+#[test]
+fn test_lint_batch_files() {
+    let mut with_image = tempfile::NamedTempFile::new().unwrap();
+    write!(with_image, "<body><img src='a.png'></body>").unwrap();
+    let mut clean = tempfile::NamedTempFile::new().unwrap();
+    write!(clean, "<body><p>Text</p></body>").unwrap();
+    let missing = Path::new("non_existent_file.html");
+
+    let paths = [with_image.path(), missing, clean.path()];
+    let results = img_alt_linter().lint_batch_files(&paths);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].0, with_image.path());
+    assert_eq!(results[0].1.as_ref().unwrap().len(), 1);
+    assert_eq!(results[1].0, missing);
+    assert!(matches!(results[1].1, Err(LinterError::IoError(_))));
+    assert!(results[2].1.as_ref().unwrap().is_empty());
+}
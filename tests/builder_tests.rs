@@ -0,0 +1,71 @@
+mod common;
+
+use html_linter::{HtmlLinter, HtmlLinterBuilder, Rule, RuleType, Severity};
+
+fn img_alt_rule() -> Rule {
+    Rule {
+        name: "img-alt".to_string(),
+        ..common::rule(
+            RuleType::AttributePresence,
+            Severity::Error,
+            "img",
+            "alt-missing",
+            "Images must have alt attributes",
+            &[],
+        )
+    }
+}
+
+fn inline_style_rule() -> Rule {
+    Rule {
+        name: "no-inline-styles".to_string(),
+        ..common::rule(
+            RuleType::AttributePresence,
+            Severity::Warning,
+            "*",
+            "style-attribute",
+            "Inline styles should be avoided",
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_builder_composes_rules() {
+    let linter = HtmlLinterBuilder::new()
+        .rule(img_alt_rule())
+        .rules(vec![inline_style_rule()])
+        .build();
+
+    assert_eq!(linter.get_rules().len(), 2);
+
+    let html = r#"<img src="test.jpg" style="border: 0">"#;
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 2);
+}
+
+#[test]
+fn test_builder_options() {
+    let linter = HtmlLinter::builder()
+        .rules(vec![img_alt_rule(), inline_style_rule()])
+        .option(|options| options.allow_inline_styles = true)
+        .build();
+
+    let html = r#"<img src="test.jpg" alt="Test" style="border: 0">"#;
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 0);
+}
+
+#[test]
+fn test_builder_ignore_rule() {
+    let linter = HtmlLinterBuilder::default()
+        .rule(img_alt_rule())
+        .rule(inline_style_rule())
+        .ignore_rule("img-alt")
+        .build();
+
+    let html = r#"<img src="test.jpg" style="border: 0">"#;
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].rule, "no-inline-styles");
+}
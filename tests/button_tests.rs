@@ -9,7 +9,7 @@ fn setup_button_rules() -> Vec<Rule> {
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "button".to_string(),
-            condition: "explicit-type".to_string(),
+            condition: "explicit-type".into(),
             message: "Buttons should have an explicit type attribute".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -21,13 +21,14 @@ fn setup_button_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "type".to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "button-accessible-name".to_string(),
             rule_type: RuleType::Compound,
             severity: Severity::Error,
             selector: "button".to_string(),
-            condition: "any-condition-met".to_string(),
+            condition: "any-condition-met".into(),
             message: "Buttons must have an accessible name via text content, aria-label, or aria-labelledby".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -50,13 +51,14 @@ fn setup_button_rules() -> Vec<Rule> {
                 ]).to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "button-no-disabled".to_string(),
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "button[disabled]".to_string(),
-            condition: "aria-disabled".to_string(),
+            condition: "aria-disabled".into(),
             message: "Consider using aria-disabled instead of disabled attribute".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -65,6 +67,7 @@ fn setup_button_rules() -> Vec<Rule> {
                 options.insert("pattern".to_string(), r#".*"#.to_string());
                 options
             },
+            ..Default::default()
         },
     ]
 }
@@ -222,7 +225,7 @@ fn test_button_with_nonexistent_labelledby() {
         rule_type: RuleType::Compound,
         severity: Severity::Error,
         selector: "button".to_string(),
-        condition: "any-condition-met".to_string(),
+        condition: "any-condition-met".into(),
         message:
             "Buttons must have an accessible name via text content, aria-label, or aria-labelledby"
                 .to_string(),
@@ -252,6 +255,7 @@ fn test_button_with_nonexistent_labelledby() {
             );
             options
         },
+        ..Default::default()
     };
 
     let linter = HtmlLinter::new(vec![rule], None);
@@ -321,7 +325,7 @@ fn test_button_weighted_conditions() {
         rule_type: RuleType::Compound,
         severity: Severity::Warning,
         selector: "button".to_string(),
-        condition: "weighted-conditions".to_string(),
+        condition: "weighted-conditions".into(),
         message: "Button should meet weighted accessibility requirements".to_string(),
         options: {
             let mut options = HashMap::new();
@@ -350,6 +354,7 @@ fn test_button_weighted_conditions() {
             );
             options
         },
+        ..Default::default()
     });
 
     let linter = HtmlLinter::new(rules, None);
@@ -379,7 +384,7 @@ fn test_button_dependency_chain() {
         rule_type: RuleType::Compound,
         severity: Severity::Warning,
         selector: "button".to_string(),
-        condition: "dependency-chain".to_string(),
+        condition: "dependency-chain".into(),
         message: "Button should follow progressive enhancement pattern".to_string(),
         options: {
             let mut options = HashMap::new();
@@ -406,6 +411,7 @@ fn test_button_dependency_chain() {
             );
             options
         },
+        ..Default::default()
     });
 
     let linter = HtmlLinter::new(rules, None);
@@ -437,7 +443,7 @@ fn test_button_alternating_pattern() {
         rule_type: RuleType::Compound,
         severity: Severity::Warning,
         selector: "button".to_string(),
-        condition: "alternating-pattern".to_string(),
+        condition: "alternating-pattern".into(),
         message: "Button should alternate between icon and text".to_string(),
         options: {
             let mut options = HashMap::new();
@@ -459,6 +465,7 @@ fn test_button_alternating_pattern() {
             );
             options
         },
+        ..Default::default()
     });
 
     let linter = HtmlLinter::new(rules, None);
@@ -486,7 +493,7 @@ fn test_button_subset_match() {
         rule_type: RuleType::Compound,
         severity: Severity::Warning,
         selector: "button".to_string(),
-        condition: "valid-combinations".to_string(),
+        condition: "valid-combinations".into(),
         message: "Button should use valid combination of attributes".to_string(),
         options: {
             let mut options = HashMap::new();
@@ -527,6 +534,7 @@ fn test_button_subset_match() {
             );
             options
         },
+        ..Default::default()
     });
 
     let linter = HtmlLinter::new(rules, None);
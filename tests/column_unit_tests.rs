@@ -0,0 +1,66 @@
+mod common;
+
+use html_linter::{ColumnUnit, HtmlLinter, LinterOptions, Rule, RuleType, Severity};
+
+fn img_alt_rule() -> Rule {
+    Rule {
+        name: "img-alt".to_string(),
+        ..common::rule(
+            RuleType::AttributePresence,
+            Severity::Error,
+            "img",
+            "alt-missing",
+            "Images must have alt attributes",
+            &[],
+        )
+    }
+}
+
+fn img_column(html: &str, column_unit: Option<ColumnUnit>) -> usize {
+    let options = column_unit.map(|column_unit| LinterOptions {
+        column_unit,
+        ..Default::default()
+    });
+    let linter = HtmlLinter::new(vec![img_alt_rule()], options);
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    results[0].location.column
+}
+
+#[test]
+fn test_column_defaults_to_chars() {
+    let html = "<html><body>\n<p>你好</p><img src='a.png'>\n</body></html>";
+    assert_eq!(img_column(html, None), 10);
+}
+
+#[test]
+fn test_column_units_with_cjk() {
+    // "你好" is 2 chars, 2 UTF-16 units and 6 bytes
+    let html = "<html><body>\n<p>你好</p><img src='a.png'>\n</body></html>";
+    assert_eq!(img_column(html, Some(ColumnUnit::Chars)), 10);
+    assert_eq!(img_column(html, Some(ColumnUnit::Utf16Units)), 10);
+    assert_eq!(img_column(html, Some(ColumnUnit::Bytes)), 14);
+}
+
+#[test]
+fn test_column_units_with_emoji() {
+    // "😀" is 1 char, a surrogate pair in UTF-16 and 4 bytes
+    let html = "<html><body>\n<p>😀</p><img src='a.png'>\n</body></html>";
+    assert_eq!(img_column(html, Some(ColumnUnit::Chars)), 9);
+    assert_eq!(img_column(html, Some(ColumnUnit::Utf16Units)), 10);
+    assert_eq!(img_column(html, Some(ColumnUnit::Bytes)), 12);
+}
+
+#[test]
+fn test_context_caret_with_emoji() {
+    let html = "<html><body>\n<p>😀</p><img src='a.png'>\n</body></html>";
+    for unit in [ColumnUnit::Bytes, ColumnUnit::Chars, ColumnUnit::Utf16Units] {
+        let options = LinterOptions {
+            column_unit: unit,
+            ..Default::default()
+        };
+        let linter = HtmlLinter::new(vec![img_alt_rule()], Some(options));
+        let results = linter.lint(html).unwrap();
+        assert!(results[0].context.contains("  |         ^"), "{:?}", unit);
+    }
+}
@@ -0,0 +1,26 @@
+use html_linter::{Rule, RuleType, Severity};
+
+/// A rule named after its `condition`, with `options` given as key-value pairs. Tests set
+/// any other field with struct update syntax.
+pub fn rule(
+    rule_type: RuleType,
+    severity: Severity,
+    selector: &str,
+    condition: &str,
+    message: &str,
+    options: &[(&str, &str)],
+) -> Rule {
+    Rule {
+        name: condition.to_string(),
+        rule_type,
+        severity,
+        selector: selector.to_string(),
+        condition: condition.into(),
+        message: message.to_string(),
+        options: options
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,244 @@
+mod common;
+
+use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+use serde_json::json;
+
+fn compound_rule(selector: &str, conditions: serde_json::Value) -> Rule {
+    common::rule(
+        RuleType::Compound,
+        Severity::Error,
+        selector,
+        "compound",
+        "Compound condition failed",
+        &[
+            ("check_mode", "all"),
+            ("conditions", &conditions.to_string()),
+        ],
+    )
+}
+
+#[test]
+fn test_child_presence_min_count() {
+    let rule = compound_rule(
+        "ul",
+        json!([{ "type": "ChildPresence", "selector": "li", "min_count": 2 }]),
+    );
+    let linter = HtmlLinter::new(vec![rule], None);
+
+    let results = linter.lint("<ul><li>One</li><li>Two</li></ul>").unwrap();
+    assert_eq!(results.len(), 0);
+
+    let results = linter.lint("<ul><li>Only one</li></ul>").unwrap();
+    assert_eq!(results.len(), 1);
+}
+
+#[test]
+fn test_child_presence_max_count() {
+    let rule = compound_rule(
+        "select",
+        json!([{ "type": "ChildPresence", "selector": "option", "max_count": 2 }]),
+    );
+    let linter = HtmlLinter::new(vec![rule], None);
+
+    let results = linter
+        .lint("<select><option>1</option><option>2</option></select>")
+        .unwrap();
+    assert_eq!(results.len(), 0);
+
+    let results = linter
+        .lint("<select><option>1</option><option>2</option><option>3</option></select>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+}
+
+#[test]
+fn test_child_presence_ignores_grandchildren() {
+    let rule = compound_rule(
+        "div.outer",
+        json!([{ "type": "ChildPresence", "selector": "p", "min_count": 1 }]),
+    );
+    let linter = HtmlLinter::new(vec![rule], None);
+
+    let results = linter
+        .lint(r#"<div class="outer"><div><p>Nested</p></div></div>"#)
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = linter
+        .lint(r#"<div class="outer"><p>Direct</p></div>"#)
+        .unwrap();
+    assert_eq!(results.len(), 0);
+}
+
+#[test]
+fn test_ancestor_presence_required() {
+    let rule = compound_rule(
+        "input",
+        json!([{ "type": "AncestorPresence", "selector": "label", "required": true }]),
+    );
+    let linter = HtmlLinter::new(vec![rule], None);
+
+    let results = linter
+        .lint("<form><label>Name <span><input type='text'></span></label></form>")
+        .unwrap();
+    assert_eq!(results.len(), 0);
+
+    let results = linter.lint("<form><input type='text'></form>").unwrap();
+    assert_eq!(results.len(), 1);
+}
+
+#[test]
+fn test_ancestor_presence_forbidden() {
+    let rule = compound_rule(
+        "button",
+        json!([{ "type": "AncestorPresence", "selector": "a", "required": false }]),
+    );
+    let linter = HtmlLinter::new(vec![rule], None);
+
+    let results = linter
+        .lint("<a href='/home'><button>Home</button></a>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = linter.lint("<div><button>Home</button></div>").unwrap();
+    assert_eq!(results.len(), 0);
+}
+
+#[test]
+fn test_descendant_presence_range() {
+    let rule = compound_rule(
+        "form",
+        json!([{ "type": "DescendantPresence", "selector": "input", "min_count": 1, "max_count": 3 }]),
+    );
+    let linter = HtmlLinter::new(vec![rule], None);
+
+    let results = linter
+        .lint("<form><fieldset><label>Name <input type='text'></label></fieldset></form>")
+        .unwrap();
+    assert_eq!(results.len(), 0);
+
+    let results = linter.lint("<form><button>Send</button></form>").unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = linter
+        .lint("<form><input name='a'><input name='b'><div><input name='c'><input name='d'></div></form>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+}
+
+#[test]
+fn test_descendant_presence_scoped_to_node() {
+    let rule = compound_rule(
+        "nav",
+        json!([{ "type": "DescendantPresence", "selector": "a", "min_count": 2 }]),
+    );
+    let linter = HtmlLinter::new(vec![rule], None);
+
+    // Links outside the nav don't count towards it
+    let results = linter
+        .lint("<nav><ul><li><a href='/'>Home</a></li></ul></nav><footer><a href='/about'>About</a></footer>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = linter
+        .lint(
+            "<nav><ul><li><a href='/'>Home</a></li><li><a href='/about'>About</a></li></ul></nav>",
+        )
+        .unwrap();
+    assert_eq!(results.len(), 0);
+}
+
+#[test]
+fn test_element_presence_scoped_to_node() {
+    let rule = compound_rule(
+        "div",
+        json!([{ "type": "ElementPresence", "selector": "p" }]),
+    );
+    let linter = HtmlLinter::new(vec![rule], None);
+
+    let results = linter
+        .lint("<div class='card'><section><p>Inside</p></section></div>")
+        .unwrap();
+    assert_eq!(results.len(), 0);
+
+    // Sibling and parent paragraphs don't count for the div
+    let results = linter
+        .lint("<p>Before</p><div class='card'><span>Empty</span></div><p>After</p>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let results = linter
+        .lint("<p><span>Outer</span></p><section><div class='card'></div><p>Next</p></section>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+}
+
+#[test]
+fn test_nesting_depth_range() {
+    // html(1) > body(2) > section(3..6) > div(7)
+    let html = "<html><body><div id='shallow'>Top</div><section><section><section><section><div id='deep'>Deep</div></section></section></section></section></body></html>";
+
+    let rule = compound_rule("div", json!([{ "type": "NestingDepth", "max": 6 }]));
+    let results = HtmlLinter::new(vec![rule], None).lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].source.contains("deep"));
+
+    let rule = compound_rule("div", json!([{ "type": "NestingDepth", "min": 4 }]));
+    let results = HtmlLinter::new(vec![rule], None).lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].source.contains("shallow"));
+}
+
+fn counted_rule(check_mode: &str, required_count: &str) -> Rule {
+    let mut rule = compound_rule(
+        "img",
+        json!([
+            { "type": "AttributeValue", "attribute": "alt", "pattern": ".+" },
+            { "type": "AttributeValue", "attribute": "width", "pattern": "^\\d+$" },
+            { "type": "AttributeValue", "attribute": "height", "pattern": "^\\d+$" },
+            { "type": "AttributeValue", "attribute": "loading", "pattern": "^lazy$" }
+        ]),
+    );
+    rule.options
+        .insert("check_mode".to_string(), check_mode.to_string());
+    rule.options
+        .insert("required_count".to_string(), required_count.to_string());
+    rule
+}
+
+#[test]
+fn test_n_of_m_check_mode() {
+    let linter = HtmlLinter::new(vec![counted_rule("n_of_m", "3")], None);
+
+    let html = "<img src='a.png' alt='Chart' width='100' height='50'>";
+    assert_eq!(linter.lint(html).unwrap().len(), 0);
+
+    let html = "<img src='a.png' alt='Chart' width='100' height='50' loading='lazy'>";
+    assert_eq!(linter.lint(html).unwrap().len(), 0);
+
+    let results = linter
+        .lint("<img src='a.png' alt='Chart' width='100'>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0]
+        .message
+        .contains("Only 2 of 4 conditions met; at least 3 required"));
+}
+
+#[test]
+fn test_exactly_n_check_mode() {
+    let linter = HtmlLinter::new(vec![counted_rule("exactly_n", "3")], None);
+
+    let html = "<img src='a.png' alt='Chart' width='100' height='50'>";
+    assert_eq!(linter.lint(html).unwrap().len(), 0);
+
+    let html = "<img src='a.png' alt='Chart' width='100' height='50' loading='lazy'>";
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0]
+        .message
+        .contains("4 of 4 conditions met; exactly 3 required"));
+
+    let rule = counted_rule("exactly_n", "three");
+    assert!(HtmlLinter::new(vec![rule], None).lint(html).is_err());
+}
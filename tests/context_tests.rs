@@ -0,0 +1,122 @@
+mod common;
+
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+
+fn context_rule(condition: &str) -> Rule {
+    common::rule(
+        RuleType::Semantics,
+        Severity::Error,
+        "*",
+        condition,
+        "Element is outside its required context",
+        &[],
+    )
+}
+
+fn lint(condition: &str, html: &str) -> Vec<LintResult> {
+    HtmlLinter::new(vec![context_rule(condition)], None)
+        .lint(html)
+        .unwrap()
+}
+
+#[test]
+fn test_list_context_valid_nesting() {
+    let html = "<ul><li>One</li></ul><ol><li>Two</li></ol><menu><li>Three</li></menu>\
+        <dl><dt>Term</dt><dd>Definition</dd><div><dt>Grouped</dt><dd>Value</dd></div></dl>";
+    assert!(lint("list-context", html).is_empty());
+}
+
+#[test]
+fn test_list_item_outside_list() {
+    let html = "<div><li class='stray'>Stray</li></div><ul><li>Fine</li></ul>";
+    let results = lint("list-context", html);
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Element is outside its required context (<li> must be a direct child of <ul>, <ol>, <menu>, not <div>)"
+    );
+    assert!(results[0].source.contains("stray"));
+}
+
+#[test]
+fn test_description_terms_outside_dl() {
+    let html = "<section><dt>Term</dt><dd>Definition</dd></section>\
+        <div><div><dd class='deep'>Not in a dl</dd></div></div>";
+    let messages: Vec<String> = lint("list-context", html)
+        .into_iter()
+        .map(|result| result.message)
+        .collect();
+    assert_eq!(messages.len(), 3);
+    assert!(messages[0].contains("<dt> must be a direct child of <dl>, not <section>"));
+    assert!(messages[1].contains("<dd> must be a direct child of <dl>, not <section>"));
+    assert!(messages[2].contains("<dd> must be a direct child of <dl>, not <div>"));
+}
+
+#[test]
+fn test_table_context_valid_nesting() {
+    let html = "<table><thead><tr><th>Day</th></tr></thead><tbody><tr><td>Mon</td></tr></tbody>\
+        <tfoot><tr><td>Total</td></tr></tfoot></table>";
+    assert!(lint("table-context", html).is_empty());
+}
+
+#[test]
+fn test_table_context_misplaced_cells() {
+    // The HTML parser inserts a tr around the first cell and drops the second, so both are
+    // found in the source
+    let html = "<table><td>Cell</td></table>\n<div><td class='dropped'>Dropped</td></div>\
+        <svg><td>Not a table cell</td></svg>";
+    let results = lint("table-context", html);
+    let messages: Vec<&str> = results
+        .iter()
+        .map(|result| result.message.as_str())
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Element is outside its required context (<td> must be a direct child of <tr>, not <table>)",
+            "Element is outside its required context (<td> must be a direct child of <tr>, not <div>)",
+        ]
+    );
+    assert_eq!(
+        (results[1].location.line, results[1].location.column),
+        (2, 6)
+    );
+    assert_eq!(results[1].source, "<td class='dropped'>");
+}
+
+#[test]
+fn test_table_context_rows() {
+    // Rows may sit directly in a table, but not in a cell or at the top of the body
+    let html = "<table><tr><td>Fine</td></tr></table><tr><td>Stray</td></tr>";
+    let messages: Vec<String> = lint("table-context", html)
+        .into_iter()
+        .map(|result| result.message)
+        .collect();
+    assert_eq!(
+        messages,
+        vec!["Element is outside its required context (<tr> must be a direct child of <thead>, <tbody>, <tfoot>, <table>, not <body>)"]
+    );
+
+    let linter = HtmlLinter::new(vec![context_rule("table-context")], None);
+    assert!(linter
+        .lint_fragment("<tr><td>Top level</td></tr>")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_context_in_fragments() {
+    let linter = HtmlLinter::new(vec![context_rule("list-context")], None);
+    // A fragment's real parent is unknown, so its top-level items are not reported
+    assert!(linter
+        .lint_fragment("<li>One</li><li>Two</li>")
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        linter
+            .lint_fragment("<div><li>Inside a div</li></div>")
+            .unwrap()
+            .len(),
+        1
+    );
+}
@@ -0,0 +1,145 @@
+mod common;
+
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+
+fn deprecated_rule(options: &[(&str, &str)]) -> Rule {
+    common::rule(
+        RuleType::Semantics,
+        Severity::Warning,
+        "*",
+        "deprecated-attributes",
+        "Deprecated attribute",
+        options,
+    )
+}
+
+fn lint(rule: Rule, html: &str) -> Vec<LintResult> {
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+#[test]
+fn test_deprecated_attributes_reported() {
+    let html = "<html><body bgcolor='white'><table border='1'><tr><td>A</td></tr></table><ul type='disc'><li>B</li></ul><script language='javascript'></script></body></html>";
+    let messages: Vec<String> = lint(deprecated_rule(&[]), html)
+        .into_iter()
+        .map(|result| result.message)
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "The `bgcolor` attribute on `body` is deprecated; use CSS instead",
+            "The `border` attribute on `table` is deprecated; use CSS instead",
+            "The `type` attribute on `ul` is deprecated; use CSS instead",
+            "The `language` attribute on `script` is deprecated; use the type attribute instead",
+        ]
+    );
+}
+
+#[test]
+fn test_wildcard_and_valid_attributes() {
+    let html = "<body><p align='center'>Centered</p><ol type='a'><li>A</li></ol><img src='a.png' alt='A' width='10'></body>";
+    let results = lint(deprecated_rule(&[]), html);
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "p");
+}
+
+#[test]
+fn test_additional_deprecated_attributes() {
+    let rule = deprecated_rule(&[(
+        "additional_deprecated",
+        r#"[{"element": "div", "attribute": "data-legacy"}]"#,
+    )]);
+    let results = lint(
+        rule,
+        "<body><div data-legacy='x'>A</div><div>B</div></body>",
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "The `data-legacy` attribute on `div` is deprecated; use CSS instead"
+    );
+}
+
+#[test]
+fn test_invalid_additional_deprecated_json() {
+    let linter = HtmlLinter::new(
+        vec![deprecated_rule(&[("additional_deprecated", "not json")])],
+        None,
+    );
+    assert!(linter.lint("<body></body>").is_err());
+}
+
+fn usage_rule(options: &[(&str, &str)]) -> Rule {
+    Rule {
+        name: "deprecated-usage".to_string(),
+        condition: "deprecated-usage".into(),
+        message: "Deprecated element usage".to_string(),
+        ..deprecated_rule(options)
+    }
+}
+
+#[test]
+fn test_deprecated_usage_reported() {
+    let html = "<body><p><b>Bold</b> and <i>italic</i> and <strong>strong</strong></p><hr><hr style='border: 2px dashed'><hr class='fancy-divider'></body>";
+    let messages: Vec<String> = lint(usage_rule(&[]), html)
+        .into_iter()
+        .map(|result| result.message)
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "Consider using `<strong>` instead of `<b>` for semantic emphasis",
+            "Consider using `<em>` instead of `<i>` for semantic emphasis",
+            "`<hr>` marks a thematic break but is styled inline; use a CSS border for decoration",
+            "`<hr>` marks a thematic break but has the decorative class `fancy-divider`; use a CSS border for decoration",
+        ]
+    );
+}
+
+#[test]
+fn test_semantic_elements_messages() {
+    let rule = Rule {
+        name: "semantic-html".to_string(),
+        selector: "body".to_string(),
+        condition: "semantic-elements".into(),
+        message: "Use semantic elements".to_string(),
+        ..deprecated_rule(&[])
+    };
+    let messages: Vec<String> = lint(rule, "<body><b>Bold</b><i>Term</i></body>")
+        .into_iter()
+        .map(|result| result.message)
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "Use <strong> for strong importance",
+            "Use <em> for emphasized text",
+        ]
+    );
+}
+
+#[test]
+fn test_deprecated_map_option() {
+    let rule = usage_rule(&[("deprecated_map", r#"{"s": "del", "b": "mark"}"#)]);
+    let messages: Vec<String> = lint(rule, "<body><s>Old</s><b>Key</b><i>Term</i></body>")
+        .into_iter()
+        .map(|result| result.message)
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "Consider using `<del>` instead of `<s>`",
+            "Consider using `<mark>` instead of `<b>`",
+            "Consider using `<em>` instead of `<i>` for semantic emphasis",
+        ]
+    );
+
+    let linter = HtmlLinter::new(vec![usage_rule(&[("deprecated_map", "[]")])], None);
+    assert!(linter.lint("<body></body>").is_err());
+}
@@ -0,0 +1,142 @@
+use html_linter::{HtmlLinter, LintResult, LinterOptions, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn setup_linter() -> HtmlLinter {
+    let rules = vec![
+        Rule {
+            name: "img-alt".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Error,
+            selector: "img".to_string(),
+            condition: "alt-missing".into(),
+            message: "Images must have alt attributes".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+        Rule {
+            name: "no-marquee".to_string(),
+            rule_type: RuleType::ElementPresence,
+            severity: Severity::Warning,
+            selector: "marquee".to_string(),
+            condition: "forbidden".into(),
+            message: "Avoid the marquee element".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+    ];
+    HtmlLinter::new(rules, None)
+}
+
+fn lines_for(results: &[LintResult], rule: &str) -> Vec<usize> {
+    results
+        .iter()
+        .filter(|r| r.rule == rule)
+        .map(|r| r.location.line)
+        .collect()
+}
+
+#[test]
+fn test_disable_and_enable_region() {
+    let html = "<body>
+<img src='one.jpg'>
+<!-- html-linter-disable img-alt -->
+<img src='two.jpg'>
+<marquee>Still reported</marquee>
+<!-- html-linter-enable img-alt -->
+<img src='three.jpg'>
+</body>";
+
+    let results = setup_linter().lint(html).unwrap();
+    assert_eq!(lines_for(&results, "img-alt"), vec![2, 7]);
+    assert_eq!(lines_for(&results, "no-marquee"), vec![5]);
+}
+
+#[test]
+fn test_disable_without_enable_runs_to_end() {
+    let html = "<body>
+<img src='one.jpg'>
+<!-- html-linter-disable img-alt, no-marquee -->
+<img src='two.jpg'>
+<marquee>Hidden</marquee>
+</body>";
+
+    let results = setup_linter().lint(html).unwrap();
+    assert_eq!(lines_for(&results, "img-alt"), vec![2]);
+    assert!(lines_for(&results, "no-marquee").is_empty());
+}
+
+#[test]
+fn test_disable_all_rules() {
+    let html = "<body>
+<!-- html-linter-disable -->
+<img src='one.jpg'>
+<marquee>Hidden</marquee>
+<!-- html-linter-enable -->
+<img src='two.jpg'>
+</body>";
+
+    let results = setup_linter().lint(html).unwrap();
+    assert_eq!(lines_for(&results, "img-alt"), vec![6]);
+    assert!(lines_for(&results, "no-marquee").is_empty());
+}
+
+#[test]
+fn test_disable_next_line() {
+    let html = "<body>
+<!-- html-linter-disable-next-line img-alt -->
+<img src='one.jpg'>
+<img src='two.jpg'>
+</body>";
+
+    let results = setup_linter().lint(html).unwrap();
+    assert_eq!(lines_for(&results, "img-alt"), vec![4]);
+}
+
+#[test]
+fn test_configure_severity() {
+    let html = "<body>
+<img src='one.jpg'>
+<!-- html-linter-configure img-alt severity=warning -->
+<img src='two.jpg'>
+<marquee>Unchanged</marquee>
+<!-- html-linter-configure severity=info -->
+<img src='three.jpg'>
+<marquee class='late'>Info</marquee>
+</body>";
+
+    let results = setup_linter().lint(html).unwrap();
+    let severities: Vec<(usize, Severity)> = results
+        .iter()
+        .map(|r| (r.location.line, r.severity.clone()))
+        .collect();
+    assert!(severities.contains(&(2, Severity::Error)));
+    assert!(severities.contains(&(4, Severity::Warning)));
+    assert!(severities.contains(&(5, Severity::Warning)));
+    // The rule-specific directive wins over one for every rule
+    assert!(severities.contains(&(7, Severity::Warning)));
+    assert!(severities.contains(&(8, Severity::Info)));
+}
+
+#[test]
+fn test_comment_directives_can_be_disabled() {
+    let html = "<body>
+<!-- html-linter-disable -->
+<img src='one.jpg'>
+<!-- html-linter-configure no-marquee severity=info -->
+<marquee>Reported</marquee>
+</body>";
+
+    let options = LinterOptions {
+        enable_comment_directives: false,
+        ..LinterOptions::default()
+    };
+    let linter = HtmlLinter::new(setup_linter().get_rules(), Some(options));
+    let results = linter.lint(html).unwrap();
+    assert_eq!(lines_for(&results, "img-alt"), vec![3]);
+    assert_eq!(lines_for(&results, "no-marquee"), vec![5]);
+    assert!(results
+        .iter()
+        .all(|r| r.rule != "no-marquee" || r.severity == Severity::Warning));
+
+    assert!(setup_linter().lint(html).unwrap().is_empty());
+}
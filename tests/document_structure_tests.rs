@@ -0,0 +1,83 @@
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn lint(condition: &str, html: &str) -> Vec<LintResult> {
+    let rule = Rule {
+        name: condition.to_string(),
+        rule_type: RuleType::DocumentStructure,
+        severity: Severity::Error,
+        selector: "html".to_string(),
+        condition: condition.into(),
+        message: "Invalid document structure".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    };
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+#[test]
+fn test_required_structure_valid() {
+    let html =
+        "<!DOCTYPE html>\n<html>\n<head><title>T</title></head>\n<body><p>Hi</p></body>\n</html>";
+    assert!(lint("required-structure", html).is_empty());
+
+    // Optional tags may be omitted entirely
+    assert!(lint(
+        "required-structure",
+        "<!DOCTYPE html><title>T</title><p>Hi</p>"
+    )
+    .is_empty());
+}
+
+#[test]
+fn test_body_before_head() {
+    let html = "<html>\n<body><p>Hi</p></body>\n<head><title>T</title></head>\n</html>";
+    let results = lint("required-structure", html);
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Invalid document structure (<head> must come before <body>)"
+    );
+    assert_eq!(results[0].location.line, 3);
+    assert_eq!(results[0].source, "<head>");
+}
+
+#[test]
+fn test_duplicate_head_and_body() {
+    let html =
+        "<html>\n<head></head>\n<head></head>\n<body></body>\n<body class='x'></body>\n</html>";
+    let results = lint("required-structure", html);
+
+    let messages: Vec<(usize, &str)> = results
+        .iter()
+        .map(|result| (result.location.line, result.message.as_str()))
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            (
+                3,
+                "Invalid document structure (<head> appears more than once)"
+            ),
+            (
+                5,
+                "Invalid document structure (<body> appears more than once)"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_no_content_before_head() {
+    let html = "<!DOCTYPE html>\n<html>\n<!-- <p>comment</p> -->\n<div>Early</div>\n<head><title>T</title></head>\n</html>";
+    let results = lint("no-content-before-head", html);
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.line, 4);
+    assert_eq!(results[0].location.element, "div");
+
+    let html =
+        "<!DOCTYPE html><html><head><script>var s = '<b>';</script></head><body></body></html>";
+    assert!(lint("no-content-before-head", html).is_empty());
+}
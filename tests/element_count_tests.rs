@@ -0,0 +1,92 @@
+mod common;
+
+use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+
+fn count_rule(selector: &str, condition: &str, options: &[(&str, &str)]) -> Rule {
+    Rule {
+        name: format!("{}-{}", selector, condition),
+        ..common::rule(
+            RuleType::ElementCount,
+            Severity::Error,
+            selector,
+            condition,
+            "Unexpected element count",
+            options,
+        )
+    }
+}
+
+#[test]
+fn test_min_count() {
+    let linter = HtmlLinter::new(vec![count_rule("nav", "min-count", &[("min", "1")])], None);
+
+    let results = linter.lint("<body><main>Content</main></body>").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.line, 1);
+    assert_eq!(results[0].location.column, 1);
+    assert!(results[0]
+        .message
+        .contains("found 0 'nav', expected at least 1"));
+
+    let results = linter.lint("<body><nav>Menu</nav></body>").unwrap();
+    assert_eq!(results.len(), 0);
+}
+
+#[test]
+fn test_exact_count() {
+    let linter = HtmlLinter::new(
+        vec![count_rule("h1", "exact-count", &[("count", "1")])],
+        None,
+    );
+
+    assert_eq!(linter.lint("<body><h1>Title</h1></body>").unwrap().len(), 0);
+
+    let results = linter.lint("<body><h2>Subtitle</h2></body>").unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("expected exactly 1"));
+
+    let results = linter
+        .lint("<body><h1>One</h1><h1>Two</h1><h1>Three</h1></body>")
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.location.element == "h1"));
+}
+
+#[test]
+fn test_range_count() {
+    let linter = HtmlLinter::new(
+        vec![count_rule(
+            "li",
+            "range-count",
+            &[("min", "2"), ("max", "3")],
+        )],
+        None,
+    );
+
+    let results = linter.lint("<ul><li>1</li></ul>").unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("expected between 2 and 3"));
+
+    assert_eq!(
+        linter
+            .lint("<ul><li>1</li><li>2</li><li>3</li></ul>")
+            .unwrap()
+            .len(),
+        0
+    );
+
+    let results = linter
+        .lint("<ul><li>1</li><li>2</li><li>3</li><li>4</li></ul>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "li");
+}
+
+#[test]
+fn test_invalid_count_option() {
+    let linter = HtmlLinter::new(
+        vec![count_rule("h1", "exact-count", &[("count", "one")])],
+        None,
+    );
+    assert!(linter.lint("<h1>Title</h1>").is_err());
+}
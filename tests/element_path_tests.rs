@@ -0,0 +1,51 @@
+mod common;
+
+use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+
+fn presence_rule(selector: &str, condition: &str) -> Rule {
+    Rule {
+        name: "element-path".to_string(),
+        ..common::rule(
+            RuleType::ElementPresence,
+            Severity::Warning,
+            selector,
+            condition,
+            "Flagged element",
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_element_path_uses_nth_child_for_repeated_tags() {
+    let linter = HtmlLinter::new(vec![presence_rule("p", "element-absent")], None);
+    let html = "<html><body><main><article><h2>One</h2></article><article><h2>Two</h2><p>Body</p></article></main></body></html>";
+    let results = linter.lint(html).unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].element_path,
+        "html > body > main > article:nth-child(2) > p"
+    );
+}
+
+#[test]
+fn test_element_path_uses_id_when_present() {
+    let linter = HtmlLinter::new(vec![presence_rule("span", "element-absent")], None);
+    let html = "<html><body><div id='intro'><span>Hi</span></div><div>Other</div></body></html>";
+    let results = linter.lint(html).unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].element_path, "html > body > div#intro > span");
+}
+
+#[test]
+fn test_element_path_empty_for_document_level_results() {
+    let linter = HtmlLinter::new(vec![presence_rule("main", "element-present")], None);
+    let results = linter
+        .lint("<html><body><p>Text</p></body></html>")
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert!(results[0].element_path.is_empty());
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use html_linter::{HtmlLinter, LintResult, LinterError, Rule, RuleType, Severity};
+
+fn conditional_rule(trigger: &str, required: &str) -> Rule {
+    common::rule(
+        RuleType::ElementPresence,
+        Severity::Error,
+        "body",
+        "conditional-presence",
+        "Required companion element is missing",
+        &[
+            ("trigger_selector", trigger),
+            ("required_selector", required),
+        ],
+    )
+}
+
+fn lint(rule: Rule, html: &str) -> Vec<LintResult> {
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+#[test]
+fn test_conditional_presence_missing_requirement() {
+    let rule = conditional_rule("form", "button[type=submit]");
+    let html = "<body><form><input name='q'></form><form><input name='r'></form></body>";
+    let results = lint(rule, html);
+
+    // One document-level result, however many triggers there are
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Required companion element is missing (found form but no button[type=submit])"
+    );
+    assert_eq!(results[0].location.line, 1);
+    assert_eq!(results[0].location.column, 1);
+}
+
+#[test]
+fn test_conditional_presence_satisfied_or_not_triggered() {
+    let rule = conditional_rule("video", "track[kind=captions]");
+    let captioned = "<body><video src='a.mp4'><track kind='captions' src='a.vtt'></video></body>";
+    assert!(lint(rule.clone(), captioned).is_empty());
+
+    let no_video = "<body><p>No media here</p></body>";
+    assert!(lint(rule.clone(), no_video).is_empty());
+
+    let uncaptioned = "<body><video src='a.mp4'><track kind='chapters' src='c.vtt'></video></body>";
+    assert_eq!(lint(rule, uncaptioned).len(), 1);
+}
+
+#[test]
+fn test_conditional_presence_requires_option() {
+    let mut rule = conditional_rule("form", "button");
+    rule.options.remove("required_selector");
+    let result = HtmlLinter::new(vec![rule], None).lint("<form></form>");
+    assert!(matches!(result, Err(LinterError::RuleError(_))));
+}
@@ -0,0 +1,188 @@
+use html_linter::{HtmlLinter, LintResult, LinterError, LinterOptions, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn lint(condition: &str, html: &str) -> Vec<LintResult> {
+    let rule = Rule {
+        name: condition.to_string(),
+        rule_type: RuleType::Encoding,
+        severity: Severity::Error,
+        selector: "meta".to_string(),
+        condition: condition.into(),
+        message: "Encoding problem".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    };
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+#[test]
+fn test_charset_declared() {
+    let html = "<!DOCTYPE html><html><head><meta charset='utf-8'><title>T</title></head></html>";
+    assert!(lint("charset-declared", html).is_empty());
+
+    let html = "<html><head><meta http-equiv='Content-Type' content='text/html; charset=utf-8'></head></html>";
+    assert!(lint("charset-declared", html).is_empty());
+
+    let results = lint(
+        "charset-declared",
+        "<html><head><title>T</title></head></html>",
+    );
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Encoding problem (no <meta charset> declaration)"
+    );
+    assert_eq!(results[0].location.line, 1);
+}
+
+#[test]
+fn test_charset_declared_too_late() {
+    let padding = "x".repeat(1100);
+    let html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<!-- {} -->\n<meta charset='utf-8'>\n</head></html>",
+        padding
+    );
+    let results = lint("charset-declared", &html);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.line, 5);
+    assert!(results[0]
+        .message
+        .contains("must be within the first 1024 bytes"));
+
+    // Double quotes take the raw-source fallback path for the position
+    let html = format!(
+        "<html><head><!-- {} --><meta charset=\"utf-8\"></head></html>",
+        padding
+    );
+    assert_eq!(lint("charset-declared", &html).len(), 1);
+}
+
+#[test]
+fn test_charset_valid() {
+    let html = "<html><head><meta charset='UTF-8'></head></html>";
+    assert!(lint("charset-valid", html).is_empty());
+
+    let html = "<html><head><meta http-equiv='content-type' content='text/html; charset=windows-1252'></head></html>";
+    assert!(lint("charset-valid", html).is_empty());
+
+    let results = lint(
+        "charset-valid",
+        "<html><head><meta charset='utf-9'></head></html>",
+    );
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Encoding problem (unknown charset 'utf-9')"
+    );
+}
+
+#[test]
+fn test_bom_detected() {
+    let results = lint(
+        "bom-detected",
+        "\u{FEFF}<!DOCTYPE html><html><head></head></html>",
+    );
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        (results[0].location.line, results[0].location.column),
+        (1, 1)
+    );
+
+    assert!(lint("bom-detected", "<!DOCTYPE html><html><head></head></html>").is_empty());
+}
+
+fn lint_latin1_file(options: LinterOptions, bytes: &[u8]) -> Result<Vec<LintResult>, LinterError> {
+    lint_titled_file(options, bytes, "Café — menu")
+}
+
+fn lint_titled_file(
+    options: LinterOptions,
+    bytes: &[u8],
+    title: &str,
+) -> Result<Vec<LintResult>, LinterError> {
+    let rule = Rule {
+        name: "title-text".to_string(),
+        rule_type: RuleType::TextContent,
+        severity: Severity::Warning,
+        selector: "title".to_string(),
+        condition: "text-content".into(),
+        message: "Title found".to_string(),
+        options: HashMap::from([("pattern".to_string(), format!("^{}$", title))]),
+        ..Default::default()
+    };
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("page.html");
+    std::fs::write(&path, bytes).unwrap();
+    HtmlLinter::new(vec![rule], Some(options)).lint_file(&path)
+}
+
+// "Café — menu" in Windows-1252, where é is 0xE9 and the em dash 0x97
+const LATIN1_TITLE: &[u8] = b"<title>Caf\xE9 \x97 menu</title>";
+
+#[test]
+fn test_lint_file_detects_encoding() {
+    let options = LinterOptions {
+        encoding_detection: true,
+        ..LinterOptions::default()
+    };
+    let html = [
+        b"<html><head><meta charset=\"windows-1252\">".as_slice(),
+        LATIN1_TITLE,
+        b"</head></html>",
+    ]
+    .concat();
+    assert_eq!(lint_latin1_file(options.clone(), &html).unwrap().len(), 1);
+
+    let html = "\u{FEFF}<title>Café — menu</title>"
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect::<Vec<u8>>();
+    assert_eq!(lint_latin1_file(options, &html).unwrap().len(), 1);
+}
+
+#[test]
+fn test_lint_file_with_configured_encoding() {
+    let html = [b"<html><head>".as_slice(), LATIN1_TITLE, b"</head></html>"].concat();
+
+    let options = LinterOptions {
+        encoding: Some("ISO-8859-1".to_string()),
+        ..LinterOptions::default()
+    };
+    assert_eq!(lint_latin1_file(options, &html).unwrap().len(), 1);
+
+    // Without an encoding the bytes aren't valid UTF-8
+    assert!(matches!(
+        lint_latin1_file(LinterOptions::default(), &html),
+        Err(LinterError::ParseError(_))
+    ));
+
+    // "カフェ" in Shift_JIS
+    let html = b"<title>\x83J\x83t\x83F</title>";
+    let options = LinterOptions {
+        encoding: Some("shift_jis".to_string()),
+        ..LinterOptions::default()
+    };
+    assert_eq!(lint_titled_file(options, html, "カフェ").unwrap().len(), 1);
+
+    let options = LinterOptions {
+        encoding: Some("klingon".to_string()),
+        ..LinterOptions::default()
+    };
+    assert!(matches!(
+        lint_latin1_file(options, html),
+        Err(LinterError::ParseError(_))
+    ));
+}
+
+#[test]
+fn test_lint_file_utf16_big_endian_bom() {
+    let options = LinterOptions {
+        encoding: Some("utf-16".to_string()),
+        ..LinterOptions::default()
+    };
+    let html = "\u{FEFF}<title>Café — menu</title>"
+        .encode_utf16()
+        .flat_map(u16::to_be_bytes)
+        .collect::<Vec<u8>>();
+    assert_eq!(lint_latin1_file(options, &html).unwrap().len(), 1);
+}
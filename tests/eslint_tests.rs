@@ -9,16 +9,17 @@ fn setup_eslint_rules() -> Vec<Rule> {
             rule_type: RuleType::AttributePresence,
             severity: Severity::Error,
             selector: "*".to_string(),
-            condition: "duplicate-attributes".to_string(),
+            condition: "duplicate-attributes".into(),
             message: "Duplicate attributes are not allowed".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         },
         Rule {
             name: "no-duplicate-id".to_string(),
             rule_type: RuleType::AttributeValue,
             severity: Severity::Error,
             selector: "[id]".to_string(),
-            condition: "unique-id".to_string(),
+            condition: "unique-id".into(),
             message: "IDs must be unique".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -27,31 +28,34 @@ fn setup_eslint_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "id".to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "no-inline-styles".to_string(),
             rule_type: RuleType::AttributePresence,
             severity: Severity::Warning,
             selector: "*".to_string(),
-            condition: "style-attribute".to_string(),
+            condition: "style-attribute".into(),
             message: "Inline styles should be avoided".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         },
         Rule {
             name: "require-doctype".to_string(),
             rule_type: RuleType::DocumentStructure,
             severity: Severity::Error,
             selector: "html".to_string(),
-            condition: "doctype-present".to_string(),
+            condition: "doctype-present".into(),
             message: "HTML documents must have a DOCTYPE declaration".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         },
         Rule {
             name: "require-lang".to_string(),
             rule_type: RuleType::Compound,
             severity: Severity::Error,
             selector: "html".to_string(),
-            condition: "all-conditions-met".to_string(),
+            condition: "all-conditions-met".into(),
             message: "The <html> element must have a non-empty lang attribute".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -69,53 +73,58 @@ fn setup_eslint_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "no-obsolete-tags".to_string(),
             rule_type: RuleType::ElementPresence,
             severity: Severity::Error,
             selector: "marquee, blink, font, center".to_string(),
-            condition: "element-present".to_string(),
+            condition: "element-absent".into(),
             message: "Obsolete HTML tags are not allowed".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         },
         Rule {
             name: "no-multiple-h1".to_string(),
             rule_type: RuleType::ElementCount,
             severity: Severity::Error,
             selector: "h1".to_string(),
-            condition: "max-count".to_string(),
+            condition: "max-count".into(),
             message: "Only one <h1> element is allowed per page".to_string(),
             options: {
                 let mut options = HashMap::new();
                 options.insert("max".to_string(), "1".to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "require-meta-description".to_string(),
             rule_type: RuleType::ElementPresence,
             severity: Severity::Warning,
             selector: "head meta[name='description']".to_string(),
-            condition: "element-present".to_string(),
+            condition: "element-present".into(),
             message: "Meta description is required".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         },
         Rule {
             name: "require-title".to_string(),
             rule_type: RuleType::ElementPresence,
             severity: Severity::Error,
             selector: "head title".to_string(),
-            condition: "element-present".to_string(),
+            condition: "element-present".into(),
             message: "Title element is required in head".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         },
         Rule {
             name: "no-positive-tabindex".to_string(),
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "[tabindex]".to_string(),
-            condition: "positive-number".to_string(),
+            condition: "positive-number".into(),
             message: "Positive tabindex values should be avoided".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -124,37 +133,41 @@ fn setup_eslint_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "tabindex".to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "require-img-alt".to_string(),
             rule_type: RuleType::AttributePresence,
             severity: Severity::Error,
             selector: "img".to_string(),
-            condition: "alt-attribute".to_string(),
+            condition: "alt-attribute".into(),
             message: "Images must have alt attributes".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         },
         Rule {
             name: "lowercase".to_string(),
             rule_type: RuleType::ElementCase,
             severity: Severity::Warning,
             selector: "*".to_string(),
-            condition: "lowercase".to_string(),
+            condition: "lowercase".into(),
             message: "HTML tags and attributes should be lowercase".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         },
         Rule {
             name: "quotes".to_string(),
             rule_type: RuleType::AttributeQuotes,
             severity: Severity::Warning,
             selector: "*".to_string(),
-            condition: "quote-style".to_string(),
+            condition: "quote-style".into(),
             message: "Use double quotes for attribute values".to_string(),
             options: {
                 let mut options = HashMap::new();
                 options.insert("style".to_string(), "double".to_string());
                 options
             },
+            ..Default::default()
         },
     ]
 }
@@ -166,6 +179,7 @@ fn test_valid_html_document() {
 <html lang="en">
 <head>
     <title>Valid Document</title>
+    <meta name="description" content="A document that passes every rule">
 </head>
 <body>
     <div id="unique">Content</div>
@@ -354,3 +368,46 @@ fn test_lang_attribute_values() {
         assert_eq!(!has_lang_error, should_pass, "{}", message);
     }
 }
+
+#[test]
+fn test_required_elements() {
+    let linter = HtmlLinter::new(setup_eslint_rules(), None);
+    let html = r#"<!DOCTYPE html>
+<html lang="en">
+<head></head>
+<body></body>
+</html>"#;
+    let results = linter.lint(html).unwrap();
+
+    for rule in ["require-title", "require-meta-description"] {
+        let result = results
+            .iter()
+            .find(|r| r.rule == rule)
+            .unwrap_or_else(|| panic!("Should report missing element for {}", rule));
+        assert_eq!(result.location.line, 1);
+        assert_eq!(result.location.column, 1);
+    }
+}
+
+#[test]
+fn test_obsolete_tags() {
+    let linter = HtmlLinter::new(setup_eslint_rules(), None);
+    let html = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <title>Obsolete</title>
+    <meta name="description" content="Uses obsolete tags">
+</head>
+<body>
+    <marquee>Scrolling</marquee>
+</body>
+</html>"#;
+    let results = linter.lint(html).unwrap();
+    let obsolete: Vec<_> = results
+        .iter()
+        .filter(|r| r.rule == "no-obsolete-tags")
+        .collect();
+    assert_eq!(obsolete.len(), 1);
+    assert_eq!(obsolete[0].location.element, "marquee");
+    assert_eq!(obsolete[0].location.line, 8);
+}
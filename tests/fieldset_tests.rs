@@ -0,0 +1,72 @@
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn lint(html: &str) -> Vec<LintResult> {
+    let rule = Rule {
+        name: "form-fieldset-legend".to_string(),
+        rule_type: RuleType::Semantics,
+        severity: Severity::Warning,
+        selector: "form".to_string(),
+        condition: "form-fieldset-legend".into(),
+        message: "Group related controls".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    };
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+#[test]
+fn test_grouped_radios_pass() {
+    let html = "<form><fieldset><legend>Size</legend>\
+                <label><input type='radio' name='size' value='s'> S</label>\
+                <label><input type='radio' name='size' value='m'> M</label>\
+                </fieldset></form>";
+    assert!(lint(html).is_empty());
+}
+
+#[test]
+fn test_fieldset_legend_count() {
+    let html = "<form><fieldset id='none'><input name='a'></fieldset>\
+                <fieldset id='two'><legend>A</legend><legend>B</legend></fieldset></form>";
+    let results = lint(html);
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].message,
+        "Group related controls (fieldset has no legend)"
+    );
+    assert_eq!(
+        results[1].message,
+        "Group related controls (fieldset has 2 legends)"
+    );
+}
+
+#[test]
+fn test_nested_legend_does_not_count() {
+    let html = "<form><fieldset><div><legend>Hidden</legend></div></fieldset></form>";
+    assert_eq!(lint(html).len(), 1);
+}
+
+#[test]
+fn test_radio_group_outside_fieldset() {
+    let html = "<form><input type='radio' name='color' value='red'>\
+                <input type='radio' name='color' value='blue'></form>";
+    let results = lint(html);
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].message,
+        "Group related controls (radio group \"color\" is not grouped in one fieldset)"
+    );
+    assert!(results[0].source.contains("red"));
+}
+
+#[test]
+fn test_radio_group_split_across_fieldsets() {
+    let html = "<form><fieldset><legend>A</legend><input type='radio' name='plan' value='a'></fieldset>\
+                <fieldset><legend>B</legend><input type='radio' name='plan' value='b'></fieldset></form>";
+    let results = lint(html);
+
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("\"plan\""));
+}
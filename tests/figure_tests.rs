@@ -0,0 +1,64 @@
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn lint(condition: &str, html: &str) -> Vec<LintResult> {
+    let rule = Rule {
+        name: condition.to_string(),
+        rule_type: RuleType::Semantics,
+        severity: Severity::Warning,
+        selector: "*".to_string(),
+        condition: condition.into(),
+        message: "Invalid figure".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    };
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+fn messages(results: &[LintResult]) -> Vec<&str> {
+    results
+        .iter()
+        .map(|result| result.message.as_str())
+        .collect()
+}
+
+#[test]
+fn test_figcaption_content() {
+    let html = "<figure><img src='a.png' alt='Chart'>\
+        <figcaption>Sales by <em>region</em>, <span><a href='/data'>source</a></span>\
+        <button type='button'>Zoom</button><a name='anchor'>here</a>\
+        <input type='hidden' name='id' value='1'></figcaption></figure>";
+    let results = lint("figcaption-content", html);
+    assert_eq!(
+        messages(&results),
+        vec![
+            "Invalid figure (interactive <a> inside <figcaption>)",
+            "Invalid figure (interactive <button> inside <figcaption>)",
+        ]
+    );
+    assert!(results[0].source.contains("/data"));
+
+    let html = "<figure><img src='b.png' alt='Map'><figcaption>Office <strong>locations</strong></figcaption></figure>";
+    assert!(lint("figcaption-content", html).is_empty());
+}
+
+#[test]
+fn test_figure_structure() {
+    let html = "<figure class='first'><figcaption>Top</figcaption><img src='a.png' alt='A'></figure>\
+        <figure class='last'><img src='b.png' alt='B'>\n<figcaption>Bottom</figcaption>\n</figure>\
+        <figure class='none'><img src='c.png' alt='C'></figure>\
+        <figure class='two'><figcaption>One</figcaption><img src='d.png' alt='D'><figcaption>Two</figcaption></figure>\
+        <figure class='middle'><img src='e.png' alt='E'><figcaption id='mid'>Middle</figcaption><p>Notes</p></figure>";
+    let results = lint("figure-structure", html);
+    assert_eq!(
+        messages(&results),
+        vec![
+            "Invalid figure (figure has no figcaption)",
+            "Invalid figure (figure has 2 figcaptions)",
+            "Invalid figure (figcaption must be the first or last child of its figure)",
+        ]
+    );
+    assert!(results[0].source.contains("none"));
+    assert!(results[1].source.contains("two"));
+    assert!(results[2].source.contains("mid"));
+}
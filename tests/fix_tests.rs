@@ -0,0 +1,247 @@
+mod common;
+
+use html_linter::{FixSuggestion, HtmlLinter, LintResult, Location, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn fixable(line: usize, source: &str, replacement: &str, is_safe: bool) -> LintResult {
+    LintResult {
+        rule: "fixable".to_string(),
+        severity: Severity::Warning,
+        message: "Fixable problem".to_string(),
+        location: Location {
+            line,
+            column: 1,
+            element: String::new(),
+            file: None,
+        },
+        source: source.to_string(),
+        context: String::new(),
+        fix_suggestion: Some(FixSuggestion {
+            description: "Fix it".to_string(),
+            replacement: Some(replacement.to_string()),
+            is_safe,
+        }),
+        ..Default::default()
+    }
+}
+
+fn fixer() -> HtmlLinter {
+    HtmlLinter::new(Vec::new(), None)
+}
+
+#[test]
+fn test_apply_fixes_in_reverse_order() {
+    let html = "<div>\n<A HREF='x'>link</A>   \n</div>";
+    let results = vec![
+        fixable(2, "<A HREF='x'>", "<a href='x'>", true),
+        fixable(2, "<A HREF='x'>link</A>   ", "<A HREF='x'>link</A>", true),
+    ];
+
+    // Both fixes overlap on line 2, so only the later one in source order is applied
+    let fixed = fixer().apply_fixes(html, &results);
+    assert_eq!(fixed, "<div>\n<A HREF='x'>link</A>\n</div>");
+
+    let results = vec![
+        fixable(1, "<div>", "<section>", true),
+        fixable(3, "</div>", "</section>", true),
+        fixable(2, "<A HREF='x'>", "<a href='x'>", true),
+    ];
+    let fixed = fixer().apply_fixes(html, &results);
+    assert_eq!(fixed, "<section>\n<a href='x'>link</A>   \n</section>");
+}
+
+#[test]
+fn test_apply_fixes_skips_unsafe_and_unlocated() {
+    let html = "<p>one</p>\n<p>two</p>";
+    let mut unlocated = fixable(0, "<p>", "<div>", true);
+    unlocated.location.line = 0;
+    let results = vec![
+        fixable(1, "<p>one</p>", "<p>1</p>", false),
+        unlocated,
+        fixable(2, "<p>missing</p>", "<p>2</p>", true),
+    ];
+
+    assert_eq!(fixer().apply_fixes(html, &results), html);
+}
+
+#[test]
+fn test_apply_fixes_uses_reported_line() {
+    let html = "<br>\n<br>";
+    let results = vec![fixable(2, "<br>", "<br />", true)];
+    assert_eq!(fixer().apply_fixes(html, &results), "<br>\n<br />");
+}
+
+#[test]
+fn test_apply_fixes_uses_reported_column() {
+    let html = "<br><br>";
+    let mut second = fixable(1, "<br>", "<br />", true);
+    second.location.column = 5;
+    assert_eq!(fixer().apply_fixes(html, &[second]), "<br><br />");
+
+    // Text that isn't at the reported position is left alone
+    let mut misplaced = fixable(1, "<br>", "<br />", true);
+    misplaced.location.column = 3;
+    assert_eq!(fixer().apply_fixes(html, &[misplaced]), html);
+}
+
+// Lints `html`, applies the fixes, and returns the fixed document with what re-linting it finds
+fn lint_fix_relint(rule: Rule, html: &str) -> (String, Vec<LintResult>) {
+    let linter = HtmlLinter::new(vec![rule], None);
+    let results = linter.lint(html).unwrap();
+    assert!(!results.is_empty());
+    let fixed = linter.apply_fixes(html, &results);
+    let remaining = linter.lint(&fixed).unwrap();
+    (fixed, remaining)
+}
+
+fn quote_rule(style: &str) -> Rule {
+    Rule {
+        name: "quotes".to_string(),
+        ..common::rule(
+            RuleType::AttributeQuotes,
+            Severity::Warning,
+            "*",
+            "quote-style",
+            &format!("Use {} quotes for attribute values", style),
+            &[("style", style)],
+        )
+    }
+}
+
+#[test]
+fn test_fix_attribute_quotes_end_to_end() {
+    let html =
+        "<body>\n  <input type='checkbox' checked title='Tom &amp; Jerry' data-n=3>\n</body>";
+    let (fixed, remaining) = lint_fix_relint(quote_rule("double"), html);
+    assert_eq!(
+        fixed,
+        "<body>\n  <input type=\"checkbox\" checked title=\"Tom &amp; Jerry\" data-n=\"3\">\n</body>"
+    );
+    assert!(remaining.is_empty());
+
+    let html = "<p class=\"lead\"\n   id=\"intro\">Text</p><p title=\"x\">More</p>";
+    let (fixed, remaining) = lint_fix_relint(quote_rule("single"), html);
+    assert_eq!(
+        fixed,
+        "<p class='lead'\n   id='intro'>Text</p><p title='x'>More</p>"
+    );
+    assert!(remaining.is_empty());
+}
+
+#[test]
+fn test_fix_element_case_end_to_end() {
+    let rule = Rule {
+        name: "lowercase".to_string(),
+        rule_type: RuleType::ElementCase,
+        severity: Severity::Warning,
+        selector: "*".to_string(),
+        condition: "lowercase".into(),
+        message: "HTML tags and attributes should be lowercase".to_string(),
+        ..Default::default()
+    };
+    let html = "<body><DIV Class=Note>Text</DIV><svg viewBox='0 0 1 1'></svg></body>";
+
+    let results = HtmlLinter::new(vec![rule.clone()], None)
+        .lint(html)
+        .unwrap();
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec!["HTML tags and attributes should be lowercase (element: DIV) (attributes: Class)"]
+    );
+
+    // Values keep their case, and SVG's camel-cased names aren't flagged
+    let (fixed, remaining) = lint_fix_relint(rule, html);
+    assert_eq!(
+        fixed,
+        "<body><div class=Note>Text</DIV><svg viewBox='0 0 1 1'></svg></body>"
+    );
+    assert!(remaining.is_empty());
+}
+
+#[test]
+fn test_fix_trailing_whitespace_end_to_end() {
+    let rule = Rule {
+        name: "trailing-whitespace".to_string(),
+        rule_type: RuleType::WhiteSpace,
+        severity: Severity::Warning,
+        selector: "*".to_string(),
+        condition: "trailing-whitespace".into(),
+        message: "No trailing whitespace".to_string(),
+        ..Default::default()
+    };
+    let html = "<body>  \n<p>One</p>\t\n<p>Two</p>\n</body>";
+    let (fixed, remaining) = lint_fix_relint(rule, html);
+    assert_eq!(fixed, "<body>\n<p>One</p>\n<p>Two</p>\n</body>");
+    assert!(remaining.is_empty());
+}
+
+#[test]
+fn test_attribute_quotes_suggestion() {
+    let rules = vec![Rule {
+        name: "quotes".to_string(),
+        rule_type: RuleType::AttributeQuotes,
+        severity: Severity::Warning,
+        selector: "p".to_string(),
+        condition: "quote-style".into(),
+        message: "Use double quotes for attribute values".to_string(),
+        options: {
+            let mut options = HashMap::new();
+            options.insert("style".to_string(), "double".to_string());
+            options
+        },
+        ..Default::default()
+    }];
+
+    let linter = HtmlLinter::new(rules, None);
+    let results = linter
+        .lint(r#"<p title='hello' class="lead">Text</p>"#)
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let fix = results[0].fix_suggestion.as_ref().unwrap();
+    assert!(fix.is_safe);
+    assert_eq!(
+        fix.replacement.as_deref(),
+        Some(r#"<p title="hello" class="lead">"#)
+    );
+}
+
+#[test]
+fn test_attribute_quotes_detected_from_markup() {
+    let rule = |style: &str| Rule {
+        name: "quotes".to_string(),
+        rule_type: RuleType::AttributeQuotes,
+        severity: Severity::Warning,
+        selector: "img".to_string(),
+        condition: "quote-style".into(),
+        message: format!("Use {} quotes for attribute values", style),
+        options: HashMap::from([("style".to_string(), style.to_string())]),
+        ..Default::default()
+    };
+    let html = r#"<img src="a.png" alt="it's fine"><img src='b.png' alt='say "hi"'>"#;
+
+    // A double-quoted value containing an apostrophe is still double-quoted
+    let results = HtmlLinter::new(vec![rule("double")], None)
+        .lint(html)
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result.source.contains("b.png")));
+
+    let results = HtmlLinter::new(vec![rule("single")], None)
+        .lint(html)
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results
+        .iter()
+        .all(|result| !result.source.contains("b.png")));
+
+    // Valueless attributes have no quotes, and the parser renames `<image>` to `img`, so that
+    // element has no start tag to read quotes from
+    let html = "<input type='checkbox' checked><image src='c.png' alt='x'>";
+    let results = HtmlLinter::new(vec![quote_rule("double")], None)
+        .lint(html)
+        .unwrap();
+    let sources: Vec<&str> = results.iter().map(|r| r.source.as_str()).collect();
+    assert_eq!(sources, vec!["<input type='checkbox' checked>"]);
+}
@@ -0,0 +1,230 @@
+mod common;
+
+use html_linter::{HtmlLinter, LinterError, Rule, RuleType, Severity};
+
+fn form_rule(condition: &str) -> Rule {
+    Rule {
+        name: "form-validation".to_string(),
+        ..common::rule(
+            RuleType::FormValidation,
+            Severity::Error,
+            "form",
+            condition,
+            "Form issue",
+            &[],
+        )
+    }
+}
+
+fn lint(rule: Rule, html: &str) -> Vec<html_linter::LintResult> {
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+#[test]
+fn test_label_wrapping_input() {
+    let html = "<form><label>Name <input type='text' name='name'></label></form>";
+    assert!(lint(form_rule("input-label-association"), html).is_empty());
+}
+
+#[test]
+fn test_label_for_id() {
+    let html =
+        "<form><label for='email'>Email</label><input type='email' id='email' name='email'></form>";
+    assert!(lint(form_rule("input-label-association"), html).is_empty());
+}
+
+#[test]
+fn test_unlabelled_input() {
+    let html = "<form><input type='text' name='name'></form>";
+    let results = lint(form_rule("input-label-association"), html);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "input");
+    assert_eq!(
+        results[0].message,
+        "Form issue - Form control has no associated label"
+    );
+}
+
+#[test]
+fn test_label_for_other_id() {
+    let html =
+        "<form><label for='other'>Email</label><input type='email' id='email' name='email'></form>";
+    assert_eq!(lint(form_rule("input-label-association"), html).len(), 1);
+}
+
+#[test]
+fn test_hidden_and_button_inputs_need_no_label() {
+    let html = "<form><input type='hidden' name='token' value='x'><input type='submit' value='Send'></form>";
+    assert!(lint(form_rule("input-label-association"), html).is_empty());
+}
+
+#[test]
+fn test_unlabelled_select_and_textarea() {
+    let html = "<form><select name='size'><option>S</option></select><textarea name='notes'></textarea></form>";
+    let results = lint(form_rule("input-label-association"), html);
+    let elements: Vec<&str> = results
+        .iter()
+        .map(|r| r.location.element.as_str())
+        .collect();
+    assert_eq!(elements, vec!["select", "textarea"]);
+}
+
+#[test]
+fn test_inputs_outside_selector_are_ignored() {
+    let html = "<div><input type='text' name='search'></div><form><label>Name <input type='text' name='name'></label></form>";
+    assert!(lint(form_rule("input-label-association"), html).is_empty());
+}
+
+#[test]
+fn test_required_field_with_name() {
+    let html = "<form><label>Name <input type='text' name='name' required></label></form>";
+    assert!(lint(form_rule("required-fields"), html).is_empty());
+}
+
+#[test]
+fn test_required_field_without_name() {
+    let html = "<form><label>Name <input type='text' required></label><label>Notes <textarea name=' ' required></textarea></label></form>";
+    let results = lint(form_rule("required-fields"), html);
+    assert_eq!(results.len(), 2);
+    assert!(results
+        .iter()
+        .all(|r| r.message == "Form issue - Required field has no name"));
+}
+
+#[test]
+fn test_valid_form_methods() {
+    for method in ["get", "POST", "dialog"] {
+        let html = format!("<form method='{}'></form>", method);
+        assert!(
+            lint(form_rule("form-method"), &html).is_empty(),
+            "{}",
+            method
+        );
+    }
+    assert!(lint(form_rule("form-method"), "<form></form>").is_empty());
+}
+
+#[test]
+fn test_invalid_form_method() {
+    let results = lint(form_rule("form-method"), "<form method='put'></form>");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "form");
+    assert_eq!(results[0].message, "Form issue - Invalid form method 'put'");
+}
+
+#[test]
+fn test_text_input_without_autocomplete() {
+    let html = "<form><label>Email <input type='email' name='email'></label><label>Name <input name='name'></label></form>";
+    let results = lint(form_rule("autocomplete"), html);
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].message,
+        "Form issue - Text input has no autocomplete attribute"
+    );
+}
+
+#[test]
+fn test_autocomplete_present_or_not_text() {
+    let html = "<form><label>Email <input type='email' name='email' autocomplete='email'></label><label>Agree <input type='checkbox' name='agree'></label></form>";
+    assert!(lint(form_rule("autocomplete"), html).is_empty());
+}
+
+#[test]
+fn test_form_validation_runs_all_checks() {
+    let html = "<form method='put'><input type='text' required></form>";
+    let results = lint(form_rule("form-validation"), html);
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Form issue - Invalid form method 'put'",
+            "Form issue - Form control has no associated label",
+            "Form issue - Required field has no name",
+            "Form issue - Text input has no autocomplete attribute",
+        ]
+    );
+}
+
+#[test]
+fn test_form_validation_selected_checks() {
+    let mut rule = form_rule("form-validation");
+    rule.options.insert(
+        "checks".to_string(),
+        r#"["form-method", "required-fields"]"#.to_string(),
+    );
+    let html = "<form method='put'><input type='text' required></form>";
+    assert_eq!(lint(rule, html).len(), 2);
+}
+
+#[test]
+fn test_form_validation_unknown_check() {
+    let mut rule = form_rule("form-validation");
+    rule.options
+        .insert("checks".to_string(), r#"["spellcheck"]"#.to_string());
+    let linter = HtmlLinter::new(vec![rule], None);
+    assert!(matches!(
+        linter.lint("<form></form>"),
+        Err(LinterError::RuleError(_))
+    ));
+}
+
+#[test]
+fn test_form_validation_invalid_checks_json() {
+    let mut rule = form_rule("form-validation");
+    rule.options
+        .insert("checks".to_string(), "form-method".to_string());
+    let linter = HtmlLinter::new(vec![rule], None);
+    assert!(linter.lint("<form></form>").is_err());
+}
+
+fn autocomplete_rule(options: &[(&str, &str)]) -> Rule {
+    common::rule(
+        RuleType::Semantics,
+        Severity::Error,
+        "input",
+        "autocomplete",
+        "Inputs must identify their purpose",
+        options,
+    )
+}
+
+#[test]
+fn test_semantic_autocomplete_tokens() {
+    let html = "<form>\
+        <input type='email' name='email' autocomplete='email'>\
+        <input type='text' name='street' autocomplete='section-home shipping street-address'>\
+        <input type='tel' name='phone' autocomplete='work tel'>\
+        <input type='text' name='nickname'>\
+        <input type='text' name='city' autocomplete='town'>\
+        <input type='url' name='site' autocomplete='url shipping'>\
+        <input type='hidden' name='token'>\
+        <input type='checkbox' name='subscribe'>\
+        </form>";
+    let results = lint(autocomplete_rule(&[]), html);
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Inputs must identify their purpose (missing autocomplete attribute)",
+            r#"Inputs must identify their purpose (unrecognized autocomplete value "town")"#,
+            r#"Inputs must identify their purpose (unrecognized autocomplete value "url shipping")"#,
+        ]
+    );
+    assert!(results[0].source.contains("nickname"));
+}
+
+#[test]
+fn test_semantic_autocomplete_allowed_values() {
+    let html = "<form><input type='text' name='code' autocomplete='one-time-code'>\
+        <input type='email' name='email' autocomplete='email'></form>";
+    let rule = autocomplete_rule(&[("allowed_values", r#"["one-time-code"]"#)]);
+    let results = lint(rule, html);
+    assert_eq!(results.len(), 1);
+    assert!(results[0].source.contains("email"));
+
+    let rule = autocomplete_rule(&[("allowed_values", "one-time-code")]);
+    assert!(matches!(
+        HtmlLinter::new(vec![rule], None).lint(html),
+        Err(LinterError::RuleError(_))
+    ));
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+
+fn rule(rule_type: RuleType, selector: &str, condition: &str) -> Rule {
+    common::rule(
+        rule_type,
+        Severity::Error,
+        selector,
+        condition,
+        "Fragment issue",
+        &[],
+    )
+}
+
+#[test]
+fn test_fragment_skips_synthesized_html() {
+    let linter = HtmlLinter::new(
+        vec![rule(RuleType::AttributePresence, "html", "lang-attribute")],
+        None,
+    );
+    let html = "<div><p>text</p></div>";
+
+    // As a document, the parser-inserted <html> has no lang attribute
+    assert_eq!(linter.lint(html).unwrap().len(), 1);
+    assert!(linter.lint_fragment(html).unwrap().is_empty());
+}
+
+#[test]
+fn test_fragment_reports_locations() {
+    let linter = HtmlLinter::new(
+        vec![rule(RuleType::AttributePresence, "img", "alt-missing")],
+        None,
+    );
+    let html = "<div class='card'>\n  <img src='a.png'>\n</div>";
+
+    let results = linter.lint_fragment(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.line, 2);
+    assert_eq!(results[0].location.column, 3);
+}
+
+#[test]
+fn test_fragment_context_element() {
+    let linter = HtmlLinter::new(
+        vec![rule(RuleType::ElementPresence, "td", "forbidden")],
+        None,
+    );
+    let html = "<tr><td>Cell</td></tr>";
+
+    // Table rows are only parsed as such inside a table section
+    assert!(linter.lint_fragment(html).unwrap().is_empty());
+    assert_eq!(linter.lint_fragment_in(html, "tbody").unwrap().len(), 1);
+}
@@ -0,0 +1,75 @@
+use html_linter::output::github::{to_github_annotation, GithubAnnotations};
+use html_linter::output::LintResultWriter;
+use html_linter::{HtmlLinter, LintResult, Location, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn result(severity: Severity, line: usize, column: usize, message: &str) -> LintResult {
+    LintResult {
+        rule: "img-alt".to_string(),
+        severity,
+        message: message.to_string(),
+        location: Location {
+            line,
+            column,
+            element: "img".to_string(),
+            file: None,
+        },
+        source: String::new(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_annotation_commands_per_severity() {
+    assert_eq!(
+        to_github_annotation(&result(Severity::Error, 3, 5, "Missing alt")),
+        "::error line=3,col=5,title=img-alt::Missing alt"
+    );
+    assert_eq!(
+        to_github_annotation(&result(Severity::Warning, 1, 1, "Missing alt")),
+        "::warning line=1,col=1,title=img-alt::Missing alt"
+    );
+    assert_eq!(
+        to_github_annotation(&result(Severity::Info, 0, 0, "Missing alt")),
+        "::notice title=img-alt::Missing alt"
+    );
+}
+
+#[test]
+fn test_annotation_escaping() {
+    let mut result = result(Severity::Error, 2, 4, "100% broken\nsee docs");
+    result.location.file = Some("pages/a,b:c.html".to_string());
+    assert_eq!(
+        to_github_annotation(&result),
+        "::error file=pages/a%2Cb%3Ac.html,line=2,col=4,title=img-alt::100%25 broken%0Asee docs"
+    );
+}
+
+#[test]
+fn test_write_all_from_lint_file() {
+    let path = std::env::temp_dir().join(format!("github_annotations_{}.html", std::process::id()));
+    std::fs::write(&path, "<html><body>\n<img src='a.png'>\n</body></html>").unwrap();
+
+    let rule = Rule {
+        name: "img-alt".to_string(),
+        rule_type: RuleType::AttributePresence,
+        severity: Severity::Error,
+        selector: "img".to_string(),
+        condition: "alt-missing".into(),
+        message: "Images must have alt attributes".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    };
+    let results = HtmlLinter::new(vec![rule], None).lint_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let mut output = Vec::new();
+    GithubAnnotations.write_all(&results, &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        format!(
+            "::error file={},line=2,col=1,title=img-alt::Images must have alt attributes\n",
+            path.display()
+        )
+    );
+}
@@ -1,4 +1,6 @@
-use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+use html_linter::{
+    ConditionType, HtmlLinter, LinterError, LinterOptions, Rule, RuleType, Severity,
+};
 use serde_json::json;
 use std::collections::HashMap;
 
@@ -9,18 +11,20 @@ fn create_basic_linter() -> HtmlLinter {
             rule_type: RuleType::AttributePresence,
             severity: Severity::Error,
             selector: "img".to_string(),
-            condition: "alt-missing".to_string(),
+            condition: "alt-missing".into(),
             message: "Images must have alt attributes".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         },
         Rule {
             name: "no-inline-styles".to_string(),
             rule_type: RuleType::AttributePresence,
             severity: Severity::Warning,
             selector: "*".to_string(),
-            condition: "style-attribute".to_string(),
+            condition: "style-attribute".into(),
             message: "Inline styles should be avoided".to_string(),
             options: HashMap::new(),
+            ..Default::default()
         },
     ];
 
@@ -68,9 +72,10 @@ fn test_heading_order() {
         rule_type: RuleType::ElementOrder,
         severity: Severity::Error,
         selector: "h1,h2,h3,h4,h5,h6".to_string(),
-        condition: "sequential-order".to_string(),
+        condition: "sequential-order".into(),
         message: "Heading levels should not be skipped".to_string(),
         options: HashMap::new(),
+        ..Default::default()
     }];
 
     let linter = HtmlLinter::new(rules, None);
@@ -86,6 +91,33 @@ fn test_heading_order() {
     assert_eq!(results.len(), 1);
 }
 
+#[test]
+fn test_heading_order_follows_document_order() {
+    let rules = vec![Rule {
+        name: "heading-order".to_string(),
+        rule_type: RuleType::ElementOrder,
+        severity: Severity::Error,
+        selector: "h1,h2,h3,h4,h5,h6".to_string(),
+        condition: "sequential-order".into(),
+        message: "Heading levels should not be skipped".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    }];
+
+    let html = "<body><header><h1>Title</h1></header>\
+        <main><section><div><h2>Intro</h2><article><h4>Deep</h4></article></div></section>\
+        <h3>Back up</h3><aside><div><h5>Aside</h5></div></aside></main></body>";
+    let results = HtmlLinter::new(rules, None).lint(html).unwrap();
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Heading level jumped from h2 to h4",
+            "Heading level jumped from h3 to h5",
+        ]
+    );
+}
+
 #[test]
 fn test_semantic_structure() {
     let rules = vec![Rule {
@@ -93,7 +125,7 @@ fn test_semantic_structure() {
         rule_type: RuleType::AttributeValue,
         severity: Severity::Warning,
         selector: "div,span".to_string(),
-        condition: "attribute-value".to_string(),
+        condition: "attribute-value".into(),
         message: "Consider using semantic HTML elements".to_string(),
         options: {
             let mut options = HashMap::new();
@@ -105,6 +137,7 @@ fn test_semantic_structure() {
             options.insert("check_mode".to_string(), "ensure_nonexistence".to_string());
             options
         },
+        ..Default::default()
     }];
 
     let linter = HtmlLinter::new(rules, None);
@@ -186,9 +219,10 @@ fn test_nested_elements() {
         rule_type: RuleType::Nesting,
         severity: Severity::Error,
         selector: "input".to_string(),
-        condition: "parent-label-or-for".to_string(),
+        condition: "parent-label-or-for".into(),
         message: "Input elements should be associated with a label".to_string(),
         options: HashMap::new(),
+        ..Default::default()
     }];
 
     let linter = HtmlLinter::new(rules, None);
@@ -217,7 +251,7 @@ fn test_seo_rules() {
             rule_type: RuleType::ElementContent,
             severity: Severity::Error,
             selector: "head".to_string(),
-            condition: "meta-tags".to_string(),
+            condition: "meta-tags".into(),
             message: "Meta description validation failed".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -235,13 +269,14 @@ fn test_seo_rules() {
                 );
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "og-tags".to_string(),
             rule_type: RuleType::ElementContent,
             severity: Severity::Warning,
             selector: "head".to_string(),
-            condition: "meta-tags".to_string(),
+            condition: "meta-tags".into(),
             message: "Open Graph tag validation failed".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -268,13 +303,14 @@ fn test_seo_rules() {
                 );
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "viewport".to_string(),
             rule_type: RuleType::ElementContent,
             severity: Severity::Error,
             selector: "head".to_string(),
-            condition: "meta-tags".to_string(),
+            condition: "meta-tags".into(),
             message: "Viewport meta tag validation failed".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -292,6 +328,7 @@ fn test_seo_rules() {
                 );
                 options
             },
+            ..Default::default()
         },
     ];
 
@@ -411,6 +448,189 @@ fn test_load_rules_from_file() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_load_rules_from_yaml() {
+    let yaml = r#"
+- name: img-alt
+  rule_type: AttributePresence
+  severity: Error
+  selector: img
+  condition: alt-missing
+  message: Images must have alt attributes
+- name: no-empty-links
+  rule_type: !Custom no-empty-links
+  severity: Warning
+  selector: a
+  condition: no-empty-links
+  message: Links must have content
+  options:
+    check_mode: normal
+"#;
+
+    let linter = HtmlLinter::from_yaml(yaml, None).unwrap();
+    let rules = linter.get_rules();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0].name, "img-alt");
+    assert!(matches!(rules[0].rule_type, RuleType::AttributePresence));
+    assert!(matches!(rules[1].rule_type, RuleType::Custom(ref v) if v == "no-empty-links"));
+    assert_eq!(rules[1].options.get("check_mode").unwrap(), "normal");
+
+    let results = linter.lint(r#"<img src="test.jpg">"#).unwrap();
+    assert_eq!(results.len(), 1);
+
+    // Test malformed YAML
+    let result = HtmlLinter::from_yaml("- name: [unclosed", None);
+    assert!(matches!(result, Err(LinterError::ParseError(_))));
+
+    // Test missing required fields
+    let result = HtmlLinter::from_yaml("- name: test-rule", None);
+    assert!(matches!(result, Err(LinterError::ParseError(_))));
+}
+
+#[test]
+fn test_load_rules_from_yaml_file() {
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    let mut temp_file = NamedTempFile::new().unwrap();
+    let yaml_content = r#"
+- name: file-rule
+  rule_type: ElementPresence
+  severity: Warning
+  selector: span
+  condition: required
+  message: File test message
+"#;
+    write!(temp_file, "{}", yaml_content).unwrap();
+
+    let linter = HtmlLinter::from_yaml_file(temp_file.path().to_str().unwrap(), None).unwrap();
+    let rules = linter.get_rules();
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].name, "file-rule");
+    assert_eq!(rules[0].severity, Severity::Warning);
+
+    let result = HtmlLinter::from_yaml_file("non_existent_file.yaml", None);
+    assert!(matches!(result, Err(LinterError::IoError(_))));
+}
+
+#[test]
+fn test_load_rules_from_toml() {
+    let toml = r#"
+[linter_options]
+severity_map = { img-alt = "Warning" }
+
+[[rules]]
+name = "img-alt"
+rule_type = "AttributePresence"
+severity = "Error"
+selector = "img"
+condition = "alt-missing"
+message = "Images must have alt attributes"
+
+[[rules]]
+name = "no-empty-links"
+rule_type = { Custom = "no-empty-links" }
+severity = "Warning"
+selector = "a"
+condition = "no-empty-links"
+message = "Links must have content"
+
+[rules.options]
+check_mode = "normal"
+"#;
+
+    let linter = HtmlLinter::from_toml(toml, None).unwrap();
+    let rules = linter.get_rules();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0].name, "img-alt");
+    assert!(matches!(rules[0].rule_type, RuleType::AttributePresence));
+    assert!(matches!(rules[1].rule_type, RuleType::Custom(ref v) if v == "no-empty-links"));
+    assert_eq!(rules[1].options.get("check_mode").unwrap(), "normal");
+
+    // [linter_options] applies unless overridden by the options argument
+    let results = linter.lint(r#"<img src="test.jpg">"#).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].severity, Severity::Warning);
+
+    let options = LinterOptions {
+        severity_map: HashMap::from([("img-alt".to_string(), Severity::Info)]),
+        ..LinterOptions::default()
+    };
+    let linter = HtmlLinter::from_toml(toml, Some(options)).unwrap();
+    let results = linter.lint(r#"<img src="test.jpg">"#).unwrap();
+    assert_eq!(results[0].severity, Severity::Info);
+
+    // Test malformed TOML
+    let result = HtmlLinter::from_toml("[[rules]\nname = ", None);
+    assert!(matches!(result, Err(LinterError::TomlError(_))));
+
+    // Test missing required fields
+    let result = HtmlLinter::from_toml("[[rules]]\nname = \"test-rule\"", None);
+    assert!(matches!(result, Err(LinterError::TomlError(_))));
+}
+
+#[test]
+fn test_load_rules_from_config_file() {
+    use std::io::Write;
+    use tempfile::Builder;
+
+    let json = r#"[
+        {
+            "name": "file-rule",
+            "rule_type": "ElementPresence",
+            "severity": "Warning",
+            "selector": "span",
+            "condition": "required",
+            "message": "File test message",
+            "options": { "min": "1" },
+            "tags": ["content"]
+        }
+    ]"#;
+    let yaml = r#"
+- name: file-rule
+  rule_type: ElementPresence
+  severity: Warning
+  selector: span
+  condition: required
+  message: File test message
+  options:
+    min: "1"
+  tags: [content]
+"#;
+    let toml = r#"
+[[rules]]
+name = "file-rule"
+rule_type = "ElementPresence"
+severity = "Warning"
+selector = "span"
+condition = "required"
+message = "File test message"
+options = { min = "1" }
+tags = ["content"]
+"#;
+
+    let mut loaded = Vec::new();
+    for (suffix, content) in [
+        (".json", json),
+        (".yaml", yaml),
+        (".yml", yaml),
+        (".toml", toml),
+    ] {
+        let mut temp_file = Builder::new().suffix(suffix).tempfile().unwrap();
+        write!(temp_file, "{}", content).unwrap();
+        let linter = HtmlLinter::from_config_file(temp_file.path().to_str().unwrap()).unwrap();
+        loaded.push(serde_json::to_string(&linter.get_rules()).unwrap());
+    }
+    assert!(loaded.windows(2).all(|pair| pair[0] == pair[1]));
+
+    let temp_file = Builder::new().suffix(".ini").tempfile().unwrap();
+    let result = HtmlLinter::from_config_file(temp_file.path().to_str().unwrap());
+    assert!(matches!(result, Err(LinterError::ParseError(_))));
+
+    let result = HtmlLinter::from_config_file("non_existent_file.toml");
+    assert!(matches!(result, Err(LinterError::IoError(_))));
+}
+
 #[test]
 fn test_load_complex_rules() {
     let json = r#"[
@@ -430,7 +650,7 @@ fn test_load_complex_rules() {
             "rule_type": "Semantics",
             "severity": "Warning",
             "selector": "div,span",
-            "condition": "semantic-structure",
+            "condition": "semantic-elements",
             "message": "Use semantic elements where appropriate",
             "options": {
                 "semantic_alternatives": "[\"header\",\"main\",\"footer\",\"article\",\"section\",\"nav\"]"
@@ -452,3 +672,301 @@ fn test_load_complex_rules() {
     assert!(matches!(rules[1].rule_type, RuleType::Semantics));
     assert!(rules[1].options.contains_key("semantic_alternatives"));
 }
+
+#[test]
+fn test_condition_types() {
+    let rule: Rule = serde_json::from_value(json!({
+        "name": "img-alt",
+        "rule_type": "AttributePresence",
+        "severity": "Error",
+        "selector": "img",
+        "condition": "alt-missing",
+        "message": "Images must have alt attributes"
+    }))
+    .unwrap();
+    assert_eq!(rule.condition, ConditionType::AltMissing);
+    assert_eq!(
+        serde_json::to_value(&rule).unwrap()["condition"],
+        "alt-missing"
+    );
+
+    // Unknown names are kept for pattern, plugin and custom rules
+    let custom = ConditionType::from("no-empty-links");
+    assert_eq!(custom, ConditionType::Custom("no-empty-links".to_string()));
+    assert!(custom.is_compatible_with(&RuleType::Custom("no-empty-links".to_string())));
+    assert!(custom.is_compatible_with(&RuleType::AttributeValue));
+    assert!(!custom.is_compatible_with(&RuleType::AttributePresence));
+    assert!(ConditionType::Autocomplete.is_compatible_with(&RuleType::FormValidation));
+    assert!(!ConditionType::MaxCount.is_compatible_with(&RuleType::Nesting));
+
+    // A misspelt condition is rejected instead of silently never reporting
+    let json = r#"[{
+        "name": "img-alt",
+        "rule_type": "AttributePresence",
+        "severity": "Error",
+        "selector": "img",
+        "condition": "alt-mising",
+        "message": "Images must have alt attributes"
+    }]"#;
+    let result = HtmlLinter::from_json(json, None);
+    assert!(
+        matches!(result, Err(LinterError::RuleError(message)) if message.contains("alt-mising"))
+    );
+}
+
+#[test]
+fn test_result_context() {
+    let rules = vec![Rule {
+        name: "img-alt".to_string(),
+        rule_type: RuleType::AttributePresence,
+        severity: Severity::Error,
+        selector: "img".to_string(),
+        condition: "alt-missing".into(),
+        message: "Images must have alt attributes".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    }];
+    let linter = HtmlLinter::new(rules, None);
+
+    let html =
+        "<html>\n<body>\n<div>\n<p>Intro</p>\n    <img src='test.jpg'>\n</div>\n</body>\n</html>";
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.line, 5);
+    assert_eq!(results[0].location.column, 5);
+
+    let lines: Vec<&str> = results[0].context.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "2 | <body>",
+            "3 | <div>",
+            "4 | <p>Intro</p>",
+            "5 |     <img src='test.jpg'>",
+            "  |     ^",
+            "6 | </div>",
+            "7 | </body>",
+            "8 | </html>",
+        ]
+    );
+
+    let html = r#"<p>Intro</p><img src="test.jpg">"#;
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results[0].location.line, 1);
+    assert_eq!(results[0].location.column, 13);
+    assert_eq!(results[0].source, r#"<img src="test.jpg">"#);
+
+    // Unknown locations carry no context and omit the field when serialized. The parser
+    // renames `<image>` to `img`, so the element has no start tag of its own.
+    let html = r#"<image src="test.jpg">"#;
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results[0].location.line, 0);
+    assert!(results[0].context.is_empty());
+    let json = serde_json::to_value(&results[0]).unwrap();
+    assert!(json.get("context").is_none());
+}
+
+#[test]
+fn test_meta_tags_scoped_to_selector() {
+    let rules = vec![Rule {
+        name: "meta-description".to_string(),
+        rule_type: RuleType::ElementContent,
+        severity: Severity::Error,
+        selector: "head".to_string(),
+        condition: "meta-tags".into(),
+        message: "Meta description validation failed".to_string(),
+        options: {
+            let mut options = HashMap::new();
+            options.insert(
+                "required_meta_tags".to_string(),
+                json!([{
+                    "name": "description",
+                    "pattern": { "type": "NonEmpty" },
+                    "required": true
+                }])
+                .to_string(),
+            );
+            options
+        },
+        ..Default::default()
+    }];
+    let linter = HtmlLinter::new(rules, None);
+
+    let html =
+        r#"<html><head><meta name="description" content="In the head"></head><body></body></html>"#;
+    assert_eq!(linter.lint(html).unwrap().len(), 0);
+
+    // A meta tag in the body doesn't satisfy a rule scoped to <head>
+    let html = r#"<html><head><title>Page</title></head><body><meta name="description" content="In the body"></body></html>"#;
+    assert_eq!(linter.lint(html).unwrap().len(), 1);
+}
+
+#[test]
+fn test_url_pattern_meta_tags() {
+    let url_rule = |pattern: serde_json::Value| Rule {
+        name: "og-url".to_string(),
+        rule_type: RuleType::ElementContent,
+        severity: Severity::Warning,
+        selector: "head".to_string(),
+        condition: "meta-tags".into(),
+        message: "og:url must be a valid URL".to_string(),
+        options: {
+            let mut options = HashMap::new();
+            options.insert(
+                "required_meta_tags".to_string(),
+                json!([{ "property": "og:url", "pattern": pattern, "required": true }]).to_string(),
+            );
+            options
+        },
+        ..Default::default()
+    };
+    let lint = |linter: &HtmlLinter, url: &str| {
+        let html = format!(
+            r#"<html><head><meta property="og:url" content="{}"></head></html>"#,
+            url
+        );
+        linter.lint(&html).unwrap().len()
+    };
+
+    let linter = HtmlLinter::new(vec![url_rule(json!({ "type": "UrlPattern" }))], None);
+    assert_eq!(lint(&linter, "https://example.com/page"), 0);
+    assert_eq!(lint(&linter, "http://example.com/page"), 0);
+    assert_eq!(lint(&linter, "ftp://example.com/file"), 1);
+    assert_eq!(lint(&linter, "/relative/page"), 1);
+    assert_eq!(lint(&linter, "https://exa mple.com"), 1);
+    assert_eq!(lint(&linter, ""), 1);
+
+    let linter = HtmlLinter::new(
+        vec![url_rule(json!({
+            "type": "UrlPattern",
+            "require_https": true,
+            "allow_relative": true
+        }))],
+        None,
+    );
+    assert_eq!(lint(&linter, "https://example.com/page"), 0);
+    assert_eq!(lint(&linter, "http://example.com/page"), 1);
+    assert_eq!(lint(&linter, "/relative/page"), 0);
+
+    let linter = HtmlLinter::new(
+        vec![url_rule(
+            json!({ "type": "UrlPattern", "schemes": ["ftp"] }),
+        )],
+        None,
+    );
+    assert_eq!(lint(&linter, "ftp://example.com/file"), 0);
+    assert_eq!(lint(&linter, "https://example.com/page"), 1);
+}
+
+#[test]
+fn test_length_meta_tag_patterns() {
+    let meta_rule = |pattern: serde_json::Value| Rule {
+        name: "meta-description".to_string(),
+        rule_type: RuleType::ElementContent,
+        severity: Severity::Warning,
+        selector: "head".to_string(),
+        condition: "meta-tags".into(),
+        message: "Meta description has the wrong length".to_string(),
+        options: {
+            let mut options = HashMap::new();
+            options.insert(
+                "required_meta_tags".to_string(),
+                json!([{ "name": "description", "pattern": pattern, "required": true }])
+                    .to_string(),
+            );
+            options
+        },
+        ..Default::default()
+    };
+    let lint = |linter: &HtmlLinter, content: &str| {
+        let html = format!(
+            r#"<html><head><meta name="description" content="{}"></head></html>"#,
+            content
+        );
+        linter.lint(&html).unwrap().len()
+    };
+
+    let linter = HtmlLinter::new(
+        vec![meta_rule(json!({ "type": "MaxLength", "value": 10 }))],
+        None,
+    );
+    assert_eq!(lint(&linter, "0123456789"), 0);
+    assert_eq!(lint(&linter, "01234567890"), 1);
+    // Limits count characters rather than bytes
+    assert_eq!(lint(&linter, "éééééééééé"), 0);
+
+    let linter = HtmlLinter::new(
+        vec![meta_rule(json!({ "type": "ExactLength", "value": 7 }))],
+        None,
+    );
+    assert_eq!(lint(&linter, "summary"), 0);
+    assert_eq!(lint(&linter, "summary_large_image"), 1);
+
+    let linter = HtmlLinter::new(
+        vec![meta_rule(json!({ "type": "MinLength", "value": 5 }))],
+        None,
+    );
+    assert_eq!(lint(&linter, "ééééé"), 0);
+    assert_eq!(lint(&linter, "éééé"), 1);
+
+    let linter = HtmlLinter::new(
+        vec![meta_rule(
+            json!({ "type": "LengthRange", "min": 2, "max": 4 }),
+        )],
+        None,
+    );
+    assert_eq!(lint(&linter, "日本語"), 0);
+    assert_eq!(lint(&linter, "日本語です"), 1);
+
+    let linter = HtmlLinter::new(vec![meta_rule(json!({ "type": "NotEmpty" }))], None);
+    assert_eq!(lint(&linter, "Described"), 0);
+    assert_eq!(lint(&linter, "   "), 1);
+}
+
+#[test]
+fn test_json_schema_meta_tags() {
+    let schema_rule = |pattern: serde_json::Value| Rule {
+        name: "app-config".to_string(),
+        rule_type: RuleType::ElementContent,
+        severity: Severity::Error,
+        selector: "head".to_string(),
+        condition: "meta-tags".into(),
+        message: "app-config must be valid JSON".to_string(),
+        options: {
+            let mut options = HashMap::new();
+            options.insert(
+                "required_meta_tags".to_string(),
+                json!([{ "name": "app-config", "pattern": pattern, "required": true }]).to_string(),
+            );
+            options
+        },
+        ..Default::default()
+    };
+    let schema = json!({
+        "type": "object",
+        "required": ["@context", "@type"]
+    });
+    let lint = |linter: &HtmlLinter, content: &str| {
+        let html = format!(
+            r#"<html><head><meta name="app-config" content='{}'></head></html>"#,
+            content
+        );
+        linter.lint(&html).unwrap().len()
+    };
+
+    for type_name in ["JsonSchema", "JsonSchemaDraft07"] {
+        let linter = HtmlLinter::new(
+            vec![schema_rule(json!({ "type": type_name, "schema": schema }))],
+            None,
+        );
+        assert_eq!(
+            lint(
+                &linter,
+                r#"{"@context": "https://schema.org", "@type": "WebSite"}"#
+            ),
+            0
+        );
+        assert_eq!(lint(&linter, r#"{"@context": "https://schema.org"}"#), 1);
+        assert_eq!(lint(&linter, "not json"), 1);
+    }
+}
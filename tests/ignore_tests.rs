@@ -0,0 +1,64 @@
+mod common;
+
+use html_linter::{HtmlLinter, LinterOptions, Rule, RuleType, Severity};
+
+fn img_alt_rule() -> Rule {
+    Rule {
+        name: "img-alt".to_string(),
+        ..common::rule(
+            RuleType::AttributePresence,
+            Severity::Error,
+            "img",
+            "alt-missing",
+            "Images must have alt attributes",
+            &[],
+        )
+    }
+}
+
+const HTML: &str = "<html><body>
+<img src='logo.png'>
+<div class='widget' data-no-lint>
+  <section><img src='ad.png'></section>
+</div>
+<div id='generated'><img src='chart.png'></div>
+</body></html>";
+
+fn reported_lines(options: LinterOptions) -> Vec<usize> {
+    HtmlLinter::new(vec![img_alt_rule()], Some(options))
+        .lint(HTML)
+        .unwrap()
+        .into_iter()
+        .map(|r| r.location.line)
+        .collect()
+}
+
+#[test]
+fn test_nothing_ignored_by_default() {
+    assert_eq!(reported_lines(LinterOptions::default()), vec![2, 4, 6]);
+}
+
+#[test]
+fn test_ignore_data_attribute() {
+    let options = LinterOptions {
+        ignore_data_attribute: Some("data-no-lint".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(reported_lines(options), vec![2, 6]);
+}
+
+#[test]
+fn test_ignore_selectors() {
+    let options = LinterOptions {
+        ignore_selectors: vec!["#generated".to_string(), ".widget".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(reported_lines(options), vec![2]);
+
+    // Matching the reported element itself also ignores it
+    let options = LinterOptions {
+        ignore_selectors: vec!["img[src='logo.png']".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(reported_lines(options), vec![4, 6]);
+}
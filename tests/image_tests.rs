@@ -8,7 +8,7 @@ fn setup_image_rules() -> Vec<Rule> {
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "img".to_string(),
-            condition: "dimensions-present".to_string(),
+            condition: "dimensions-present".into(),
             message: "Images should not specify width and height attributes - use CSS instead"
                 .to_string(),
             options: {
@@ -18,13 +18,14 @@ fn setup_image_rules() -> Vec<Rule> {
                 options.insert("pattern".to_string(), r#"^\d+$"#.to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "img-loading".to_string(),
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "img".to_string(),
-            condition: "loading-attribute".to_string(),
+            condition: "loading-attribute".into(),
             message: "Images should have a loading attribute with value 'lazy' or 'eager'"
                 .to_string(),
             options: {
@@ -34,13 +35,14 @@ fn setup_image_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "loading".to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "img-format".to_string(),
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "img".to_string(),
-            condition: "file-extension".to_string(),
+            condition: "file-extension".into(),
             message: "Use modern image formats".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -52,13 +54,14 @@ fn setup_image_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "src".to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "img-filename".to_string(),
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "img".to_string(),
-            condition: "filename-pattern".to_string(),
+            condition: "filename-pattern".into(),
             message: "Image filenames should be descriptive".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -67,6 +70,7 @@ fn setup_image_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "src".to_string());
                 options
             },
+            ..Default::default()
         },
     ]
 }
@@ -7,9 +7,10 @@ fn create_img_alt_linter() -> HtmlLinter {
         rule_type: RuleType::AttributePresence,
         severity: Severity::Error,
         selector: "img".to_string(),
-        condition: "alt-missing".to_string(),
+        condition: "alt-missing".into(),
         message: "Images must have alt attributes".to_string(),
         options: HashMap::new(),
+        ..Default::default()
     }];
 
     HtmlLinter::new(rules, None)
@@ -7,9 +7,10 @@ fn create_inline_styles_linter() -> HtmlLinter {
         rule_type: RuleType::AttributePresence,
         severity: Severity::Warning,
         selector: "*".to_string(),
-        condition: "style-attribute".to_string(),
+        condition: "style-attribute".into(),
         message: "Inline styles should be avoided".to_string(),
         options: HashMap::new(),
+        ..Default::default()
     }];
 
     HtmlLinter::new(rules, None)
@@ -0,0 +1,197 @@
+mod common;
+
+use html_linter::{HtmlLinter, LinterError, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn json_ld_rule(options: HashMap<String, String>) -> Rule {
+    Rule {
+        name: "structured-data".to_string(),
+        options,
+        ..common::rule(
+            RuleType::JsonLd,
+            Severity::Error,
+            "script[type='application/ld+json']",
+            "json-ld-validation",
+            "Structured data is invalid",
+            &[],
+        )
+    }
+}
+
+const ARTICLE: &str = r#"
+<html><head>
+    <script type="application/ld+json">
+    {
+        "@context": "https://schema.org",
+        "@type": "Article",
+        "headline": "Article Title",
+        "author": { "@type": "Person", "name": "Jane Doe" },
+        "datePublished": null
+    }
+    </script>
+</head></html>
+"#;
+
+#[test]
+fn test_invalid_json() {
+    let linter = HtmlLinter::new(vec![json_ld_rule(HashMap::new())], None);
+    let html = r#"<html><head><script type="application/ld+json">{ "invalid": "json" "schema" }</script></head></html>"#;
+
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("invalid JSON"));
+    assert!(results[0].source.starts_with("{ \"invalid\""));
+}
+
+#[test]
+fn test_required_type_and_context() {
+    let mut options = HashMap::new();
+    options.insert("required_type".to_string(), "Article".to_string());
+    options.insert(
+        "schema_version".to_string(),
+        "https://schema.org/".to_string(),
+    );
+    let linter = HtmlLinter::new(vec![json_ld_rule(options.clone())], None);
+    assert_eq!(linter.lint(ARTICLE).unwrap().len(), 0);
+
+    options.insert("required_type".to_string(), "Product".to_string());
+    let linter = HtmlLinter::new(vec![json_ld_rule(options)], None);
+    let results = linter.lint(ARTICLE).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("Product"));
+}
+
+#[test]
+fn test_required_fields() {
+    let mut options = HashMap::new();
+    options.insert(
+        "required_fields".to_string(),
+        r#"["/headline", "/author/name", "/datePublished", "/image"]"#.to_string(),
+    );
+    let linter = HtmlLinter::new(vec![json_ld_rule(options)], None);
+
+    let results = linter.lint(ARTICLE).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("'/datePublished'"));
+    assert!(results[0].message.contains("'/image'"));
+    assert!(!results[0].message.contains("'/headline'"));
+}
+
+#[test]
+fn test_required_schemas_across_blocks() {
+    let mut options = HashMap::new();
+    options.insert(
+        "required_schemas".to_string(),
+        r#"["Article", "Organization"]"#.to_string(),
+    );
+    let linter = HtmlLinter::new(vec![json_ld_rule(options)], None);
+
+    let results = linter.lint(ARTICLE).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("Organization"));
+    assert!(!results[0].message.contains("Article"));
+
+    let html = r#"
+        <html><head>
+            <script type="application/ld+json">{"@type": "Article"}</script>
+            <script type="application/ld+json">{"@graph": [{"@type": "Organization"}]}</script>
+        </head></html>
+    "#;
+    assert_eq!(linter.lint(html).unwrap().len(), 0);
+
+    let results = linter.lint("<html><head></head></html>").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        (results[0].location.line, results[0].location.column),
+        (1, 1)
+    );
+
+    let html = "<html><head>\n<script type='application/ld+json'>{\"@type\": \"Article\"}</script>\n</head></html>";
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        (results[0].location.line, results[0].location.column),
+        (2, 1)
+    );
+    assert_eq!(results[0].location.element, "script");
+}
+
+#[test]
+fn test_json_schema() {
+    let schema = r#"{
+        "type": "object",
+        "required": ["@context", "@type"],
+        "properties": {
+            "@context": { "const": "https://schema.org" },
+            "@type": { "type": "string" },
+            "headline": { "type": "string", "maxLength": 20 }
+        }
+    }"#;
+    let options = HashMap::from([("schema".to_string(), schema.to_string())]);
+    let linter = HtmlLinter::new(vec![json_ld_rule(options)], None);
+    assert_eq!(linter.lint(ARTICLE).unwrap().len(), 0);
+
+    let html = r#"
+        <html><head>
+            <script type="application/ld+json">
+            { "@context": "https://schema.org", "headline": "A headline that is far too long" }
+            </script>
+        </head></html>
+    "#;
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0]
+        .message
+        .contains("/: \"@type\" is a required property"));
+    assert!(results[0]
+        .message
+        .contains("/headline: \"A headline that is far too long\" is longer than 20 characters"));
+    assert!(!results[0].message.contains("@context"));
+
+    let options = HashMap::from([("schema".to_string(), "{ not json".to_string())]);
+    let linter = HtmlLinter::new(vec![json_ld_rule(options)], None);
+    assert!(linter.lint(ARTICLE).is_err());
+}
+
+#[test]
+fn test_json_schema_refs_and_formats() {
+    let schema = r##"{
+        "definitions": {
+            "url": { "type": "string", "format": "uri" }
+        },
+        "type": "object",
+        "properties": {
+            "@context": { "$ref": "#/definitions/url" },
+            "url": { "$ref": "#/definitions/url" }
+        }
+    }"##;
+    let options = HashMap::from([("schema".to_string(), schema.to_string())]);
+    let linter = HtmlLinter::new(vec![json_ld_rule(options)], None);
+    assert_eq!(linter.lint(ARTICLE).unwrap().len(), 0);
+
+    let html = r#"
+        <html><head>
+            <script type="application/ld+json">
+            { "@context": "https://schema.org", "url": "not a url" }
+            </script>
+        </head></html>
+    "#;
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0]
+        .message
+        .contains("/url: \"not a url\" is not a \"uri\""));
+}
+
+#[test]
+fn test_invalid_json_schema_is_rule_error() {
+    let schema = r#"{ "properties": { "headline": { "type": "string", "pattern": "(" } } }"#;
+    let options = HashMap::from([("schema".to_string(), schema.to_string())]);
+    let linter = HtmlLinter::new(vec![json_ld_rule(options)], None);
+    match linter.lint(ARTICLE) {
+        Err(LinterError::RuleError(message)) => {
+            assert!(message.starts_with("Invalid JSON schema"))
+        }
+        other => panic!("expected a rule error, got {:?}", other),
+    }
+}
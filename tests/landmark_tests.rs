@@ -0,0 +1,75 @@
+mod common;
+
+use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+
+fn landmark_rule() -> Rule {
+    common::rule(
+        RuleType::Semantics,
+        Severity::Warning,
+        "body",
+        "landmark-uniqueness",
+        "Landmark should be unique",
+        &[],
+    )
+}
+
+fn lint(html: &str) -> Vec<html_linter::LintResult> {
+    HtmlLinter::new(vec![landmark_rule()], None)
+        .lint(html)
+        .unwrap()
+}
+
+#[test]
+fn test_unique_landmarks_pass() {
+    let html = "<html><body><header>Site</header><main><p>Content</p></main><footer>Legal</footer></body></html>";
+    assert!(lint(html).is_empty());
+}
+
+#[test]
+fn test_sectioning_headers_and_footers_are_allowed() {
+    let html = "<html><body>
+<header>Site</header>
+<main>
+  <header>Page title</header>
+  <article><header>Post</header><footer>Posted today</footer></article>
+  <section><header>Comments</header><footer>More</footer></section>
+</main>
+<aside><footer>Related</footer></aside>
+<footer>Legal</footer>
+</body></html>";
+    assert!(lint(html).is_empty());
+}
+
+#[test]
+fn test_duplicate_main_reports_second_occurrence() {
+    let html = "<html><body>\n<main id='first'>First</main>\n<main id='second'>Second</main>\n</body></html>";
+    let results = lint(html);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "main");
+    assert_eq!(results[0].location.line, 3);
+    assert!(results[0]
+        .message
+        .contains("more than one page-level <main>"));
+}
+
+#[test]
+fn test_duplicate_page_level_header_and_footer() {
+    let html = "<html><body>
+<header>Site</header>
+<div><header class='banner'>Second banner</header></div>
+<main><p>Content</p></main>
+<footer>Legal</footer>
+<footer class='legal'>More legal</footer>
+</body></html>";
+    let results = lint(html);
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Landmark should be unique (more than one page-level <header>)",
+            "Landmark should be unique (more than one page-level <footer>)",
+        ]
+    );
+    assert_eq!(results[0].location.line, 3);
+    assert_eq!(results[1].location.line, 6);
+}
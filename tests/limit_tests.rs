@@ -0,0 +1,196 @@
+use html_linter::{HtmlLinter, LinterOptions, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn rules() -> Vec<Rule> {
+    vec![
+        Rule {
+            name: "img-alt".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Error,
+            selector: "img".to_string(),
+            condition: "alt-missing".into(),
+            message: "Images must have alt attributes".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+        Rule {
+            name: "no-inline-styles".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Warning,
+            selector: "*".to_string(),
+            condition: "style-attribute".into(),
+            message: "Inline styles should be avoided".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+        Rule {
+            name: "no-marquee".to_string(),
+            rule_type: RuleType::ElementPresence,
+            severity: Severity::Error,
+            selector: "marquee".to_string(),
+            condition: "forbidden".into(),
+            message: "Marquee is obsolete".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+    ]
+}
+
+// 4 img-alt errors, 2 inline style warnings and 1 marquee error
+const HTML: &str = "<html><body>
+<img src='1.png'><img src='2.png'><img src='3.png'><img src='4.png'>
+<p style='color: red'>One</p><p style='color: blue'>Two</p>
+<marquee>Old</marquee>
+</body></html>";
+
+fn lint(options: LinterOptions) -> Vec<(String, Severity)> {
+    HtmlLinter::new(rules(), Some(options))
+        .lint(HTML)
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.rule, r.severity))
+        .collect()
+}
+
+#[test]
+fn test_no_limits_by_default() {
+    assert_eq!(lint(LinterOptions::default()).len(), 7);
+}
+
+#[test]
+fn test_max_errors_stops_early() {
+    let results = lint(LinterOptions {
+        max_errors: Some(3),
+        ..Default::default()
+    });
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|(rule, _)| rule == "img-alt"));
+
+    let results = lint(LinterOptions {
+        max_errors: Some(5),
+        ..Default::default()
+    });
+    // Warnings don't count, so the remaining rules still run until the fifth error
+    let errors = results
+        .iter()
+        .filter(|(_, severity)| *severity == Severity::Error)
+        .count();
+    assert_eq!(errors, 5);
+    assert_eq!(results.len(), 7);
+    assert_eq!(results.last().unwrap().0, "no-marquee");
+}
+
+#[test]
+fn test_max_errors_counting_warnings() {
+    let results = lint(LinterOptions {
+        max_errors: Some(5),
+        max_count_includes_warnings: true,
+        ..Default::default()
+    });
+    assert_eq!(results.len(), 5);
+    assert_eq!(results[4].0, "no-inline-styles");
+}
+
+#[test]
+fn test_max_errors_per_rule() {
+    let results = lint(LinterOptions {
+        max_errors_per_rule: Some(1),
+        ..Default::default()
+    });
+    let rules: Vec<&str> = results.iter().map(|(rule, _)| rule.as_str()).collect();
+    assert_eq!(
+        rules,
+        vec![
+            "img-alt",
+            "no-inline-styles",
+            "no-inline-styles",
+            "no-marquee"
+        ]
+    );
+
+    let results = lint(LinterOptions {
+        max_errors_per_rule: Some(1),
+        max_count_includes_warnings: true,
+        ..Default::default()
+    });
+    assert_eq!(results.len(), 3);
+}
+
+#[test]
+fn test_max_errors_counts_promoted_warnings() {
+    let options = LinterOptions {
+        max_errors: Some(5),
+        treat_warnings_as_errors: true,
+        ..Default::default()
+    };
+    let results = lint(options.clone());
+    // The inline style warnings are errors by the time they're counted
+    assert_eq!(results.len(), 5);
+    assert_eq!(
+        results[4],
+        ("no-inline-styles".to_string(), Severity::Error)
+    );
+
+    let stats = HtmlLinter::new(rules(), Some(options))
+        .dry_run(HTML)
+        .unwrap();
+    assert_eq!(stats.error_count, 5);
+    assert_eq!(stats.warning_count, 0);
+}
+
+#[test]
+fn test_max_errors_zero() {
+    let results = lint(LinterOptions {
+        max_errors: Some(0),
+        ..Default::default()
+    });
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_max_errors_per_rule_marks_truncation() {
+    let lint_img_alt = |max: usize| {
+        let options = LinterOptions {
+            max_errors_per_rule: Some(max),
+            ..Default::default()
+        };
+        HtmlLinter::new(rules(), Some(options))
+            .lint(HTML)
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.rule == "img-alt")
+            .collect::<Vec<_>>()
+    };
+
+    // Exactly the limit is kept out of the four violations, the last one flagged
+    let results = lint_img_alt(2);
+    let truncated: Vec<bool> = results.iter().map(|r| r.truncated).collect();
+    assert_eq!(truncated, vec![false, true]);
+    assert!(results[1].source.contains("2.png"));
+
+    // Nothing was dropped, so nothing is flagged
+    let results = lint_img_alt(4);
+    assert_eq!(results.len(), 4);
+    assert!(results.iter().all(|r| !r.truncated));
+
+    let json = serde_json::to_string(&lint_img_alt(1)[0]).unwrap();
+    assert!(json.contains("\"truncated\":true"));
+}
+
+#[test]
+fn test_max_errors_per_rule_truncates_promoted_warnings() {
+    let options = LinterOptions {
+        max_errors_per_rule: Some(1),
+        treat_warnings_as_errors: true,
+        ..Default::default()
+    };
+    let results: Vec<_> = HtmlLinter::new(rules(), Some(options))
+        .lint(HTML)
+        .unwrap()
+        .into_iter()
+        .filter(|r| r.rule == "no-inline-styles")
+        .collect();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].severity, Severity::Error);
+    assert!(results[0].truncated);
+}
@@ -8,7 +8,7 @@ fn setup_link_rules() -> Vec<Rule> {
             rule_type: RuleType::TextContent,
             severity: Severity::Warning,
             selector: "a".to_string(),
-            condition: "descriptive-text".to_string(),
+            condition: "descriptive-text".into(),
             message: "Link text should be descriptive (avoid 'click here', 'learn more', etc.)"
                 .to_string(),
             options: {
@@ -20,13 +20,14 @@ fn setup_link_rules() -> Vec<Rule> {
                 options.insert("check_mode".to_string(), "ensure_nonexistence".to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "link-target".to_string(),
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "a[target='_blank']".to_string(),
-            condition: "security-rel".to_string(),
+            condition: "security-rel".into(),
             message: "Links opening in new tabs should have rel='noopener noreferrer'".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -35,13 +36,14 @@ fn setup_link_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "rel".to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "link-href-javascript".to_string(),
             rule_type: RuleType::AttributeValue,
             severity: Severity::Error,
             selector: "a".to_string(),
-            condition: "valid-href".to_string(),
+            condition: "valid-href".into(),
             message: "Links should have a valid href attribute".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -50,13 +52,14 @@ fn setup_link_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "href".to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "link-href".to_string(),
             rule_type: RuleType::AttributeValue,
             severity: Severity::Error,
             selector: "a".to_string(),
-            condition: "valid-href".to_string(),
+            condition: "valid-href".into(),
             message: "Links should have a valid href attribute".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -65,13 +68,14 @@ fn setup_link_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "href".to_string());
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "link-underline".to_string(),
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "a".to_string(),
-            condition: "text-decoration".to_string(),
+            condition: "text-decoration".into(),
             message: "Links should be visually distinct (underlined by default)".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -83,6 +87,7 @@ fn setup_link_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
     ]
 }
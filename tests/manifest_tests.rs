@@ -0,0 +1,140 @@
+mod common;
+
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+
+fn manifest_rule(condition: &str, options: &[(&str, &str)]) -> Rule {
+    Rule {
+        name: "web-app-manifest".to_string(),
+        ..common::rule(
+            RuleType::Manifest,
+            Severity::Warning,
+            "link[rel~=manifest]",
+            condition,
+            "Invalid web app manifest link",
+            options,
+        )
+    }
+}
+
+fn messages(rule: Rule, html: &str) -> Vec<String> {
+    HtmlLinter::new(vec![rule], None)
+        .lint(html)
+        .unwrap()
+        .into_iter()
+        .map(|result: LintResult| result.message)
+        .collect()
+}
+
+#[test]
+fn test_manifest_link_valid() {
+    let rule = manifest_rule("manifest-link", &[]);
+    let html =
+        "<html><head><link rel='manifest' href='/site.webmanifest'></head><body></body></html>";
+    assert!(messages(rule, html).is_empty());
+}
+
+#[test]
+fn test_manifest_link_missing_or_misplaced() {
+    let rule = manifest_rule("manifest-link", &[]);
+    assert_eq!(
+        messages(rule.clone(), "<html><head></head><body></body></html>"),
+        vec!["Invalid web app manifest link (missing <link rel=\"manifest\">)"]
+    );
+
+    let html = "<html><head></head><body><p>Hi</p><link rel='manifest' href=''></body></html>";
+    assert_eq!(
+        messages(rule, html),
+        vec![
+            "Invalid web app manifest link (manifest link must be in <head>)",
+            "Invalid web app manifest link (missing href)",
+        ]
+    );
+}
+
+#[test]
+fn test_manifest_link_cross_origin() {
+    let html = "<html><head><link rel='manifest' href='https://cdn.example.com/app.webmanifest'></head></html>";
+    assert_eq!(
+        messages(manifest_rule("manifest-link", &[]), html),
+        vec![
+            "Invalid web app manifest link (manifest href \"https://cdn.example.com/app.webmanifest\" should be a relative URL)",
+            "Invalid web app manifest link (missing crossorigin attribute)",
+        ]
+    );
+
+    let rule = manifest_rule("manifest-link", &[("allow_absolute", "true")]);
+    assert_eq!(
+        messages(rule.clone(), html),
+        vec!["Invalid web app manifest link (missing crossorigin attribute)"]
+    );
+
+    let html = "<html><head><link rel='manifest' href='https://cdn.example.com/app.webmanifest' crossorigin='use-credentials'></head></html>";
+    assert!(messages(rule, html).is_empty());
+}
+
+#[test]
+fn test_manifest_link_crossorigin_values() {
+    let rule = manifest_rule("manifest-link", &[("require_crossorigin", "true")]);
+    let html = "<html><head><link rel='manifest' href='/app.webmanifest'></head></html>";
+    assert_eq!(
+        messages(rule.clone(), html),
+        vec!["Invalid web app manifest link (missing crossorigin attribute)"]
+    );
+
+    let html = "<html><head><link rel='manifest' href='/app.webmanifest' crossorigin='anonymous'></head></html>";
+    assert!(messages(rule.clone(), html).is_empty());
+
+    let html = "<html><head><link rel='manifest' href='/app.webmanifest' crossorigin='always'></head></html>";
+    assert_eq!(
+        messages(rule, html),
+        vec!["Invalid web app manifest link (crossorigin must be \"anonymous\" or \"use-credentials\", not \"always\")"]
+    );
+}
+
+#[test]
+fn test_web_app_capable() {
+    let rule = manifest_rule("web-app-capable", &[]);
+    let html = "<html><head><meta name='mobile-web-app-capable' content='yes'></head></html>";
+    assert!(messages(rule.clone(), html).is_empty());
+
+    let html = "<html><head><meta name='apple-mobile-web-app-capable' content='yes'></head></html>";
+    assert!(messages(rule.clone(), html).is_empty());
+
+    let html = "<html><head><meta name='viewport' content='width=device-width'></head></html>";
+    assert_eq!(
+        messages(rule, html),
+        vec!["Invalid web app manifest link (missing <meta name=\"mobile-web-app-capable\">)"]
+    );
+}
+
+#[test]
+fn test_manifest_link_protocol_relative() {
+    let html =
+        "<html><head><link rel='manifest' href='//cdn.example.com/app.webmanifest'></head></html>";
+    assert_eq!(
+        messages(
+            manifest_rule("manifest-link", &[("allow_absolute", "true")]),
+            html
+        ),
+        vec!["Invalid web app manifest link (missing crossorigin attribute)"]
+    );
+}
+
+#[test]
+fn test_manifest_link_document_origin() {
+    let rule = manifest_rule("manifest-link", &[("allow_absolute", "true")]);
+    let html = "<html><head><base href='https://example.com/app/'>\
+        <link rel='manifest' href='https://example.com/app.webmanifest'></head></html>";
+    assert!(messages(rule.clone(), html).is_empty());
+
+    let html = "<html><head><link rel='canonical' href='https://example.com/'>\
+        <link rel='manifest' href='//example.com/app.webmanifest'></head></html>";
+    assert!(messages(rule.clone(), html).is_empty());
+
+    let html = "<html><head><link rel='canonical' href='https://example.com/'>\
+        <link rel='manifest' href='https://cdn.example.com/app.webmanifest'></head></html>";
+    assert_eq!(
+        messages(rule, html),
+        vec!["Invalid web app manifest link (missing crossorigin attribute)"]
+    );
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+
+fn microdata_rule(options: &[(&str, &str)]) -> Rule {
+    common::rule(
+        RuleType::Microdata,
+        Severity::Warning,
+        "[itemscope]",
+        "microdata",
+        "Invalid microdata",
+        options,
+    )
+}
+
+fn lint(rule: Rule, html: &str) -> Vec<LintResult> {
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+fn messages(results: &[LintResult]) -> Vec<&str> {
+    results
+        .iter()
+        .map(|result| result.message.as_str())
+        .collect()
+}
+
+#[test]
+fn test_valid_microdata() {
+    let html = "<div itemscope itemtype='https://schema.org/Product'>\
+        <span itemprop='name'>Widget</span>\
+        <img itemprop='image' src='widget.png' alt='Widget'>\
+        <a itemprop='url' href='/widget'>Details</a>\
+        <meta itemprop='sku' content='W-1'>\
+        <time itemprop='releaseDate' datetime='2024-01-01'>New Year</time>\
+        <div itemprop='offers' itemscope itemtype='https://schema.org/Offer'>\
+        <data itemprop='price' value='9.99'>$9.99</data>\
+        </div></div>";
+    let results = lint(microdata_rule(&[]), html);
+    assert!(results.is_empty(), "{:?}", messages(&results));
+}
+
+#[test]
+fn test_missing_and_invalid_itemtype() {
+    let html = "<div itemscope='' class='untyped'><span itemprop='name'>A</span></div>\
+        <div itemscope='' itemtype='Product' class='relative'><span itemprop='name'>B</span></div>";
+    let results = lint(microdata_rule(&[]), html);
+    assert_eq!(
+        messages(&results),
+        vec![
+            "Invalid microdata (missing itemtype)",
+            r#"Invalid microdata (itemtype "Product" is not a valid URL)"#,
+        ]
+    );
+    // Bare boolean attributes can't be located in the source, hence `itemscope=''`
+    assert!(results[0].source.contains("untyped"));
+    assert!(results[1].source.contains("relative"));
+}
+
+#[test]
+fn test_empty_property_values() {
+    let html = "<div itemscope itemtype='https://schema.org/Person'>\
+        <span itemprop='name'> </span>\
+        <img itemprop='image' alt='Portrait'>\
+        <meta itemprop='email' content=''>\
+        <a itemprop='url'>Homepage</a>\
+        <span itemprop='jobTitle'>Engineer</span>\
+        </div>";
+    let results = lint(microdata_rule(&[]), html);
+    assert_eq!(
+        messages(&results),
+        vec![
+            r#"Invalid microdata (itemprop "name" has no value)"#,
+            r#"Invalid microdata (itemprop "image" has no value)"#,
+            r#"Invalid microdata (itemprop "email" has no value)"#,
+            r#"Invalid microdata (itemprop "url" has no value)"#,
+        ]
+    );
+}
+
+#[test]
+fn test_nested_itemscope_needs_itemprop() {
+    let html = "<div itemscope itemtype='https://schema.org/Event'>\
+        <span itemprop='name'>Concert</span>\
+        <div itemscope='' itemtype='https://schema.org/Place' class='venue'>\
+        <span itemprop='name'>Hall</span>\
+        <span itemprop='address'></span>\
+        </div></div>";
+    let results = lint(microdata_rule(&[]), html);
+    assert_eq!(
+        messages(&results),
+        vec![
+            "Invalid microdata (nested itemscope has no itemprop)",
+            r#"Invalid microdata (itemprop "address" has no value)"#,
+        ]
+    );
+    // Both problems belong to the nested item, not the event
+    assert!(results.iter().all(|result| result.source.contains("venue")));
+}
+
+#[test]
+fn test_required_types() {
+    let rule = microdata_rule(&[(
+        "required_types",
+        r#"["https://schema.org/Product", "https://schema.org/Offer"]"#,
+    )]);
+    let html =
+        "<div itemscope itemtype='https://schema.org/Product'><span itemprop='name'>A</span></div>\
+        <div itemscope itemtype='https://schema.org/Recipe'><span itemprop='name'>B</span></div>";
+    let results = lint(rule, html);
+    assert_eq!(
+        messages(&results),
+        vec![
+            r#"Invalid microdata (itemtype "https://schema.org/Recipe" is not one of the accepted types)"#
+        ]
+    );
+}
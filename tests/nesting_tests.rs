@@ -0,0 +1,352 @@
+mod common;
+
+use html_linter::{HtmlLinter, LinterOptions, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn max_depth_rule(selector: &str, max: usize) -> Rule {
+    common::rule(
+        RuleType::Nesting,
+        Severity::Warning,
+        selector,
+        "max-depth",
+        "Too deeply nested",
+        &[("max", &max.to_string())],
+    )
+}
+
+// html(1) > body(2) > div(3) > div(4) > div(5) > span(6)
+const DEEP_HTML: &str =
+    "<html><body><div class='a'><div class='b'><div class='c'><span>Deep</span></div></div></div></body></html>";
+
+#[test]
+fn test_max_depth_reports_deepest_element() {
+    let linter = HtmlLinter::new(vec![max_depth_rule("div", 3)], None);
+
+    let results = linter.lint(DEEP_HTML).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "div");
+    assert!(results[0].message.contains("depth 5 exceeds maximum of 3"));
+
+    let linter = HtmlLinter::new(vec![max_depth_rule("div", 5)], None);
+    assert_eq!(linter.lint(DEEP_HTML).unwrap().len(), 0);
+}
+
+#[test]
+fn test_max_depth_per_selector() {
+    let linter = HtmlLinter::new(
+        vec![max_depth_rule("div", 5), max_depth_rule("table, td", 10)],
+        None,
+    );
+
+    let html = "<html><body><table><tbody><tr><td><table><tbody><tr><td>Cell</td></tr></tbody></table></td></tr></tbody></table></body></html>";
+    assert_eq!(linter.lint(html).unwrap().len(), 0);
+}
+
+#[test]
+fn test_max_depth_requires_max_option() {
+    let mut rule = max_depth_rule("div", 3);
+    rule.options.clear();
+    let linter = HtmlLinter::new(vec![rule], None);
+    assert!(linter.lint(DEEP_HTML).is_err());
+}
+
+#[test]
+fn test_max_nesting_depth_option() {
+    let options = LinterOptions {
+        max_nesting_depth: Some(4),
+        ..Default::default()
+    };
+    let linter = HtmlLinter::new(vec![], Some(options));
+
+    let results = linter.lint(DEEP_HTML).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].rule, "max-nesting-depth");
+    assert_eq!(results[0].location.element, "span");
+    assert!(results[0].message.contains("depth 6 exceeds maximum of 4"));
+
+    let linter = HtmlLinter::new(vec![], None);
+    assert_eq!(linter.lint(DEEP_HTML).unwrap().len(), 0);
+}
+
+fn interactive_rule() -> Rule {
+    Rule {
+        name: "interactive-nesting".to_string(),
+        ..common::rule(
+            RuleType::Nesting,
+            Severity::Error,
+            "*",
+            "interactive-in-interactive",
+            "Interactive content cannot be nested",
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_button_inside_link() {
+    let linter = HtmlLinter::new(vec![interactive_rule()], None);
+
+    let results = linter
+        .lint("<a href='/cart'><button>Buy</button></a>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "button");
+    assert_eq!(
+        results[0].message,
+        "Interactive content cannot be nested (<button> inside <a>)"
+    );
+}
+
+#[test]
+fn test_link_inside_button() {
+    let linter = HtmlLinter::new(vec![interactive_rule()], None);
+
+    let results = linter
+        .lint("<button type='button'><span><a href='/help'>Help</a></span></button>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "a");
+    assert!(results[0].message.ends_with("(<a> inside <button>)"));
+}
+
+#[test]
+fn test_non_interactive_content_in_link() {
+    let linter = HtmlLinter::new(vec![interactive_rule()], None);
+
+    let html = "<a href='/'><span>Home</span></a><a name='top'><button>Top</button></a><button><input type='hidden' name='id'></button>";
+    assert_eq!(linter.lint(html).unwrap().len(), 0);
+}
+
+fn block_in_inline_rule() -> Rule {
+    common::rule(
+        RuleType::Nesting,
+        Severity::Error,
+        "*",
+        "block-in-inline",
+        "Block elements cannot be nested in inline elements",
+        &[],
+    )
+}
+
+#[test]
+fn test_block_in_inline() {
+    let linter = HtmlLinter::new(vec![block_in_inline_rule()], None);
+
+    let results = linter
+        .lint("<html><body>\n<span class='card'>\n<div class='inner'>Text</div>\n</span>\n</body></html>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "div");
+    assert_eq!(results[0].location.line, 3);
+
+    let results = linter
+        .lint("<a href='/post'><strong><p>Read more</p></strong></a>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "p");
+}
+
+#[test]
+fn test_inline_in_block_is_allowed() {
+    let linter = HtmlLinter::new(vec![block_in_inline_rule()], None);
+
+    let html = "<div><p><span><em>Fine</em></span> <a href='/'>home</a></p><ul><li><a href='/a'>A</a></li></ul></div>";
+    assert_eq!(linter.lint(html).unwrap().len(), 0);
+}
+
+fn dl_lint(html: &str) -> Vec<String> {
+    let rule = Rule {
+        name: "dl-structure".to_string(),
+        rule_type: RuleType::Nesting,
+        severity: Severity::Warning,
+        selector: "dl".to_string(),
+        condition: "dl-structure".into(),
+        message: "Invalid definition list".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    };
+    HtmlLinter::new(vec![rule], None)
+        .lint(html)
+        .unwrap()
+        .into_iter()
+        .map(|result| result.message)
+        .collect()
+}
+
+#[test]
+fn test_dl_well_formed() {
+    let html = "<body><dl><dt>Term</dt><dt>Alias</dt><dd>Definition</dd><dt>Other</dt><dd>A</dd><dd>B</dd></dl></body>";
+    assert!(dl_lint(html).is_empty());
+}
+
+#[test]
+fn test_dl_with_div_wrappers() {
+    let html = "<body><dl><div><dt>Term</dt><dd>Definition</dd></div><div><dt>Other</dt><dd>More</dd></div></dl></body>";
+    assert!(dl_lint(html).is_empty());
+
+    let html = "<body><dl><div><dt>A</dt><dd>1</dd><dt>B</dt><dd>2</dd></div></dl></body>";
+    assert_eq!(
+        dl_lint(html),
+        vec!["Invalid definition list (a <dl> <div> may hold only one group)"]
+    );
+}
+
+#[test]
+fn test_empty_dl() {
+    assert_eq!(
+        dl_lint("<body><dl></dl></body>"),
+        vec!["Invalid definition list (<dl> has no <dt>/<dd> pairs)"]
+    );
+}
+
+#[test]
+fn test_dl_with_only_dt() {
+    assert_eq!(
+        dl_lint("<body><dl><dt>Lonely term</dt></dl></body>"),
+        vec!["Invalid definition list (<dt> is not followed by a <dd>)"]
+    );
+}
+
+#[test]
+fn test_dl_ordering_and_children() {
+    let html = "<body><dl><dd>Orphan</dd><p>Text</p><dt>Term</dt><dd>Definition</dd></dl><dd>Stray</dd></body>";
+    assert_eq!(
+        dl_lint(html),
+        vec![
+            "Invalid definition list (<dd> has no preceding <dt>)",
+            "Invalid definition list (<p> is not allowed in <dl>)",
+            "Invalid definition list (<dd> outside a <dl>)",
+        ]
+    );
+}
+
+fn table_lint(html: &str) -> Vec<String> {
+    let rule = Rule {
+        name: "table-structure".to_string(),
+        rule_type: RuleType::Nesting,
+        severity: Severity::Warning,
+        selector: "table".to_string(),
+        condition: "table-structure".into(),
+        message: "Invalid table structure".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    };
+    HtmlLinter::new(vec![rule], None)
+        .lint(html)
+        .unwrap()
+        .into_iter()
+        .map(|result| result.message)
+        .collect()
+}
+
+#[test]
+fn test_table_well_formed() {
+    let html = "<body><table><caption>Sales</caption><colgroup><col></colgroup>\
+        <thead><tr><th>Region</th><th>Total</th></tr></thead>\
+        <tbody><tr><th>North</th><td>10</td></tr></tbody><tbody><tr><th>South</th><td>5</td></tr></tbody>\
+        <tfoot><tr><th>All</th><td>15</td></tr></tfoot></table></body>";
+    assert!(table_lint(html).is_empty());
+}
+
+#[test]
+fn test_table_section_order() {
+    let html = "<body><table><tbody><tr><td>1</td></tr></tbody><thead><tr><th>A</th></tr></thead></table></body>";
+    assert_eq!(
+        table_lint(html),
+        vec!["Invalid table structure (<thead> must come before <tbody>)"]
+    );
+
+    let html = "<body><table><tfoot><tr><td>Total</td></tr></tfoot><tbody><tr><td>1</td></tr></tbody></table></body>";
+    assert_eq!(
+        table_lint(html),
+        vec!["Invalid table structure (<tbody> must come before <tfoot>)"]
+    );
+
+    let html =
+        "<body><table><thead><tr><th>A</th></tr></thead><caption>Late</caption></table></body>";
+    assert_eq!(
+        table_lint(html),
+        vec!["Invalid table structure (<caption> must be the first child of <table>)"]
+    );
+}
+
+#[test]
+fn test_table_duplicate_sections() {
+    let html = "<body><table><thead><tr><th>A</th></tr></thead><thead><tr><th>B</th></tr></thead></table></body>";
+    assert_eq!(
+        table_lint(html),
+        vec!["Invalid table structure (<table> has more than one <thead>)"]
+    );
+}
+
+#[test]
+fn test_table_row_cells() {
+    let html = "<body><table><tbody><tr><td>1</td><th>Label</th></tr></tbody></table></body>";
+    assert_eq!(
+        table_lint(html),
+        vec!["Invalid table structure (<th> follows a <td> in the same row)"]
+    );
+}
+
+#[test]
+fn test_table_rows_outside_sections() {
+    // The parser moves these rows into a <tbody>, so they're found in the source
+    let html = "<body><table><tr><td>1</td></tr><tr><td>2</td></tr></table>\
+        <table><tbody><tr><td>3</td></tr></tbody></table></body>";
+    assert_eq!(
+        table_lint(html),
+        vec![
+            "Invalid table structure (<tr> must be inside <thead>, <tbody> or <tfoot>)",
+            "Invalid table structure (<tr> must be inside <thead>, <tbody> or <tfoot>)",
+        ]
+    );
+
+    let html = "<body><table><thead><tr><th>A</th></thead><tr><td>1</td></table></body>";
+    assert_eq!(
+        table_lint(html),
+        vec!["Invalid table structure (<tr> must be inside <thead>, <tbody> or <tfoot>)"]
+    );
+}
+
+#[test]
+fn test_custom_element_categories() {
+    let html = "<div><span><my-card><p>Card</p></my-card></span></div>";
+    let linter = HtmlLinter::new(vec![block_in_inline_rule()], None);
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "p");
+
+    // A custom block element inside an inline one is reported like a built-in one
+    let options = LinterOptions {
+        custom_block_elements: vec!["my-card".to_string()],
+        ..Default::default()
+    };
+    let linter = HtmlLinter::new(vec![block_in_inline_rule()], Some(options));
+    assert_eq!(linter.lint(html).unwrap().len(), 2);
+}
+
+#[test]
+fn test_custom_void_elements() {
+    // The parser nests the paragraph inside <my-icon />, which was meant to be empty
+    let html = "<div><my-icon /><p>Text</p></div>";
+    let options = LinterOptions {
+        custom_inline_elements: vec!["my-icon".to_string()],
+        ..Default::default()
+    };
+    let linter = HtmlLinter::new(vec![block_in_inline_rule()], Some(options.clone()));
+    assert_eq!(linter.lint(html).unwrap().len(), 1);
+
+    let options = LinterOptions {
+        custom_void_elements: vec!["my-icon".to_string()],
+        ..options
+    };
+    let linter = HtmlLinter::new(vec![block_in_inline_rule()], Some(options.clone()));
+    assert!(linter.lint(html).unwrap().is_empty());
+
+    // Nor does it deepen the paragraph: html(1) > body(2) > div(3) > p(4)
+    let html = "<html><body><div><my-icon /><p>Text</p></div></body></html>";
+    let linter = HtmlLinter::new(vec![max_depth_rule("p", 4)], None);
+    assert_eq!(linter.lint(html).unwrap().len(), 1);
+    let linter = HtmlLinter::new(vec![max_depth_rule("p", 4)], Some(options));
+    assert!(linter.lint(html).unwrap().is_empty());
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use html_linter::{
+    DOMIndex, HtmlLinter, LintResult, LinterError, LinterPlugin, Location, Rule, RuleType, Severity,
+};
+
+/// Flags matched elements missing the attribute named by the `attribute` option.
+struct RequireAttribute;
+
+impl LinterPlugin for RequireAttribute {
+    fn check(&self, rule: &Rule, index: &DOMIndex) -> Result<Vec<LintResult>, LinterError> {
+        let attribute = rule
+            .options
+            .get("attribute")
+            .ok_or_else(|| LinterError::RuleError("attribute option required".to_string()))?;
+
+        Ok(index
+            .query(&rule.selector)
+            .into_iter()
+            .filter_map(|idx| index.get_node(idx))
+            .filter(|node| {
+                !node.attributes.iter().any(|attr| {
+                    index.resolve_symbol(attr.name).as_deref() == Some(attribute.as_str())
+                })
+            })
+            .map(|node| LintResult {
+                rule: rule.name.clone(),
+                severity: rule.severity.clone(),
+                message: rule.message.clone(),
+                location: Location {
+                    line: node.source_info.line,
+                    column: node.source_info.column,
+                    element: index.resolve_symbol(node.tag_name).unwrap_or_default(),
+                    file: None,
+                },
+                source: node.source_info.source.clone(),
+                ..Default::default()
+            })
+            .collect())
+    }
+}
+
+fn custom_rule(validator: &str, selector: &str, options: &[(&str, &str)]) -> Rule {
+    common::rule(
+        RuleType::Custom(validator.to_string()),
+        Severity::Warning,
+        selector,
+        validator,
+        "Plugin check failed",
+        options,
+    )
+}
+
+#[test]
+fn test_plugin_runs_custom_rule() {
+    let linter = HtmlLinter::new(
+        vec![custom_rule(
+            "require-tracking",
+            "button",
+            &[("attribute", "data-track")],
+        )],
+        None,
+    )
+    .with_plugin("require-tracking", Box::new(RequireAttribute));
+
+    let results = linter
+        .lint("<body><button data-track='buy'>Buy</button><button class='plain'>Cancel</button></body>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].source.contains("plain"));
+    assert!(!results[0].context.is_empty());
+}
+
+#[test]
+fn test_plugin_errors_propagate() {
+    let linter = HtmlLinter::new(vec![custom_rule("require-tracking", "button", &[])], None)
+        .with_plugin("require-tracking", Box::new(RequireAttribute));
+    assert!(linter.lint("<button>Buy</button>").is_err());
+}
+
+#[test]
+fn test_plugin_takes_precedence_over_builtin() {
+    let html = "<body><a href='/'></a></body>";
+    let rule = custom_rule("no-empty-links", "a", &[("attribute", "title")]);
+
+    let builtin = HtmlLinter::new(vec![rule.clone()], None);
+    assert!(builtin.lint(html).unwrap()[0]
+        .message
+        .contains("Link element has no content"));
+
+    let plugin =
+        HtmlLinter::new(vec![rule], None).with_plugin("no-empty-links", Box::new(RequireAttribute));
+    let results = plugin.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].message, "Plugin check failed");
+}
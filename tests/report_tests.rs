@@ -0,0 +1,195 @@
+use html5ever::driver::ParseOpts;
+use html5ever::parse_document;
+use html5ever::tendril::TendrilSink;
+use html_linter::report::HtmlReporter;
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+use markup5ever_rcdom::{Handle, NodeData, RcDom};
+use std::collections::HashMap;
+
+const SAMPLE: &str = r#"<html><body>
+<img src='a.jpg'>
+<img src='b.jpg' style='border: 0'>
+<div style='color: red'>Text</div>
+</body></html>"#;
+
+fn lint_sample() -> Vec<LintResult> {
+    let rules = vec![
+        Rule {
+            name: "img-alt".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Error,
+            selector: "img".to_string(),
+            condition: "alt-missing".into(),
+            message: "Images must have alt attributes".to_string(),
+            options: HashMap::new(),
+            tags: Vec::new(),
+            fix_hint: "Describe the image in an alt attribute".to_string(),
+        },
+        Rule {
+            name: "no-inline-styles".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Warning,
+            selector: "*".to_string(),
+            condition: "style-attribute".into(),
+            message: "Inline styles should be avoided".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+    ];
+
+    let linter = HtmlLinter::new(rules, None);
+    linter.lint(SAMPLE).unwrap()
+}
+
+fn parse(html: &str) -> RcDom {
+    parse_document(RcDom::default(), ParseOpts::default())
+        .from_utf8()
+        .read_from(&mut html.as_bytes())
+        .unwrap()
+}
+
+fn find_by_id(handle: &Handle, id: &str) -> Option<Handle> {
+    if let NodeData::Element { ref attrs, .. } = handle.data {
+        if attrs
+            .borrow()
+            .iter()
+            .any(|a| &*a.name.local == "id" && &*a.value == id)
+        {
+            return Some(handle.clone());
+        }
+    }
+    handle
+        .children
+        .borrow()
+        .iter()
+        .find_map(|child| find_by_id(child, id))
+}
+
+fn count_by_class(handle: &Handle, class: &str) -> usize {
+    let mut count = 0;
+    if let NodeData::Element { ref attrs, .. } = handle.data {
+        if attrs
+            .borrow()
+            .iter()
+            .any(|a| &*a.name.local == "class" && a.value.split_whitespace().any(|c| c == class))
+        {
+            count += 1;
+        }
+    }
+    for child in handle.children.borrow().iter() {
+        count += count_by_class(child, class);
+    }
+    count
+}
+
+fn text_of(handle: &Handle) -> String {
+    let mut text = String::new();
+    for child in handle.children.borrow().iter() {
+        if let NodeData::Text { ref contents } = child.data {
+            text.push_str(&contents.borrow());
+        }
+    }
+    text
+}
+
+#[test]
+fn test_report_summary_matches_results() {
+    let results = lint_sample();
+    assert_eq!(results.len(), 4);
+
+    let report = HtmlReporter::new().render(&results);
+    let dom = parse(&report);
+
+    let total = find_by_id(&dom.document, "total-count").unwrap();
+    assert_eq!(text_of(&total), results.len().to_string());
+
+    let errors = find_by_id(&dom.document, "error-count").unwrap();
+    assert_eq!(text_of(&errors), "2");
+    let warnings = find_by_id(&dom.document, "warning-count").unwrap();
+    assert_eq!(text_of(&warnings), "2");
+
+    assert_eq!(count_by_class(&dom.document, "violation"), results.len());
+    // Only the img-alt rule has a fix hint
+    assert_eq!(count_by_class(&dom.document, "fix-hint"), 2);
+    assert!(report.contains("<div class=\"fix-hint\">Describe the image in an alt attribute</div>"));
+}
+
+#[test]
+fn test_report_escapes_source_and_uses_title() {
+    let results = lint_sample();
+    let report = HtmlReporter::with_title("Nightly <build>").render(&results);
+
+    assert!(report.contains("<title>Nightly &lt;build&gt;</title>"));
+    assert!(report.contains("&lt;img src=&#39;a.jpg&#39;&gt;"));
+    assert!(!report.contains("<link"));
+}
+
+#[test]
+fn test_report_empty_results() {
+    let report = HtmlReporter::new().render(&[]);
+    let dom = parse(&report);
+
+    let total = find_by_id(&dom.document, "total-count").unwrap();
+    assert_eq!(text_of(&total), "0");
+    assert!(report.contains("No problems found."));
+}
+
+#[test]
+fn test_render_to_file() {
+    let results = lint_sample();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("report.html");
+
+    let reporter = HtmlReporter::new();
+    reporter.render_to_file(&results, &path).unwrap();
+
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(written, reporter.render(&results));
+}
+
+#[test]
+fn test_report_renders_context_and_links() {
+    let results = lint_sample();
+    let report = HtmlReporter::new().render(&results);
+
+    // The surrounding lines are shown with the caret under the reported column
+    assert_eq!(
+        count_by_class(&parse(&report).document, "context"),
+        results.len()
+    );
+    assert!(report.contains(&escape(&results[0].context)));
+    assert!(results[0].context.contains('^'));
+
+    // Sections exist only for severities with results, and nothing links to itself
+    assert!(report.contains("<a href=\"#error\">Errors</a>"));
+    assert!(!report.contains("href=\"#info\""));
+    assert!(!report.contains("href=\"#violation-"));
+
+    let mut results = results;
+    results[0].location.file = Some("pages/index.html".to_string());
+    let report = HtmlReporter::new().render(&results);
+    assert!(report.contains(
+        "<div class=\"location\"><a href=\"pages/index.html\">pages/index.html</a>: Line 2, column 1 &lt;img&gt;</div>"
+    ));
+}
+
+#[test]
+fn test_report_with_source_snippets() {
+    let results = lint_sample();
+    let report = HtmlReporter::new().with_source(SAMPLE).render(&results);
+
+    assert!(report.contains(
+        "<pre class=\"context\"><code>  &lt;html&gt;&lt;body&gt;\n&gt; &lt;img src=&#39;a.jpg&#39;&gt;\n"
+    ));
+    assert_eq!(
+        count_by_class(&parse(&report).document, "context"),
+        results.len()
+    );
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\'', "&#39;")
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use html_linter::{HtmlLinter, LinterOptions, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn rule(name: &str, selector: &str) -> Rule {
+    Rule {
+        name: name.to_string(),
+        ..common::rule(
+            RuleType::ElementPresence,
+            Severity::Warning,
+            selector,
+            "forbidden",
+            &format!("<{}> is not allowed", selector),
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_add_and_remove_rules() {
+    let mut linter = HtmlLinter::new(vec![rule("no-marquee", "marquee")], None);
+    assert_eq!(linter.rule_count(), 1);
+
+    linter.add_rule(rule("no-blink", "blink"));
+    assert_eq!(linter.rule_count(), 2);
+    assert!(linter.has_rule("no-blink"));
+
+    let results = linter
+        .lint("<body><marquee>Hi</marquee><blink>Hi</blink></body>")
+        .unwrap();
+    assert_eq!(results.len(), 2);
+
+    assert!(linter.remove_rule("no-marquee"));
+    assert!(!linter.remove_rule("no-marquee"));
+    assert!(!linter.has_rule("no-marquee"));
+    assert_eq!(linter.rule_count(), 1);
+
+    let results = linter
+        .lint("<body><marquee>Hi</marquee><blink>Hi</blink></body>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].rule, "no-blink");
+}
+
+#[test]
+fn test_add_duplicate_name_replaces_in_place() {
+    let mut linter = HtmlLinter::new(
+        vec![rule("no-legacy", "marquee"), rule("no-blink", "blink")],
+        None,
+    );
+
+    linter.add_rule(rule("no-legacy", "center"));
+    assert_eq!(linter.rule_count(), 2);
+
+    let rules = linter.get_rules();
+    assert_eq!(rules[0].name, "no-legacy");
+    assert_eq!(rules[0].selector, "center");
+    assert_eq!(rules[1].name, "no-blink");
+}
+
+#[test]
+fn test_replace_rule() {
+    let mut linter = HtmlLinter::new(vec![rule("no-marquee", "marquee")], None);
+
+    assert!(linter.replace_rule("no-marquee", rule("no-center", "center")));
+    assert!(!linter.has_rule("no-marquee"));
+    assert!(linter.has_rule("no-center"));
+
+    assert!(!linter.replace_rule("missing", rule("no-blink", "blink")));
+    assert!(!linter.has_rule("no-blink"));
+    assert_eq!(linter.rule_count(), 1);
+}
+
+#[test]
+fn test_merge_linters_other_wins_on_duplicate_names() {
+    let base = HtmlLinter::new(
+        vec![rule("no-marquee", "marquee"), rule("no-banned", "blink")],
+        None,
+    );
+    let project = HtmlLinter::new(
+        vec![rule("no-banned", "font"), rule("no-center", "center")],
+        None,
+    );
+
+    let merged = base.merge(project);
+    let names: Vec<String> = merged.get_rules().into_iter().map(|r| r.name).collect();
+    assert_eq!(names, vec!["no-marquee", "no-banned", "no-center"]);
+
+    let results = merged
+        .lint("<body><blink>A</blink><font>B</font><center>C</center></body>")
+        .unwrap();
+    let mut elements: Vec<&str> = results
+        .iter()
+        .map(|r| r.location.element.as_str())
+        .collect();
+    elements.sort();
+    assert_eq!(elements, vec!["center", "font"]);
+}
+
+#[test]
+fn test_merge_linter_options() {
+    let base = LinterOptions {
+        ignore_files: vec!["^legacy-".to_string(), "^vendor-".to_string()],
+        custom_selectors: HashMap::from([
+            ("nav".to_string(), "nav".to_string()),
+            ("cta".to_string(), "a.button".to_string()),
+        ]),
+        max_line_length: Some(120),
+        ..Default::default()
+    };
+    let project = LinterOptions {
+        ignore_files: vec!["^vendor-".to_string(), "^draft-".to_string()],
+        custom_selectors: HashMap::from([("cta".to_string(), "button.cta".to_string())]),
+        max_line_length: None,
+        ..Default::default()
+    };
+
+    let merged = base.merge(project);
+    assert_eq!(merged.ignore_files, vec!["^legacy-", "^vendor-", "^draft-"]);
+    assert_eq!(merged.custom_selectors.len(), 2);
+    assert_eq!(merged.custom_selectors["cta"], "button.cta");
+    assert_eq!(merged.max_line_length, Some(120));
+
+    let overridden = merged.merge(LinterOptions {
+        max_line_length: Some(80),
+        ..Default::default()
+    });
+    assert_eq!(overridden.max_line_length, Some(80));
+}
+
+#[test]
+fn test_extend_rules() {
+    let mut linter = HtmlLinter::new(vec![rule("no-marquee", "marquee")], None);
+    linter
+        .extend_rules(vec![
+            rule("no-blink", "blink"),
+            rule("no-marquee", "center"),
+        ])
+        .extend_rules(vec![rule("no-font", "font")]);
+
+    assert_eq!(linter.rule_count(), 3);
+    let results = linter
+        .lint("<body><marquee>A</marquee><center>B</center></body>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "center");
+}
@@ -0,0 +1,161 @@
+use html_linter::output::LintResultWriter;
+use html_linter::sarif::{to_sarif, SarifWriter};
+use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn create_linter() -> HtmlLinter {
+    let rules = vec![
+        Rule {
+            name: "img-alt".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Error,
+            selector: "img".to_string(),
+            condition: "alt-missing".into(),
+            message: "Images must have alt attributes".to_string(),
+            options: HashMap::new(),
+            tags: Vec::new(),
+            fix_hint: "Describe the image in an alt attribute".to_string(),
+        },
+        Rule {
+            name: "no-inline-styles".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Warning,
+            selector: "*".to_string(),
+            condition: "style-attribute".into(),
+            message: "Inline styles should be avoided".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+    ];
+
+    HtmlLinter::new(rules, None)
+}
+
+#[test]
+fn test_sarif_header() {
+    let linter = create_linter();
+    let results = linter.lint(r#"<img src="test.jpg">"#).unwrap();
+
+    let output = to_sarif(&results, "html-linter", "0.1.1");
+    let parsed: serde_json::Value = serde_json::from_str(&output.to_string()).unwrap();
+
+    assert_eq!(
+        parsed["$schema"],
+        "https://json.schemastore.org/sarif-2.1.0.json"
+    );
+    assert_eq!(parsed["version"], "2.1.0");
+    assert_eq!(parsed["runs"][0]["tool"]["driver"]["name"], "html-linter");
+    assert_eq!(parsed["runs"][0]["tool"]["driver"]["version"], "0.1.1");
+}
+
+#[test]
+fn test_sarif_results() {
+    let linter = create_linter();
+    let html = r#"<html><body>
+<img src='test.jpg' style='border: 0'>
+</body></html>"#;
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 2);
+
+    let output = to_sarif(&results, "html-linter", "0.1.1");
+    let sarif_results = output["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(sarif_results.len(), 2);
+
+    let img_alt = sarif_results
+        .iter()
+        .find(|r| r["ruleId"] == "img-alt")
+        .unwrap();
+    assert_eq!(img_alt["level"], "error");
+    assert_eq!(
+        img_alt["message"]["text"],
+        "Images must have alt attributes"
+    );
+
+    let region = &img_alt["locations"][0]["physicalLocation"]["region"];
+    assert_eq!(region["startLine"], 2);
+    assert_eq!(region["startColumn"], 1);
+    assert_eq!(region["snippet"]["text"], results[0].source);
+
+    let inline_style = sarif_results
+        .iter()
+        .find(|r| r["ruleId"] == "no-inline-styles")
+        .unwrap();
+    assert_eq!(inline_style["level"], "warning");
+
+    // Neither rule suggests a replacement, so there are no fixes; the hint is the rule's help
+    assert!(img_alt.get("fixes").is_none());
+    assert!(inline_style.get("fixes").is_none());
+
+    let rules = output["runs"][0]["tool"]["driver"]["rules"]
+        .as_array()
+        .unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(
+        rules[0]["help"]["text"],
+        "Describe the image in an alt attribute"
+    );
+    assert!(rules[1].get("help").is_none());
+}
+
+#[test]
+fn test_sarif_fix_replacements() {
+    let rule = Rule {
+        name: "trailing-whitespace".to_string(),
+        rule_type: RuleType::WhiteSpace,
+        severity: Severity::Warning,
+        selector: "*".to_string(),
+        condition: "trailing-whitespace".into(),
+        message: "No trailing whitespace".to_string(),
+        fix_hint: "Configure your editor to trim trailing whitespace".to_string(),
+        ..Default::default()
+    };
+    let mut results = HtmlLinter::new(vec![rule], None)
+        .lint("<body>\n<p>Café</p>  \n</body>")
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    results[0].location.file = Some("index.html".to_string());
+
+    let output = to_sarif(&results, "html-linter", "0.1.1");
+    let result = &output["runs"][0]["results"][0];
+    assert_eq!(
+        result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "index.html"
+    );
+
+    let change = &result["fixes"][0]["artifactChanges"][0];
+    assert_eq!(change["artifactLocation"]["uri"], "index.html");
+    assert_eq!(
+        change["replacements"][0],
+        serde_json::json!({
+            "deletedRegion": { "startLine": 2, "startColumn": 12, "endLine": 2, "endColumn": 14 },
+            "insertedContent": { "text": "" },
+        })
+    );
+
+    // The fix describes its own edit; the rule's hint stays in its help
+    assert_eq!(
+        result["fixes"][0]["description"]["text"],
+        "Remove trailing whitespace"
+    );
+    assert_eq!(
+        output["runs"][0]["tool"]["driver"]["rules"][0]["help"]["text"],
+        "Configure your editor to trim trailing whitespace"
+    );
+}
+
+#[test]
+fn test_sarif_empty_results() {
+    let output = to_sarif(&[], "html-linter", "0.1.1");
+    assert_eq!(output["runs"][0]["results"].as_array().unwrap().len(), 0);
+}
+
+#[test]
+fn test_sarif_writer() {
+    let results = create_linter().lint(r#"<img src="test.jpg">"#).unwrap();
+    let writer = SarifWriter::new("html-linter", "0.1.1");
+
+    let mut output = Vec::new();
+    writer.write_all(&results, &mut output).unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(parsed, to_sarif(&results, "html-linter", "0.1.1"));
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+
+fn security_rule(selector: &str, condition: &str) -> Rule {
+    common::rule(
+        RuleType::SecurityPattern,
+        Severity::Error,
+        selector,
+        condition,
+        "Security issue",
+        &[],
+    )
+}
+
+fn lint(rule: Rule, html: &str) -> Vec<LintResult> {
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+#[test]
+fn test_on_event_attributes() {
+    let html = "<body onload='init()'><button onclick='go()' onmouseover='hover()'>Go</button><div data-on='x'>Text</div></body>";
+    let results = lint(security_rule("*", "on-event-attributes"), html);
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Security issue - inline event handler 'onload'",
+            "Security issue - inline event handler 'onclick'",
+            "Security issue - inline event handler 'onmouseover'",
+        ]
+    );
+}
+
+#[test]
+fn test_javascript_href() {
+    let rule = security_rule("[href]", "javascript-href");
+    let html = "<a href='javascript:alert(1)'>One</a><a href=' JavaScript:void(0)'>Two</a><a href='/javascript'>Docs</a>";
+    let results = lint(rule, html);
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.location.element == "a"));
+}
+
+#[test]
+fn test_data_uri_src() {
+    let html = "<img src='data:image/png;base64,AAAA'><iframe src='data:text/html,hi'></iframe><img src='/logo.png'><script src='data:text/javascript,1'></script>";
+    let results = lint(security_rule("[src]", "data-uri-src"), html);
+    let elements: Vec<&str> = results
+        .iter()
+        .map(|r| r.location.element.as_str())
+        .collect();
+    assert_eq!(elements, vec!["img", "iframe"]);
+}
+
+#[test]
+fn test_unsafe_target() {
+    let html = "<a href='/a' target='_blank'>A</a>
+<a href='/b' target='_blank' rel='noopener'>B</a>
+<a href='/c' target='_blank' rel='external noreferrer'>C</a>
+<a href='/d' target='_self'>D</a>
+<a href='/e' target='preview'>E</a>";
+    let results = lint(security_rule("[target]", "unsafe-target"), html);
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Security issue - target='_blank' without rel='noopener'",
+            "Security issue - target='preview' without rel='noopener'",
+        ]
+    );
+}
+
+#[test]
+fn test_meta_refresh() {
+    let html = "<html><head><meta http-equiv='Refresh' content='0; url=https://example.com'><meta http-equiv='content-type' content='text/html'></head></html>";
+    let results = lint(security_rule("meta", "meta-refresh"), html);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].message, "Security issue - meta refresh redirect");
+}
+
+#[test]
+fn test_security_rules() {
+    let rules = HtmlLinter::security_rules();
+    assert_eq!(rules.len(), 5);
+    assert!(rules.iter().all(|rule| rule.severity == Severity::Error
+        && matches!(rule.rule_type, RuleType::SecurityPattern)));
+
+    let linter = HtmlLinter::new(rules, None);
+    let html = "<html><head><meta http-equiv='refresh' content='5'></head><body>
+<a href='javascript:void(0)' onclick='go()' target='_blank'>Go</a>
+<img src='data:image/gif;base64,R0lGOD' alt='Pixel'>
+</body></html>";
+    let mut rules_hit: Vec<String> = linter
+        .lint(html)
+        .unwrap()
+        .into_iter()
+        .map(|r| r.rule)
+        .collect();
+    rules_hit.sort();
+    assert_eq!(
+        rules_hit,
+        vec![
+            "no-data-uri-src",
+            "no-inline-event-handlers",
+            "no-javascript-urls",
+            "no-meta-refresh",
+            "safe-link-targets",
+        ]
+    );
+
+    let safe = "<html><body><a href='/home' target='_blank' rel='noopener'>Home</a><img src='/a.png' alt='A'></body></html>";
+    assert!(linter.lint(safe).unwrap().is_empty());
+}
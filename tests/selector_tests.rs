@@ -0,0 +1,248 @@
+mod common;
+
+use html_linter::{HtmlLinter, LinterOptions, Rule, RuleType, Severity};
+
+fn forbidden_rule(selector: &str) -> Rule {
+    common::rule(
+        RuleType::ElementPresence,
+        Severity::Warning,
+        selector,
+        "forbidden",
+        "Element is not allowed",
+        &[],
+    )
+}
+
+fn match_count(selector: &str, html: &str) -> usize {
+    let linter = HtmlLinter::new(vec![forbidden_rule(selector)], None);
+    linter.lint(html).unwrap().len()
+}
+
+#[test]
+fn test_space_list_attribute_selector() {
+    let html = r#"<html><body>
+        <a rel="nofollow noopener">One</a>
+        <a rel="noopener">Two</a>
+        <a rel="nofollower">Three</a>
+    </body></html>"#;
+
+    assert_eq!(match_count(r#"a[rel~="nofollow"]"#, html), 1);
+    assert_eq!(match_count(r#"a[rel~="noopener"]"#, html), 2);
+    assert_eq!(match_count(r#"a[rel~="follow"]"#, html), 0);
+}
+
+#[test]
+fn test_hyphen_list_attribute_selector() {
+    assert_eq!(
+        match_count(r#"html[lang|="en"]"#, r#"<html lang="en"></html>"#),
+        1
+    );
+    assert_eq!(
+        match_count(r#"html[lang|="en"]"#, r#"<html lang="en-US"></html>"#),
+        1
+    );
+    assert_eq!(
+        match_count(r#"html[lang|="en"]"#, r#"<html lang="eng"></html>"#),
+        0
+    );
+    assert_eq!(
+        match_count(r#"html[lang|="en"]"#, r#"<html lang="fr-en"></html>"#),
+        0
+    );
+}
+
+#[test]
+fn test_root_pseudo_class() {
+    let html = "<html><body><div><p>Text</p></div></body></html>";
+
+    assert_eq!(match_count(":root", html), 1);
+    assert_eq!(match_count("html:root", html), 1);
+    assert_eq!(match_count("body:root", html), 0);
+}
+
+#[test]
+fn test_nth_of_type_pseudo_class() {
+    let html = "<html><body><section>
+        <h2>Title</h2>
+        <p>One</p>
+        <span>Aside</span>
+        <p>Two</p>
+        <p>Three</p>
+        <p>Four</p>
+    </section></body></html>";
+
+    assert_eq!(match_count("p:nth-of-type(2)", html), 1);
+    assert_eq!(match_count("p:nth-of-type(odd)", html), 2);
+    assert_eq!(match_count("p:nth-of-type(2n)", html), 2);
+    assert_eq!(match_count("p:nth-of-type(-n+3)", html), 3);
+    assert_eq!(match_count("p:nth-of-type(5)", html), 0);
+    assert_eq!(match_count("p:nth-child(2)", html), 1);
+    assert_eq!(match_count("p:first-of-type", html), 1);
+    assert_eq!(match_count("p:last-of-type", html), 1);
+}
+
+#[test]
+fn test_only_child_pseudo_class() {
+    let html = "<html><body>
+        <ul><li>Alone</li></ul>
+        <ul><li>First</li><li>Second</li></ul>
+        <div>Text <em>only element</em> more text</div>
+    </body></html>";
+
+    assert_eq!(match_count("li:only-child", html), 1);
+    // Text siblings do not count as children
+    assert_eq!(match_count("em:only-child", html), 1);
+}
+
+#[test]
+fn test_only_of_type_pseudo_class() {
+    let html = "<html><body>
+        <article><h2>Title</h2><p>Body</p><p>More</p></article>
+        <aside><h2>Related</h2><p>Single</p></aside>
+    </body></html>";
+
+    assert_eq!(match_count("h2:only-of-type", html), 2);
+    assert_eq!(match_count("p:only-of-type", html), 1);
+    assert_eq!(match_count(".missing:only-of-type", html), 0);
+}
+
+#[test]
+fn test_is_pseudo_class() {
+    let html = "<html><body>
+        <main><article id='a1'>One</article><section id='s1'>Two</section></main>
+        <div><section id='s2'>Three</section><article id='a2'>Four</article></div>
+        <aside><p>Intro</p><section id='s3'>Five</section></aside>
+    </body></html>";
+
+    let results = HtmlLinter::new(
+        vec![forbidden_rule(":is(article, section):first-child")],
+        None,
+    )
+    .lint(html)
+    .unwrap();
+    let sources: Vec<&str> = results
+        .iter()
+        .map(|result| result.source.as_str())
+        .collect();
+    assert_eq!(sources.len(), 2, "{:?}", sources);
+    assert!(sources[0].contains("a1"));
+    assert!(sources[1].contains("s2"));
+
+    assert_eq!(match_count(":is(article, section)", html), 5);
+    assert_eq!(match_count(":where(#s3, p)", html), 2);
+    assert_eq!(match_count(":matches(article):last-child", html), 1);
+    assert_eq!(match_count("section:is(#s1, #s3)", html), 2);
+}
+
+#[test]
+fn test_is_pseudo_class_in_combinator_chains() {
+    let html = "<html><body>
+        <section><h1>Title</h1><p id='p1'>Lead</p><p id='p2'>Body</p></section>
+        <article><h2>Heading</h2><p id='p3'>Lead</p></article>
+        <div><h3>Other</h3><p id='p4'>Text</p></div>
+    </body></html>";
+
+    let ids = |selector: &str| {
+        HtmlLinter::new(vec![forbidden_rule(selector)], None)
+            .lint(html)
+            .unwrap()
+            .iter()
+            .filter_map(|result| {
+                let start = result.source.find("id='")? + 4;
+                Some(result.source[start..start + 2].to_string())
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(ids(":is(h1, h2) + p"), ["p1", "p3"]);
+    assert_eq!(ids("section :is(h1, h2) + p"), ["p1"]);
+    assert_eq!(ids(":is(h1, h2) ~ p"), ["p1", "p2", "p3"]);
+    assert_eq!(ids(":is(article, section) p"), ["p1", "p2", "p3"]);
+    assert_eq!(match_count("body > :is(section, div)", html), 2);
+    assert_eq!(match_count("html > :is(section, div)", html), 0);
+    assert_eq!(match_count("body > section>h1 + p", html), 1);
+    // Complex selectors inside `:is()` aren't supported and match nothing
+    assert_eq!(match_count(":is(section p)", html), 0);
+}
+
+#[test]
+fn test_selector_lists_and_combinators() {
+    let html = "<html><body>
+        <main><div><p>One</p></div><p>Two</p></main>
+        <p>Three</p><ul><li>Four</li></ul>
+    </body></html>";
+
+    assert_eq!(match_count("p, li", html), 4);
+    assert_eq!(match_count("main p", html), 2);
+    assert_eq!(match_count("main > p", html), 1);
+    assert_eq!(match_count("main > div p", html), 1);
+    assert_eq!(match_count("body > * > p", html), 1);
+    assert_eq!(match_count("main ~ ul li", html), 1);
+    assert_eq!(match_count("main >", html), 0);
+    assert_eq!(match_count("> p", html), 0);
+}
+
+#[test]
+fn test_not_pseudo_class() {
+    let html = "<html><body>
+        <p class='a'>One</p><p class='b'>Two</p><p>Three</p>
+        <div class='a'>Four</div>
+    </body></html>";
+
+    assert_eq!(match_count("p:not(.a)", html), 2);
+    assert_eq!(match_count("p:not(.a, .b)", html), 1);
+    assert_eq!(match_count(".a:not(p)", html), 1);
+    assert_eq!(match_count("p:not(:first-child)", html), 2);
+    // `html`, `head`, `body` and the `div`
+    assert_eq!(match_count(":not(p)", html), 4);
+}
+
+#[test]
+fn test_unsupported_pseudo_class_matches_nothing() {
+    let html = "<html><body><p>One</p><p>Two</p></body></html>";
+
+    assert_eq!(match_count("p:hover", html), 0);
+    assert_eq!(match_count("p:foo(bar)", html), 0);
+    assert_eq!(match_count("p:nth-child(odd-ish)", html), 0);
+    assert_eq!(match_count("p:first-child(2)", html), 0);
+    assert_eq!(match_count(":is(p,", html), 0);
+    assert_eq!(match_count("p:not()", html), 0);
+    assert_eq!(match_count("p:not(:hover)", html), 2);
+}
+
+#[test]
+fn test_case_insensitive_elements() {
+    let html = "<body><img src='a.png'><IMG src='b.png'><p class='note'>Text</p></body>";
+
+    // Parsed element names are always lowercase, so uppercase selectors don't match them
+    assert_eq!(match_count("img", html), 2);
+    assert_eq!(match_count("IMG", html), 0);
+
+    let options = LinterOptions {
+        case_insensitive_elements: true,
+        ..LinterOptions::default()
+    };
+    let lint = |selector: &str| {
+        HtmlLinter::new(vec![forbidden_rule(selector)], Some(options.clone()))
+            .lint(html)
+            .unwrap()
+            .len()
+    };
+    assert_eq!(lint("IMG"), 2);
+
+    let rules = vec![forbidden_rule("IMG"), forbidden_rule("p:hover")];
+    assert_eq!(
+        HtmlLinter::new(rules.clone(), None).selector_warnings(),
+        vec![
+            "Rule 'forbidden': Element selector `IMG` contains uppercase letters and won't match parsed HTML elements; use `img` or enable case_insensitive_elements",
+            "Rule 'forbidden': Pseudo-class `:hover` is not supported and matches nothing",
+        ]
+    );
+    assert_eq!(
+        HtmlLinter::new(rules, Some(options.clone())).selector_warnings(),
+        vec!["Rule 'forbidden': Pseudo-class `:hover` is not supported and matches nothing"]
+    );
+    assert_eq!(lint("P.note"), 1);
+    // Only element names are folded; classes stay case-sensitive
+    assert_eq!(lint("p.NOTE"), 0);
+}
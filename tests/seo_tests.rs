@@ -9,7 +9,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::ElementContent,
             severity: Severity::Error,
             selector: "head".to_string(),
-            condition: "meta-tags".to_string(),
+            condition: "meta-tags".into(),
             message: "Meta description must be between 50 and 160 characters".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -28,13 +28,14 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         Rule {
             name: "meta-title".to_string(),
             rule_type: RuleType::ElementContent,
             severity: Severity::Error,
             selector: "head title".to_string(),
-            condition: "content-length".to_string(),
+            condition: "content-length".into(),
             message: "Title tag must be between 30 and 60 characters".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -42,6 +43,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 options.insert("max_length".to_string(), "60".to_string());
                 options
             },
+            ..Default::default()
         },
         // Add this rule after the meta-title rule and before the meta-robots-advanced rule
         Rule {
@@ -49,7 +51,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::AttributeValue,
             severity: Severity::Error,
             selector: "link[rel='canonical']".to_string(),
-            condition: "meta-tags".to_string(),
+            condition: "meta-tags".into(),
             message: "Canonical URL must be present and valid".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -58,6 +60,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "href".to_string());
                 options
             },
+            ..Default::default()
         },
         // Advanced Meta Tags
         Rule {
@@ -65,7 +68,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::ElementContent,
             severity: Severity::Warning,
             selector: "head".to_string(),
-            condition: "meta-tags".to_string(),
+            condition: "meta-tags".into(),
             message: "Advanced robots meta directives should be properly configured".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -89,6 +92,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // Social Media Optimization
         Rule {
@@ -96,7 +100,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::ElementContent,
             severity: Severity::Warning,
             selector: "head".to_string(),
-            condition: "meta-tags".to_string(),
+            condition: "meta-tags".into(),
             message: "Complete Open Graph tags required for optimal social sharing".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -132,8 +136,8 @@ fn setup_seo_rules() -> Vec<Rule> {
                         {
                             "property": "og:url",
                             "pattern": {
-                                "type": "Regex",
-                                "value": "^https://"
+                                "type": "UrlPattern",
+                                "require_https": true
                             },
                             "required": true
                         }
@@ -142,6 +146,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // Performance and Core Web Vitals
         Rule {
@@ -150,7 +155,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             severity: Severity::Warning,
             selector: "script:not([type='application/ld+json']), link[rel='stylesheet']"
                 .to_string(),
-            condition: "loading-optimization".to_string(),
+            condition: "loading-optimization".into(),
             message: "Resource loading should be optimized for Core Web Vitals".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -159,6 +164,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 options.insert("pattern".to_string(), r#"^(lazy|eager|auto|\d+)$"#.to_string());
                 options
             },
+            ..Default::default()
         },
         // Structured Data
         Rule {
@@ -166,7 +172,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::ElementContent,
             severity: Severity::Error,
             selector: "head script[type='application/ld+json']".to_string(),
-            condition: "json-ld-validation".to_string(),
+            condition: "json-ld-validation".into(),
             message: "Required structured data missing or invalid".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -176,6 +182,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // Content Optimization
         Rule {
@@ -183,7 +190,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Warning,
             selector: "h1,h2,h3".to_string(),
-            condition: "content-optimization".to_string(),
+            condition: "content-optimization".into(),
             message: "Heading structure should be optimized for SEO".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -205,6 +212,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 options.insert("check_mode".to_string(), "all".to_string());
                 options
             },
+            ..Default::default()
         },
         // International SEO
         Rule {
@@ -212,7 +220,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "link[rel='alternate'][hreflang]".to_string(),
-            condition: "valid-hreflang".to_string(),
+            condition: "valid-hreflang".into(),
             message: "Complete hreflang implementation required for international SEO".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -221,6 +229,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 options.insert("attributes".to_string(), "hreflang".to_string());
                 options
             },
+            ..Default::default()
         },
         // Mobile Optimization
         Rule {
@@ -228,7 +237,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Error,
             selector: "head".to_string(),
-            condition: "mobile-friendly".to_string(),
+            condition: "mobile-friendly".into(),
             message: "Page must be optimized for mobile devices".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -247,6 +256,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 options.insert("check_mode".to_string(), "all".to_string());
                 options
             },
+            ..Default::default()
         },
         // Image Optimization
         Rule {
@@ -254,7 +264,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "img".to_string(),
-            condition: "image-best-practices".to_string(),
+            condition: "image-best-practices".into(),
             message: "Images must follow SEO best practices".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -269,6 +279,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // URL Structure
         Rule {
@@ -276,7 +287,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::AttributeValue,
             severity: Severity::Warning,
             selector: "a[href]".to_string(),
-            condition: "url-best-practices".to_string(),
+            condition: "url-best-practices".into(),
             message: "URLs should follow SEO best practices".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -287,6 +298,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 options.insert("check_mode".to_string(), "ensure_existence".to_string());
                 options
             },
+            ..Default::default()
         },
         // Core Web Vitals Optimization
         Rule {
@@ -294,7 +306,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Warning,
             selector: "body".to_string(),
-            condition: "performance-optimization".to_string(),
+            condition: "performance-optimization".into(),
             message: "Page should be optimized for Core Web Vitals".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -320,6 +332,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 options.insert("check_mode".to_string(), "any".to_string());
                 options
             },
+            ..Default::default()
         },
         // AI-Optimized Content Structure
         Rule {
@@ -327,7 +340,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Warning,
             selector: "article, main, .content".to_string(),
-            condition: "content-structure".to_string(),
+            condition: "content-structure".into(),
             message: "Content structure should be optimized for AI crawlers and LLMs".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -356,6 +369,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 options.insert("check_mode".to_string(), "all".to_string());
                 options
             },
+            ..Default::default()
         },
         // E-E-A-T Signals
         Rule {
@@ -363,7 +377,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Warning,
             selector: "body".to_string(),
-            condition: "expertise-signals".to_string(),
+            condition: "expertise-signals".into(),
             message: "Page should demonstrate Experience, Expertise, Authoritativeness, and Trustworthiness".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -395,6 +409,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // User Experience Signals
         Rule {
@@ -402,7 +417,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Error,
             selector: "body".to_string(),
-            condition: "user-experience".to_string(),
+            condition: "user-experience".into(),
             message: "Page must meet Core Web Vitals and UX requirements".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -434,6 +449,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // Content Hierarchy and Semantic Structure
         Rule {
@@ -441,7 +457,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Warning,
             selector: "body".to_string(),
-            condition: "semantic-html".to_string(),
+            condition: "semantic-html".into(),
             message: "Content must use semantic HTML elements appropriately".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -466,6 +482,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // Advanced Schema Implementation
         Rule {
@@ -473,7 +490,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::ElementContent,
             severity: Severity::Warning,
             selector: "script[type='application/ld+json']".to_string(),
-            condition: "schema-validation".to_string(),
+            condition: "schema-validation".into(),
             message: "Schema markup should implement proper hierarchy and relationships".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -496,6 +513,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // Content Readability and Engagement
         Rule {
@@ -503,7 +521,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::TextContent,
             severity: Severity::Warning,
             selector: "article p, article li".to_string(),
-            condition: "readability-check".to_string(),
+            condition: "readability-check".into(),
             message: "Content should meet readability and engagement standards".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -526,6 +544,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // Technical Performance Optimization
         Rule {
@@ -533,7 +552,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Error,
             selector: "html".to_string(),
-            condition: "performance-check".to_string(),
+            condition: "performance-check".into(),
             message: "Page must implement advanced performance optimizations".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -618,6 +637,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // Progressive Enhancement
         Rule {
@@ -625,7 +645,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Warning,
             selector: "body".to_string(),
-            condition: "enhancement-check".to_string(),
+            condition: "enhancement-check".into(),
             message: "Implement progressive enhancement for better accessibility and performance".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -657,6 +677,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // International and Language Optimization
         Rule {
@@ -664,7 +685,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Warning,
             selector: "html".to_string(),
-            condition: "language-check".to_string(),
+            condition: "language-check".into(),
             message: "Implement proper language and international optimization".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -724,6 +745,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // Image Optimization Compound
         Rule {
@@ -731,7 +753,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Warning,
             selector: "img".to_string(),
-            condition: "all-conditions-met".to_string(),
+            condition: "all-conditions-met".into(),
             message: "Images should implement all modern optimization techniques".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -778,6 +800,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 );
                 options
             },
+            ..Default::default()
         },
         // Add this rule after the language-optimization rule
         Rule {
@@ -785,7 +808,7 @@ fn setup_seo_rules() -> Vec<Rule> {
             rule_type: RuleType::Compound,
             severity: Severity::Warning,
             selector: "head".to_string(),
-            condition: "hreflang-validation".to_string(),
+            condition: "hreflang-validation".into(),
             message: "Proper hreflang implementation required for international SEO".to_string(),
             options: {
                 let mut options = HashMap::new();
@@ -825,6 +848,7 @@ fn setup_seo_rules() -> Vec<Rule> {
                 options.insert("check_mode".to_string(), "all".to_string());
                 options
             },
+            ..Default::default()
         }
     ]
 }
@@ -0,0 +1,54 @@
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn lint_missing_alt() -> Vec<LintResult> {
+    let rules = vec![Rule {
+        name: "img-alt".to_string(),
+        rule_type: RuleType::AttributePresence,
+        severity: Severity::Error,
+        selector: "img".to_string(),
+        condition: "alt-missing".into(),
+        message: "Images must have alt attributes".to_string(),
+        options: HashMap::new(),
+        ..Default::default()
+    }];
+
+    let linter = HtmlLinter::new(rules, None);
+    linter
+        .lint(r#"<html><body><img src="test.jpg"></body></html>"#)
+        .unwrap()
+}
+
+#[test]
+fn test_lint_result_round_trip() {
+    let results = lint_missing_alt();
+    assert_eq!(results.len(), 1);
+
+    let json = serde_json::to_string(&results[0]).unwrap();
+    let parsed: LintResult = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed.rule, results[0].rule);
+    assert_eq!(parsed.severity, results[0].severity);
+    assert_eq!(parsed.message, results[0].message);
+    assert_eq!(parsed.location.line, results[0].location.line);
+    assert_eq!(parsed.location.column, results[0].location.column);
+    assert_eq!(parsed.location.element, results[0].location.element);
+    assert_eq!(parsed.source, results[0].source);
+}
+
+#[test]
+fn test_lint_result_json_shape() {
+    let results = lint_missing_alt();
+    let value = serde_json::to_value(&results).unwrap();
+
+    assert_eq!(value[0]["rule"], "img-alt");
+    assert_eq!(value[0]["severity"], "Error");
+    assert_eq!(value[0]["location"]["element"], "img");
+}
+
+#[test]
+fn test_severity_exit_codes() {
+    assert_eq!(Severity::Error.to_exit_code(), 2);
+    assert_eq!(Severity::Warning.to_exit_code(), 1);
+    assert_eq!(Severity::Info.to_exit_code(), 0);
+}
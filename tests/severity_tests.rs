@@ -0,0 +1,167 @@
+use html_linter::{HtmlLinter, LinterOptions, Rule, RuleType, Severity};
+use std::collections::HashMap;
+
+fn rules() -> Vec<Rule> {
+    vec![
+        Rule {
+            name: "img-alt".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Warning,
+            selector: "img".to_string(),
+            condition: "alt-missing".into(),
+            message: "Images must have alt attributes".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+        Rule {
+            name: "no-inline-styles".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Info,
+            selector: "*".to_string(),
+            condition: "style-attribute".into(),
+            message: "Inline styles should be avoided".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+    ]
+}
+
+const HTML: &str = "<html><body><img src='a.png' style='width: 10px'></body></html>";
+
+fn severities(linter: &HtmlLinter, strict: bool) -> Vec<(String, Severity)> {
+    let results = if strict {
+        linter.lint_strict(HTML).unwrap()
+    } else {
+        linter.lint(HTML).unwrap()
+    };
+    results.into_iter().map(|r| (r.rule, r.severity)).collect()
+}
+
+#[test]
+fn test_severities_unchanged_by_default() {
+    let linter = HtmlLinter::new(rules(), None);
+    assert_eq!(
+        severities(&linter, false),
+        vec![
+            ("img-alt".to_string(), Severity::Warning),
+            ("no-inline-styles".to_string(), Severity::Info),
+        ]
+    );
+}
+
+#[test]
+fn test_treat_warnings_as_errors() {
+    let options = LinterOptions {
+        treat_warnings_as_errors: true,
+        ..Default::default()
+    };
+    let linter = HtmlLinter::new(rules(), Some(options));
+    assert_eq!(
+        severities(&linter, false),
+        vec![
+            ("img-alt".to_string(), Severity::Error),
+            ("no-inline-styles".to_string(), Severity::Info),
+        ]
+    );
+}
+
+#[test]
+fn test_treat_info_as_warnings() {
+    let options = LinterOptions {
+        treat_warnings_as_errors: true,
+        treat_info_as_warnings: true,
+        ..Default::default()
+    };
+    let linter = HtmlLinter::new(rules(), Some(options));
+
+    // Info is promoted one level only, even when warnings are promoted too
+    assert_eq!(
+        severities(&linter, false),
+        vec![
+            ("img-alt".to_string(), Severity::Error),
+            ("no-inline-styles".to_string(), Severity::Warning),
+        ]
+    );
+}
+
+#[test]
+fn test_lint_strict() {
+    let linter = HtmlLinter::new(rules(), None);
+    assert_eq!(
+        severities(&linter, true),
+        vec![
+            ("img-alt".to_string(), Severity::Error),
+            ("no-inline-styles".to_string(), Severity::Warning),
+        ]
+    );
+
+    // The linter's own options are left untouched
+    assert_eq!(severities(&linter, false)[0].1, Severity::Warning);
+}
+
+#[test]
+fn test_severity_map_overrides_rule_severity() {
+    let mut rules = rules();
+    rules[0].severity = Severity::Error;
+    let options = LinterOptions {
+        severity_map: HashMap::from([
+            ("img-alt".to_string(), Severity::Warning),
+            ("no-inline-styles".to_string(), Severity::Error),
+        ]),
+        ..Default::default()
+    };
+    let linter = HtmlLinter::new(rules, Some(options));
+    assert_eq!(
+        severities(&linter, false),
+        vec![
+            ("img-alt".to_string(), Severity::Warning),
+            ("no-inline-styles".to_string(), Severity::Error),
+        ]
+    );
+}
+
+#[test]
+fn test_downgrade_all_to() {
+    let mut rules = rules();
+    rules[0].severity = Severity::Error;
+    let options = LinterOptions {
+        downgrade_all_to: Some(Severity::Warning),
+        ..Default::default()
+    };
+    let linter = HtmlLinter::new(rules.clone(), Some(options));
+
+    // Less severe results are left alone
+    assert_eq!(
+        severities(&linter, false),
+        vec![
+            ("img-alt".to_string(), Severity::Warning),
+            ("no-inline-styles".to_string(), Severity::Info),
+        ]
+    );
+
+    let options = LinterOptions {
+        downgrade_all_to: Some(Severity::Info),
+        severity_map: HashMap::from([("img-alt".to_string(), Severity::Error)]),
+        ..Default::default()
+    };
+    let linter = HtmlLinter::new(rules, Some(options));
+    assert_eq!(severities(&linter, false)[0].1, Severity::Error);
+}
+
+#[test]
+fn test_severity_map_from_json() {
+    let options: LinterOptions = serde_json::from_str(
+        r#"{
+            "ignore_files": [],
+            "custom_selectors": {},
+            "max_line_length": null,
+            "allow_inline_styles": false,
+            "max_nesting_depth": null,
+            "severity_map": { "img-alt": "Info" },
+            "downgrade_all_to": "Warning"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(options.severity_map["img-alt"], Severity::Info);
+    assert_eq!(options.downgrade_all_to, Some(Severity::Warning));
+}
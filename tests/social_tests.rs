@@ -0,0 +1,186 @@
+mod common;
+
+use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+
+fn open_graph_rule(options: &[(&str, &str)]) -> Rule {
+    common::rule(
+        RuleType::OpenGraph,
+        Severity::Warning,
+        "meta",
+        "open-graph",
+        "Open Graph metadata is incomplete",
+        options,
+    )
+}
+
+fn messages(rule: Rule, head: &str) -> Vec<String> {
+    let html = format!("<html><head>{}</head><body></body></html>", head);
+    HtmlLinter::new(vec![rule], None)
+        .lint(&html)
+        .unwrap()
+        .into_iter()
+        .map(|result| result.message)
+        .collect()
+}
+
+#[test]
+fn test_complete_open_graph_passes() {
+    let head = "<meta property='og:title' content='Page Title'>
+        <meta property='og:type' content='website'>
+        <meta property='og:image' content='https://example.com/image.jpg'>
+        <meta property='og:image:width' content='1200'>
+        <meta property='og:url' content='https://example.com/page'>";
+    assert!(messages(open_graph_rule(&[]), head).is_empty());
+}
+
+#[test]
+fn test_missing_required_properties() {
+    let head = "<meta property='og:title' content='Page Title'>
+        <meta property='og:type' content='website'>";
+    assert_eq!(
+        messages(open_graph_rule(&[]), head),
+        vec![
+            "Open Graph metadata is incomplete - missing required og:image property",
+            "Open Graph metadata is incomplete - missing required og:url property",
+        ]
+    );
+
+    let rule = open_graph_rule(&[("required_properties", r#"["og:title"]"#)]);
+    assert!(messages(rule, head).is_empty());
+}
+
+#[test]
+fn test_type_specific_properties() {
+    let head = "<meta property='og:title' content='Post'>
+        <meta property='og:type' content='article'>
+        <meta property='og:image' content='https://example.com/post.jpg'>
+        <meta property='og:url' content='https://example.com/post'>";
+    assert_eq!(
+        messages(open_graph_rule(&[]), head),
+        vec![
+            "Open Graph metadata is incomplete - missing article:author property required for og:type \"article\""
+        ]
+    );
+
+    let rule = open_graph_rule(&[(
+        "type_specific",
+        r#"{"article": ["article:published_time"], "profile": ["profile:username"]}"#,
+    )]);
+    let head = format!(
+        "{}<meta property='article:published_time' content='2024-01-01'>",
+        head
+    );
+    assert!(messages(rule, &head).is_empty());
+}
+
+#[test]
+fn test_property_values() {
+    let rule = open_graph_rule(&[("required_properties", "[]")]);
+    let head = "<meta property='og:title' content=' '>
+        <meta property='og:type' content='blogpost'>
+        <meta property='og:image' content='/images/share.jpg'>
+        <meta property='og:image:height' content='tall'>
+        <meta property='og:url' content='ftp://example.com/page'>";
+    assert_eq!(
+        messages(rule, head),
+        vec![
+            "Open Graph metadata is incomplete - og:title property is empty",
+            "Open Graph metadata is incomplete - unknown og:type \"blogpost\"",
+            "Open Graph metadata is incomplete - og:image must be an absolute URL, got \"/images/share.jpg\"",
+            "Open Graph metadata is incomplete - og:image:height must be a whole number of pixels",
+            "Open Graph metadata is incomplete - og:url must be an absolute URL, got \"ftp://example.com/page\"",
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_options() {
+    for (option, value) in [
+        ("required_properties", "og:title"),
+        ("type_specific", "[\"article\"]"),
+    ] {
+        let linter = HtmlLinter::new(vec![open_graph_rule(&[(option, value)])], None);
+        assert!(linter.lint("<html></html>").is_err(), "{}", option);
+    }
+}
+
+fn twitter_rule() -> Rule {
+    common::rule(
+        RuleType::Twitter,
+        Severity::Warning,
+        "meta",
+        "twitter-card",
+        "Twitter Card metadata is incomplete",
+        &[],
+    )
+}
+
+#[test]
+fn test_valid_twitter_cards() {
+    let summary = "<meta name='twitter:card' content='summary_large_image'>
+        <meta name='twitter:title' content='Page Title'>
+        <meta name='twitter:image' content='https://example.com/card.png'>";
+    assert!(messages(twitter_rule(), summary).is_empty());
+
+    let app = "<meta name='twitter:card' content='app'>
+        <meta name='twitter:app:id:googleplay' content='com.example.app'>";
+    assert!(messages(twitter_rule(), app).is_empty());
+
+    let player = "<meta name='twitter:card' content='player'>
+        <meta name='twitter:title' content='Clip'>
+        <meta name='twitter:player' content='https://example.com/embed/1'>
+        <meta name='twitter:player:width' content='480'>
+        <meta name='twitter:player:height' content='270'>";
+    assert!(messages(twitter_rule(), player).is_empty());
+}
+
+#[test]
+fn test_missing_twitter_card_properties() {
+    assert_eq!(
+        messages(twitter_rule(), "<title>Page</title>"),
+        vec!["Twitter Card metadata is incomplete - missing required twitter:card property"]
+    );
+
+    assert_eq!(
+        messages(
+            twitter_rule(),
+            "<meta name='twitter:card' content='summary'>"
+        ),
+        vec![
+            "Twitter Card metadata is incomplete - missing twitter:title property required for twitter:card \"summary\""
+        ]
+    );
+
+    assert_eq!(
+        messages(twitter_rule(), "<meta name='twitter:card' content='app'>"),
+        vec![
+            "Twitter Card metadata is incomplete - missing twitter:app:id:iphone or twitter:app:id:googleplay property required for twitter:card \"app\""
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_twitter_card_values() {
+    let head = "<meta name='twitter:card' content='gallery'>
+        <meta name='twitter:title' content=''>";
+    assert_eq!(
+        messages(twitter_rule(), head),
+        vec![
+            "Twitter Card metadata is incomplete - unknown twitter:card \"gallery\"",
+            "Twitter Card metadata is incomplete - twitter:title property is empty",
+        ]
+    );
+
+    let head = "<meta name='twitter:card' content='player'>
+        <meta name='twitter:title' content='Clip'>
+        <meta name='twitter:player' content='http://example.com/embed/1'>
+        <meta name='twitter:player:width' content='480px'>
+        <meta name='twitter:player:height' content='270'>";
+    assert_eq!(
+        messages(twitter_rule(), head),
+        vec![
+            "Twitter Card metadata is incomplete - twitter:player must be an HTTPS URL, got \"http://example.com/embed/1\"",
+            "Twitter Card metadata is incomplete - twitter:player:width must be a whole number of pixels",
+        ]
+    );
+}
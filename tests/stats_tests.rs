@@ -0,0 +1,157 @@
+use html_linter::{
+    HtmlLinter, LintResult, LintStats, LinterOptions, Location, Rule, RuleType, Severity,
+};
+use std::collections::HashMap;
+
+fn result(rule: &str, severity: Severity) -> LintResult {
+    LintResult {
+        rule: rule.to_string(),
+        severity,
+        message: "Problem".to_string(),
+        location: Location {
+            line: 1,
+            column: 1,
+            element: "div".to_string(),
+            file: None,
+        },
+        source: String::new(),
+        ..Default::default()
+    }
+}
+
+fn sample() -> Vec<LintResult> {
+    vec![
+        result("img-alt", Severity::Error),
+        result("no-inline-styles", Severity::Warning),
+        result("img-alt", Severity::Error),
+        result("heading-order", Severity::Info),
+        result("no-inline-styles", Severity::Warning),
+        result("no-inline-styles", Severity::Warning),
+    ]
+}
+
+#[test]
+fn test_stats_counts() {
+    let stats = LintStats::from_results(&sample());
+    assert_eq!(stats.error_count, 2);
+    assert_eq!(stats.warning_count, 3);
+    assert_eq!(stats.info_count, 1);
+    assert_eq!(stats.total_count, 6);
+    assert_eq!(stats.rules_triggered["img-alt"], 2);
+    assert_eq!(stats.rules_triggered["no-inline-styles"], 3);
+    assert_eq!(stats.rules_triggered["heading-order"], 1);
+    assert_eq!(stats.most_common_rule.as_deref(), Some("no-inline-styles"));
+}
+
+#[test]
+fn test_compute_stats_matches_from_results() {
+    let results = sample();
+    assert_eq!(
+        HtmlLinter::compute_stats(&results),
+        LintStats::from_results(&results)
+    );
+}
+
+#[test]
+fn test_most_common_rule_tie() {
+    let results = vec![
+        result("b-rule", Severity::Info),
+        result("a-rule", Severity::Info),
+    ];
+    let stats = LintStats::from_results(&results);
+    assert_eq!(stats.most_common_rule.as_deref(), Some("a-rule"));
+}
+
+#[test]
+fn test_has_errors_and_worst_severity() {
+    let stats = LintStats::from_results(&sample());
+    assert!(stats.has_errors());
+    assert_eq!(stats.worst_severity(), Some(Severity::Error));
+
+    let stats =
+        LintStats::from_results(&[result("x", Severity::Info), result("y", Severity::Warning)]);
+    assert!(!stats.has_errors());
+    assert_eq!(stats.worst_severity(), Some(Severity::Warning));
+
+    let stats = LintStats::from_results(&[result("x", Severity::Info)]);
+    assert_eq!(stats.worst_severity(), Some(Severity::Info));
+}
+
+#[test]
+fn test_stats_empty_results() {
+    let stats = LintStats::from_results(&[]);
+    assert_eq!(stats, LintStats::default());
+    assert_eq!(stats.total_count, 0);
+    assert!(stats.rules_triggered.is_empty());
+    assert_eq!(stats.most_common_rule, None);
+    assert!(!stats.has_errors());
+    assert_eq!(stats.worst_severity(), None);
+}
+
+#[test]
+fn test_stats_serialize() {
+    let stats = LintStats::from_results(&[result("img-alt", Severity::Error)]);
+    let json = serde_json::to_value(&stats).unwrap();
+    assert_eq!(json["error_count"], 1);
+    assert_eq!(json["rules_triggered"]["img-alt"], 1);
+    assert_eq!(json["most_common_rule"], "img-alt");
+}
+
+#[test]
+fn test_dry_run_matches_lint_stats() {
+    let rules = vec![
+        Rule {
+            name: "img-alt".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Error,
+            selector: "img".to_string(),
+            condition: "alt-missing".into(),
+            message: "Images must have alt attributes".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+        Rule {
+            name: "no-inline-styles".to_string(),
+            rule_type: RuleType::AttributePresence,
+            severity: Severity::Warning,
+            selector: "*".to_string(),
+            condition: "style-attribute".into(),
+            message: "Avoid inline styles".to_string(),
+            options: HashMap::new(),
+            ..Default::default()
+        },
+    ];
+    let html = "<body><img src='a.png'><img src='b.png'><p style='color: red'>Text</p>\
+        <span style='margin: 0'>More</span><div style='padding: 0'></div></body>";
+
+    let option_sets = [
+        LinterOptions::default(),
+        LinterOptions {
+            max_errors_per_rule: Some(1),
+            ..Default::default()
+        },
+        LinterOptions {
+            treat_warnings_as_errors: true,
+            ..Default::default()
+        },
+        LinterOptions {
+            max_errors: Some(1),
+            max_count_includes_warnings: true,
+            ..Default::default()
+        },
+    ];
+    for options in option_sets {
+        let linter = HtmlLinter::new(rules.clone(), Some(options));
+        let stats = linter.dry_run(html).unwrap();
+        assert_eq!(
+            stats,
+            HtmlLinter::compute_stats(&linter.lint(html).unwrap())
+        );
+        assert!(stats.total_count > 0);
+    }
+
+    let stats = HtmlLinter::new(rules, None).dry_run(html).unwrap();
+    assert_eq!(stats.error_count, 2);
+    assert_eq!(stats.warning_count, 3);
+    assert_eq!(stats.most_common_rule.as_deref(), Some("no-inline-styles"));
+}
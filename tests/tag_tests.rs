@@ -0,0 +1,109 @@
+mod common;
+
+use html_linter::{HtmlLinter, LinterOptions, Rule, RuleType, Severity};
+
+fn tagged_rule(name: &str, selector: &str, tags: &[&str]) -> Rule {
+    Rule {
+        name: name.to_string(),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        ..common::rule(
+            RuleType::ElementPresence,
+            Severity::Warning,
+            selector,
+            "forbidden",
+            &format!("<{}> is not allowed", selector),
+            &[],
+        )
+    }
+}
+
+fn setup_rules() -> Vec<Rule> {
+    vec![
+        tagged_rule("no-marquee", "marquee", &["accessibility", "legacy"]),
+        tagged_rule("no-blink", "blink", &["accessibility"]),
+        tagged_rule("no-center", "center", &["seo"]),
+        tagged_rule("no-font", "font", &[]),
+    ]
+}
+
+const HTML: &str =
+    "<body><marquee>a</marquee><blink>b</blink><center>c</center><font>d</font></body>";
+
+fn linted_rules(options: LinterOptions) -> Vec<String> {
+    let linter = HtmlLinter::new(setup_rules(), Some(options));
+    linter
+        .lint(HTML)
+        .unwrap()
+        .into_iter()
+        .map(|r| r.rule)
+        .collect()
+}
+
+#[test]
+fn test_only_tags() {
+    let rules = linted_rules(LinterOptions {
+        only_tags: vec!["accessibility".to_string()],
+        ..Default::default()
+    });
+    assert_eq!(rules, vec!["no-marquee", "no-blink"]);
+}
+
+#[test]
+fn test_skip_tags() {
+    let rules = linted_rules(LinterOptions {
+        skip_tags: vec!["seo".to_string(), "legacy".to_string()],
+        ..Default::default()
+    });
+    assert_eq!(rules, vec!["no-blink", "no-font"]);
+}
+
+#[test]
+fn test_only_and_skip_tags() {
+    let rules = linted_rules(LinterOptions {
+        only_tags: vec!["accessibility".to_string()],
+        skip_tags: vec!["legacy".to_string()],
+        ..Default::default()
+    });
+    assert_eq!(rules, vec!["no-blink"]);
+}
+
+#[test]
+fn test_get_rules_by_tag() {
+    let linter = HtmlLinter::new(setup_rules(), None);
+
+    let names: Vec<&str> = linter
+        .get_rules_by_tag("accessibility")
+        .iter()
+        .map(|r| r.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["no-marquee", "no-blink"]);
+    assert!(linter.get_rules_by_tag("performance").is_empty());
+}
+
+#[test]
+fn test_tags_default_when_loading_json() {
+    let json = r#"[
+        {
+            "name": "untagged",
+            "rule_type": "ElementPresence",
+            "severity": "Error",
+            "selector": "div",
+            "condition": "required",
+            "message": "Test message"
+        },
+        {
+            "name": "tagged",
+            "rule_type": "ElementPresence",
+            "severity": "Error",
+            "selector": "div",
+            "condition": "required",
+            "message": "Test message",
+            "tags": ["seo"]
+        }
+    ]"#;
+
+    let linter = HtmlLinter::from_json(json, None).unwrap();
+    let rules = linter.get_rules();
+    assert!(rules[0].tags.is_empty());
+    assert_eq!(rules[1].tags, vec!["seo"]);
+}
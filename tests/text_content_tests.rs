@@ -0,0 +1,233 @@
+mod common;
+
+use html_linter::{HtmlLinter, Rule, RuleType, Severity};
+
+fn count_rule(selector: &str, condition: &str, options: &[(&str, &str)]) -> Rule {
+    common::rule(
+        RuleType::TextContent,
+        Severity::Warning,
+        selector,
+        condition,
+        "Text length out of range",
+        options,
+    )
+}
+
+#[test]
+fn test_word_count() {
+    let linter = HtmlLinter::new(
+        vec![count_rule(
+            "title",
+            "word-count",
+            &[("min_words", "3"), ("max_words", "12")],
+        )],
+        None,
+    );
+
+    let lint = |title: &str| {
+        linter
+            .lint(&format!(
+                "<html><head><title>{}</title></head></html>",
+                title
+            ))
+            .unwrap()
+    };
+
+    assert_eq!(lint("A well chosen title").len(), 0);
+
+    let results = lint("Too short");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("found 2 words"));
+
+    let results = lint("one two three four five six seven eight nine ten eleven twelve thirteen");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("found 13 words"));
+
+    // Empty and punctuation-only text contain no words
+    let results = lint("");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("found 0 words"));
+    let results = lint("- ... !");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("found 0 words"));
+}
+
+#[test]
+fn test_word_count_across_paragraphs() {
+    let linter = HtmlLinter::new(
+        vec![count_rule("article", "word-count", &[("max_words", "5")])],
+        None,
+    );
+
+    let html = "<article><p>One two three.</p>\n<p>Four five six.</p></article>";
+    let results = linter.lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("found 6 words"));
+}
+
+#[test]
+fn test_sentence_count() {
+    let linter = HtmlLinter::new(
+        vec![count_rule(
+            "p",
+            "sentence-count",
+            &[("min_sentences", "1"), ("max_sentences", "2")],
+        )],
+        None,
+    );
+    let lint = |text: &str| linter.lint(&format!("<p>{}</p>", text)).unwrap();
+
+    assert_eq!(lint("Just one sentence").len(), 0);
+    assert_eq!(lint("First one. Second one!").len(), 0);
+    assert_eq!(lint("Version 2.5 is out. Really?").len(), 0);
+
+    let results = lint("One. Two? Three! Four.");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("found 4 sentences"));
+
+    let results = lint("?!...");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.contains("found 0 sentences"));
+}
+
+#[test]
+fn test_invalid_count_option() {
+    let linter = HtmlLinter::new(
+        vec![count_rule("p", "word-count", &[("max_words", "many")])],
+        None,
+    );
+    assert!(linter.lint("<p>Text</p>").is_err());
+}
+
+fn density_rule(condition: &str, options: &[(&str, &str)]) -> Rule {
+    Rule {
+        rule_type: RuleType::ElementContent,
+        ..count_rule("article", condition, options)
+    }
+}
+
+fn density_messages(rule: Rule, html: &str) -> Vec<String> {
+    HtmlLinter::new(vec![rule], None)
+        .lint(html)
+        .unwrap()
+        .into_iter()
+        .map(|result| result.message)
+        .collect()
+}
+
+#[test]
+fn test_word_count_range() {
+    let html = "<article id='short'><p>Too short.</p></article>\
+        <article id='ok'><p>Just about the right length here.</p></article>";
+    let rule = density_rule(
+        "word-count-range",
+        &[("min_words", "4"), ("max_words", "8")],
+    );
+    let results = HtmlLinter::new(vec![rule], None).lint(html).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].message.ends_with("(found 2 words)"));
+    assert!(results[0].source.contains("short"));
+}
+
+#[test]
+fn test_character_count() {
+    let html = "<article>\n    <p>Hello</p>\n    <p>world</p>\n</article>";
+    let rule = density_rule("character-count", &[("max_characters", "5")]);
+    assert_eq!(density_messages(rule, html).len(), 1);
+    let rule = density_rule("character-count", &[("max_characters", "11")]);
+    assert!(density_messages(rule, html).is_empty());
+}
+
+#[test]
+fn test_paragraph_count() {
+    let rule = density_rule("paragraph-count", &[("min_paragraphs", "3")]);
+    let html = "<article><p>One</p><div><p>Two</p></div></article>";
+    let messages = density_messages(rule.clone(), html);
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].ends_with("(found 2 paragraphs)"));
+
+    // Plain text falls back to blank-line separated blocks
+    let html = "<article>One\n\nTwo\n\n\n\nThree</article>";
+    assert!(density_messages(rule, html).is_empty());
+}
+
+#[test]
+fn test_min_length() {
+    let linter = HtmlLinter::new(
+        vec![count_rule("h2", "min-length", &[("min_length", "5")])],
+        None,
+    );
+    let results = linter
+        .lint("<body><h2>Overview</h2><h2>  FAQ  </h2><h2></h2><h2>Café</h2><h2>Crème</h2></body>")
+        .unwrap();
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Text length out of range (found 3 characters, expected at least 5)",
+            "Text length out of range (found 0 characters, expected at least 5)",
+            "Text length out of range (found 4 characters, expected at least 5)",
+        ]
+    );
+
+    let linter = HtmlLinter::new(vec![count_rule("h2", "min-length", &[])], None);
+    assert!(linter.lint("<h2>A</h2>").is_err());
+}
+
+#[test]
+fn test_non_empty() {
+    let linter = HtmlLinter::new(vec![count_rule("button", "non-empty", &[])], None);
+    let results = linter
+        .lint("<body><button>Save</button><button>   </button><button><span>Go</span></button><button><img src='x.png'></button></body>")
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.location.element == "button"));
+}
+
+fn empty_content_rule(selector: &str, options: &[(&str, &str)]) -> Rule {
+    Rule {
+        rule_type: RuleType::ElementContent,
+        ..count_rule(selector, "non-empty", options)
+    }
+}
+
+#[test]
+fn test_non_empty_element_content() {
+    let html = "<body><h1>Title</h1><h1> </h1><button>Save</button><button></button>\
+        <label>Name <input name='n'></label><label></label>\
+        <table><tr><th>Header</th><th></th></tr></table><p><em>Note</em></p><p>\n</p></body>";
+    for tag in ["h1", "button", "label", "th", "p"] {
+        let messages = density_messages(empty_content_rule(tag, &[]), html);
+        assert_eq!(
+            messages,
+            vec![format!(
+                "Text length out of range (<{}> has no content)",
+                tag
+            )],
+            "{}",
+            tag
+        );
+    }
+
+    // Void elements never have content
+    assert!(density_messages(empty_content_rule("input", &[]), html).is_empty());
+}
+
+#[test]
+fn test_non_empty_element_content_options() {
+    let html = "<body><button><img src='save.png' alt='Save'></button><table><tr><td></td></tr></table></body>";
+
+    assert_eq!(
+        density_messages(empty_content_rule("button", &[]), html).len(),
+        1
+    );
+    let rule = empty_content_rule("button", &[("allow_children", "true")]);
+    assert!(density_messages(rule, html).is_empty());
+
+    let rule = empty_content_rule("td", &[("allowed_empty_tags", r#"["td"]"#)]);
+    assert!(density_messages(rule, html).is_empty());
+    assert_eq!(
+        density_messages(empty_content_rule("td", &[]), html).len(),
+        1
+    );
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use html_linter::{HtmlLinter, LintResult, Rule, RuleType, Severity};
+
+fn type_rule(condition: &str, options: &[(&str, &str)]) -> Rule {
+    common::rule(
+        RuleType::ElementContent,
+        Severity::Error,
+        "*",
+        condition,
+        "Unexpected type attribute",
+        options,
+    )
+}
+
+fn lint(rule: Rule, html: &str) -> Vec<LintResult> {
+    HtmlLinter::new(vec![rule], None).lint(html).unwrap()
+}
+
+#[test]
+fn test_script_type_check() {
+    let html = "<html><head>\
+        <script src='app.js'></script>\
+        <script type='module' src='main.js'></script>\
+        <script type='application/ld+json'>{}</script>\
+        <script type='text/javascript' src='legacy.js'></script>\
+        <script type='application/javascript' src='old.js'></script>\
+        <script type='text/x-template' id='row'></script>\
+        </head><body></body></html>";
+    let results = lint(type_rule("script-type-check", &[]), html);
+    assert_eq!(results.len(), 3);
+
+    assert_eq!(results[0].severity, Severity::Warning);
+    assert!(results[0]
+        .message
+        .contains(r#"type="text/javascript" is redundant in HTML5"#));
+    assert!(results[0].source.contains("legacy.js"));
+
+    assert_eq!(results[1].severity, Severity::Error);
+    assert!(results[1]
+        .message
+        .contains(r#"type="application/javascript" is not recommended"#));
+
+    assert_eq!(results[2].severity, Severity::Error);
+    assert!(results[2]
+        .message
+        .contains(r#"invalid type "text/x-template""#));
+}
+
+#[test]
+fn test_script_type_check_allow_redundant() {
+    let html = "<script type='text/javascript' src='legacy.js'></script>";
+    let rule = type_rule("script-type-check", &[("allow_redundant", "true")]);
+    assert!(lint(rule, html).is_empty());
+}
+
+#[test]
+fn test_style_type_check() {
+    let html = "<html><head>\
+        <style>p { margin: 0; }</style>\
+        <style type='text/css'>h1 { margin: 0; }</style>\
+        <link rel='stylesheet' href='site.css' type='text/less'>\
+        <link rel='icon' href='favicon.png' type='image/png'>\
+        </head><body></body></html>";
+    let results = lint(type_rule("style-type-check", &[]), html);
+    assert_eq!(results.len(), 2);
+
+    assert_eq!(results[0].severity, Severity::Warning);
+    assert!(results[0]
+        .message
+        .contains(r#"type="text/css" is redundant"#));
+    assert_eq!(results[1].severity, Severity::Error);
+    assert!(results[1].message.contains(r#"invalid type "text/less""#));
+    assert!(results[1].source.contains("site.css"));
+}
@@ -0,0 +1,198 @@
+use html_linter::{HtmlLinter, LintResult, Severity};
+
+fn violations(html: &str, rule: &str) -> Vec<LintResult> {
+    HtmlLinter::new(HtmlLinter::wcag_aa_rules(), None)
+        .lint(html)
+        .unwrap()
+        .into_iter()
+        .filter(|result| result.rule == rule)
+        .collect()
+}
+
+fn page(body: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html lang='en'><head><title>Example</title></head><body>{}</body></html>",
+        body
+    )
+}
+
+#[test]
+fn test_wcag_rule_sets() {
+    let a_rules = HtmlLinter::wcag_a_rules();
+    let aa_rules = HtmlLinter::wcag_aa_rules();
+
+    assert!(a_rules.len() < aa_rules.len());
+    assert!(a_rules
+        .iter()
+        .all(|rule| rule.tags.contains(&"wcag-a".to_string())));
+    assert!(a_rules
+        .iter()
+        .all(|rule| aa_rules.iter().any(|aa_rule| aa_rule.name == rule.name)));
+    assert!(!a_rules.iter().any(|rule| rule.name == "wcag-input-purpose"));
+    assert!(aa_rules
+        .iter()
+        .any(|rule| rule.name == "wcag-input-purpose"));
+}
+
+#[test]
+fn test_conforming_page_passes() {
+    let html = page(
+        "<h1>Shop</h1><img src='logo.png' alt='Acme Inc.'><h2>Contact</h2>\
+         <form><label for='email'>Email</label><input id='email' type='email' autocomplete='email'></form>\
+         <table><tr><th>Day</th><th>Hours</th></tr><tr><td>Mon</td><td>9-5</td></tr></table>\
+         <a href='/about'>About us</a><div role='checkbox' aria-checked='false' tabindex='0'>Subscribe</div>",
+    );
+    let results = HtmlLinter::new(HtmlLinter::wcag_aa_rules(), None)
+        .lint(&html)
+        .unwrap();
+    assert!(results.is_empty(), "{:?}", results);
+}
+
+// 1.1.1: an image that conveys information without a text alternative
+#[test]
+fn test_non_text_content() {
+    let results = violations(
+        &page("<img src='chart.png'><img src='spacer.gif' alt=''>"),
+        "wcag-img-alt",
+    );
+    assert_eq!(results.len(), 1);
+    assert!(results[0].source.contains("chart.png"));
+    assert!(results[0]
+        .fix_hint
+        .starts_with("Add alt=\"\" for decorative images"));
+}
+
+#[test]
+fn test_rule_docs() {
+    let docs = HtmlLinter::new(HtmlLinter::wcag_aa_rules(), None).rule_docs();
+
+    assert_eq!(docs.len(), HtmlLinter::wcag_aa_rules().len());
+    assert!(docs.iter().all(|doc| !doc.fix_hint.is_empty()));
+    let lang = docs
+        .iter()
+        .find(|doc| doc.name == "wcag-html-lang")
+        .unwrap();
+    assert_eq!(
+        lang.message,
+        "The html element must have a lang attribute (WCAG 3.1.1)"
+    );
+    assert_eq!(lang.tags, vec!["wcag", "wcag-a"]);
+}
+
+#[test]
+fn test_explain_rule() {
+    // Every built-in example is accepted or reported by its rule as documented
+    let builtin = HtmlLinter::wcag_aa_rules()
+        .into_iter()
+        .chain(HtmlLinter::security_rules());
+    for rule in builtin {
+        let linter = HtmlLinter::new(vec![rule.clone()], None);
+        let doc = linter.explain_rule(&rule.name).unwrap();
+        assert_ne!(doc.description, rule.message, "{}", rule.name);
+        assert!(doc.examples.iter().any(|example| example.valid));
+        assert!(doc.examples.iter().any(|example| !example.valid));
+        for example in &doc.examples {
+            let results = linter.lint(&example.html).unwrap();
+            assert_eq!(
+                results.is_empty(),
+                example.valid,
+                "{}: {}",
+                rule.name,
+                example.html
+            );
+        }
+    }
+
+    // Built-in rules are documented even when they aren't configured
+    let linter = HtmlLinter::new(vec![], None);
+    let doc = linter.explain_rule("no-meta-refresh").unwrap();
+    assert_eq!(doc.rule_type, "SecurityPattern");
+    assert_eq!(doc.severity, Severity::Error);
+    assert!(linter.explain_rule("no-such-rule").is_none());
+}
+
+#[test]
+fn test_explain_user_rule() {
+    let json = r#"[{
+        "name": "no-marquee",
+        "rule_type": "ElementPresence",
+        "severity": "Warning",
+        "selector": "marquee",
+        "condition": "forbidden",
+        "message": "Avoid the marquee element"
+    }]"#;
+    let linter = HtmlLinter::from_json(json, None).unwrap();
+    assert_eq!(linter.list_rules(), vec!["no-marquee"]);
+
+    let doc = linter.explain_rule("no-marquee").unwrap();
+    assert_eq!(doc.rule_type, "ElementPresence");
+    assert_eq!(doc.severity, Severity::Warning);
+    assert_eq!(doc.description, "Avoid the marquee element");
+    assert!(doc.examples.is_empty());
+}
+
+// 1.3.1: a data table whose header row is styled with td instead of th
+#[test]
+fn test_info_and_relationships() {
+    let html = page(
+        "<table id='bad'><tr><td><b>Name</b></td></tr><tr><td>Ada</td></tr></table>\
+         <table id='good'><tr><th>Name</th></tr><tr><td>Ada</td></tr></table>",
+    );
+    let results = violations(&html, "wcag-table-headers");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].source.contains("bad"));
+}
+
+// 1.3.5: a personal data field without autocomplete
+#[test]
+fn test_identify_input_purpose() {
+    let html = page(
+        "<label for='name'>Name</label><input id='name' type='text'>\
+         <label for='tel'>Phone</label><input id='tel' type='tel' autocomplete='tel'>",
+    );
+    let results = violations(&html, "wcag-input-purpose");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].source.contains("name"));
+    assert!(HtmlLinter::wcag_a_rules()
+        .iter()
+        .all(|rule| rule.name != "wcag-input-purpose"));
+}
+
+// 2.4.2: a page without a title element
+#[test]
+fn test_page_titled() {
+    let html = "<!DOCTYPE html><html lang='en'><head></head><body><p>Hi</p></body></html>";
+    assert_eq!(violations(html, "wcag-page-title").len(), 1);
+    assert!(violations(&page("<p>Hi</p>"), "wcag-page-title").is_empty());
+}
+
+// 2.4.6: headings that skip a level break the document outline
+#[test]
+fn test_headings_and_labels() {
+    let results = violations(
+        &page("<h1>Title</h1><h3>Skipped</h3>"),
+        "wcag-heading-order",
+    );
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.element, "h3");
+}
+
+// 3.1.1: the page language is not declared
+#[test]
+fn test_language_of_page() {
+    let html = "<!DOCTYPE html><html><head><title>T</title></head><body></body></html>";
+    assert_eq!(violations(html, "wcag-html-lang").len(), 1);
+    assert!(violations(&page(""), "wcag-html-lang").is_empty());
+}
+
+// 4.1.2: controls and links without a name, and a custom checkbox without its state
+#[test]
+fn test_name_role_value() {
+    let html = page(
+        "<input id='q' type='search' autocomplete='off'><a href='/next'></a>\
+         <div role='checkbox' tabindex='0'>Subscribe</div>",
+    );
+    assert_eq!(violations(&html, "wcag-control-name").len(), 1);
+    assert_eq!(violations(&html, "wcag-link-name").len(), 1);
+    assert_eq!(violations(&html, "wcag-aria-role").len(), 1);
+}
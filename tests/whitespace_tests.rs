@@ -0,0 +1,302 @@
+mod common;
+
+use html_linter::{HtmlLinter, LinterOptions, Rule, RuleType, Severity};
+
+fn whitespace_rule(condition: &str, options: &[(&str, &str)]) -> Rule {
+    common::rule(
+        RuleType::WhiteSpace,
+        Severity::Warning,
+        "*",
+        condition,
+        "Inconsistent indentation",
+        options,
+    )
+}
+
+fn violation_lines(rule: Rule, html: &str) -> Vec<usize> {
+    HtmlLinter::new(vec![rule], None)
+        .lint(html)
+        .unwrap()
+        .iter()
+        .map(|r| r.location.line)
+        .collect()
+}
+
+#[test]
+fn test_detected_indentation_style() {
+    let html = "<ul>\n  <li>One</li>\n\t<li>Two</li>\n  <li>Three</li>\n</ul>";
+    assert_eq!(
+        violation_lines(whitespace_rule("consistent-indentation", &[]), html),
+        vec![3]
+    );
+
+    let html = "<ul>\n\t<li>One</li>\n  <li>Two</li>\n</ul>";
+    assert_eq!(
+        violation_lines(whitespace_rule("consistent-indentation", &[]), html),
+        vec![3]
+    );
+}
+
+#[test]
+fn test_enforced_indentation_style() {
+    let html = "<ul>\n  <li>One</li>\n  <li>Two</li>\n</ul>";
+    let results = HtmlLinter::new(
+        vec![whitespace_rule(
+            "consistent-indentation",
+            &[("style", "tabs")],
+        )],
+        None,
+    )
+    .lint(html)
+    .unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].source, "  <li>One</li>");
+    assert!(results[0].message.contains("Expected tab indentation"));
+}
+
+#[test]
+fn test_indentation_size() {
+    let html = "<div>\n    <p>Four</p>\n  <p>Two</p>\n   <p>Three</p>\n</div>";
+    let rule = whitespace_rule(
+        "consistent-indentation",
+        &[("style", "spaces"), ("size", "4")],
+    );
+    assert_eq!(violation_lines(rule, html), vec![3, 4]);
+
+    let rule = whitespace_rule(
+        "consistent-indentation",
+        &[("style", "spaces"), ("size", "2")],
+    );
+    assert_eq!(violation_lines(rule, html), vec![4]);
+}
+
+#[test]
+fn test_invalid_indentation_options() {
+    let html = "<div>\n  <p>Text</p>\n</div>";
+    for options in [[("style", "both")], [("size", "0")]] {
+        let linter = HtmlLinter::new(
+            vec![whitespace_rule("consistent-indentation", &options)],
+            None,
+        );
+        assert!(linter.lint(html).is_err());
+    }
+}
+
+#[test]
+fn test_no_mixed_indentation() {
+    let html = "<div>\n\t<p>Tab</p>\n  <p>Spaces</p>\n\t  <p>Mixed</p>\n  \t<p>Mixed</p>\n</div>";
+    assert_eq!(
+        violation_lines(whitespace_rule("no-mixed-indentation", &[]), html),
+        vec![4, 5]
+    );
+}
+
+#[test]
+fn test_line_length_uses_linter_option() {
+    let html = "<p>\n1234567890\n12345678901\n</p>";
+    let options = LinterOptions {
+        max_line_length: Some(10),
+        ..Default::default()
+    };
+    let results = HtmlLinter::new(vec![whitespace_rule("line-length", &[])], Some(options))
+        .lint(html)
+        .unwrap();
+
+    // A line exactly at the limit is fine
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.line, 3);
+    assert_eq!(results[0].location.column, 11);
+    assert_eq!(results[0].source, "1234567890…");
+    assert!(results[0]
+        .message
+        .contains("line is 11 characters, limit is 10"));
+}
+
+#[test]
+fn test_line_length_rule_option_and_selector() {
+    let html = "<html><body>\n<p>A paragraph line that is fairly long</p>\n<script>\nvar short = 1;\nvar tooLongForScripts = 2;\n</script>\n</body></html>";
+    let rule = Rule {
+        selector: "script".to_string(),
+        ..whitespace_rule("line-length", &[("max_length", "20")])
+    };
+    let options = LinterOptions {
+        max_line_length: Some(80),
+        ..Default::default()
+    };
+
+    let results = HtmlLinter::new(vec![rule], Some(options))
+        .lint(html)
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].location.line, 5);
+}
+
+#[test]
+fn test_line_length_requires_limit() {
+    let linter = HtmlLinter::new(vec![whitespace_rule("line-length", &[])], None);
+    assert!(linter.lint("<p>Text</p>").is_err());
+}
+
+fn entity_messages(condition: &str, options: &[(&str, &str)], html: &str) -> Vec<String> {
+    HtmlLinter::new(vec![whitespace_rule(condition, options)], None)
+        .lint(html)
+        .unwrap()
+        .into_iter()
+        .map(|r| r.message)
+        .collect()
+}
+
+#[test]
+fn test_entity_consistency_follows_first_form() {
+    let html = "<p>Tom &amp; Jerry &#38; friends</p>\n<p>Wait&mdash;no&#8212;yes — done</p>";
+    assert_eq!(
+        entity_messages("entity-consistency", &[], html),
+        vec![
+            "Inconsistent indentation - `&#38;` should be written as `&amp;`",
+            "Inconsistent indentation - `&#8212;` should be written as `&mdash;`",
+            "Inconsistent indentation - `—` should be written as `&mdash;`",
+        ]
+    );
+
+    // Hex and decimal references are the same form
+    let html = "<p>&#x2014; and &#8212;</p>";
+    assert!(entity_messages("entity-consistency", &[], html).is_empty());
+}
+
+#[test]
+fn test_entity_consistency_prefer_named() {
+    let html = "<p>&copy; 2024 &#169; Caf\u{e9} &#x2603; &amp;</p>";
+    assert_eq!(
+        entity_messages("entity-consistency", &[("prefer", "named")], html),
+        vec![
+            "Inconsistent indentation - `&#169;` should be written as `&copy;`",
+            "Inconsistent indentation - `\u{e9}` should be written as `&eacute;`",
+        ]
+    );
+}
+
+#[test]
+fn test_entity_consistency_prefer_numeric() {
+    let html = "<p>&copy; &#169; &#xA9; \u{e9}</p>";
+    assert_eq!(
+        entity_messages("entity-consistency", &[("prefer", "numeric")], html),
+        vec![
+            "Inconsistent indentation - `&copy;` should be written as `&#169;`",
+            "Inconsistent indentation - `\u{e9}` should be written as `&#233;`",
+        ]
+    );
+}
+
+#[test]
+fn test_entity_consistency_prefer_unicode() {
+    // Markup characters and invisible ones such as &nbsp; stay encoded
+    let html = "<p>&copy; &#8212; &lt;tag&gt; &amp; &nbsp; \u{e9}</p>";
+    assert_eq!(
+        entity_messages("entity-consistency", &[("prefer", "unicode")], html),
+        vec![
+            "Inconsistent indentation - `&copy;` should be written as `\u{a9}`",
+            "Inconsistent indentation - `&#8212;` should be written as `\u{2014}`",
+        ]
+    );
+
+    let rule = whitespace_rule("entity-consistency", &[("prefer", "ascii")]);
+    assert!(HtmlLinter::new(vec![rule], None).lint("<p></p>").is_err());
+}
+
+#[test]
+fn test_unnecessary_entities() {
+    let html = "<p>A&mdash;B &#x2019; &amp; &nbsp; &shy; &lt;</p>";
+    let linter = HtmlLinter::new(vec![whitespace_rule("unnecessary-entities", &[])], None);
+    let results = linter.lint(html).unwrap();
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Inconsistent indentation - `&mdash;` can be written as `\u{2014}`",
+            "Inconsistent indentation - `&#x2019;` can be written as `\u{2019}`",
+        ]
+    );
+    assert_eq!(results[0].location.column, 5);
+    assert_eq!(results[0].source, "&mdash;");
+    assert_eq!(
+        results[0]
+            .fix_suggestion
+            .as_ref()
+            .unwrap()
+            .replacement
+            .as_deref(),
+        Some("\u{2014}")
+    );
+
+    // Each fix replaces only its reference, so every fix on a line applies
+    assert_eq!(
+        linter.apply_fixes(html, &results),
+        "<p>A\u{2014}B \u{2019} &amp; &nbsp; &shy; &lt;</p>"
+    );
+
+    // Documents declaring another encoding need the references
+    let html = "<head><meta charset='iso-8859-1'></head><p>A&mdash;B</p>";
+    assert!(entity_messages("unnecessary-entities", &[], html).is_empty());
+}
+
+#[test]
+fn test_entities_ignore_scripts_styles_and_comments() {
+    let html = "<head><style>p::before { content: '\u{2014}'; }</style>\
+        <script>var s = 'Caf\u{e9} &mdash;';</script></head>\
+        <body><!-- &mdash; --><textarea>&mdash;</textarea><p title='&mdash;'>Caf\u{e9} &mdash;</p></body>";
+    assert_eq!(
+        entity_messages("entity-consistency", &[("prefer", "named")], html),
+        vec!["Inconsistent indentation - `\u{e9}` should be written as `&eacute;`"]
+    );
+    assert_eq!(
+        entity_messages("unnecessary-entities", &[], html),
+        vec!["Inconsistent indentation - `&mdash;` can be written as `\u{2014}`"]
+    );
+}
+
+#[test]
+fn test_attribute_whitespace() {
+    let html = "<div class=' card  wide' title='Hello '></div><p id='intro'>Text</p>";
+    let results = HtmlLinter::new(vec![whitespace_rule("attribute-whitespace", &[])], None)
+        .lint(html)
+        .unwrap();
+    let messages: Vec<&str> = results.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Inconsistent indentation (class has leading whitespace)",
+            "Inconsistent indentation (class has consecutive whitespace)",
+            "Inconsistent indentation (title has trailing whitespace)",
+        ]
+    );
+    assert!(results[0].source.contains("card"));
+
+    let rule = whitespace_rule(
+        "attribute-whitespace",
+        &[
+            ("allow_leading", "true"),
+            ("allow_internal_multiple", "true"),
+        ],
+    );
+    assert_eq!(violation_lines(rule, html).len(), 1);
+}
+
+#[test]
+fn test_attribute_whitespace_as_written() {
+    // Whitespace written as character references is deliberate
+    let html = "<div title='a&#32;&#32;b' data-label='&#9;tabbed'></div>";
+    let rule = whitespace_rule("attribute-whitespace", &[]);
+    assert!(violation_lines(rule, html).is_empty());
+}
+
+#[test]
+fn test_class_list_order() {
+    let html = "<div class='alert Banner'></div><div class='wide card'></div>";
+    let results = HtmlLinter::new(vec![whitespace_rule("class-list-order", &[])], None)
+        .lint(html)
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0]
+        .message
+        .contains(r#"class names are not in alphabetical order; expected "card wide""#));
+}